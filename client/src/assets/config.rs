@@ -10,7 +10,6 @@ use super::*;
 
 #[derive(Asset, TypePath, Deserialize)]
 pub struct ConfigData {
-    #[allow(dead_code)]
     pub server_url: String,
 }
 
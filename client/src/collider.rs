@@ -39,70 +39,6 @@ impl Collider2d {
             }
         }
     }
-
-    pub fn intersects(this: (&Self, &GlobalTransform), other: (&Self, &GlobalTransform)) -> bool {
-        match (this.0, other.0) {
-            (
-                Collider2d::Box {
-                    offset: a_offset,
-                    size: a_size,
-                },
-                Collider2d::Box {
-                    offset: b_offset,
-                    size: b_size,
-                },
-            ) => {
-                let a_pos = this.1.translation().xy();
-                let a_min = a_pos + *a_offset - *a_size * 0.5;
-                let a_max = a_pos + *a_offset + *a_size * 0.5;
-                let b_pos = other.1.translation().xy();
-                let b_min = b_pos + *b_offset - *b_size * 0.5;
-                let b_max = b_pos + *b_offset + *b_size * 0.5;
-
-                a_max.x >= b_min.x && a_min.x <= b_max.x && a_max.y >= b_min.y && a_min.y <= b_max.y
-            }
-            (
-                Collider2d::Box {
-                    offset: a_offset,
-                    size: a_size,
-                },
-                Collider2d::Circle {
-                    offset: b_offset,
-                    radius: b_radius,
-                },
-            ) => {
-                let a_pos = this.1.translation().xy();
-                let a_min = a_pos + *a_offset - *a_size * 0.5;
-                let a_max = a_pos + *a_offset + *a_size * 0.5;
-                let b_center = other.1.translation().xy() + *b_offset;
-                let closest_point = b_center.clamp(a_min, a_max);
-
-                let distance_squared = (closest_point - b_center).length_squared();
-                distance_squared <= b_radius * b_radius
-            }
-            (Collider2d::Circle { .. }, Collider2d::Box { .. }) => {
-                Collider2d::intersects(other, this)
-            }
-            (
-                Collider2d::Circle {
-                    offset: a_offset,
-                    radius: a_radius,
-                },
-                Collider2d::Circle {
-                    offset: b_offset,
-                    radius: b_radius,
-                },
-            ) => {
-                let a_center = this.1.translation().xy() + *a_offset;
-                let b_center = other.1.translation().xy() + *b_offset;
-
-                let distance_squared = (a_center - b_center).length_squared();
-                let radius_sum = a_radius + b_radius;
-
-                distance_squared <= radius_sum * radius_sum
-            }
-        }
-    }
 }
 
 // --- POSTUPDATE SYSTEMS ---
@@ -6,6 +6,10 @@ use protocol::NUM_HEROS;
 
 #[cfg(target_arch = "wasm32")]
 pub const SYSTEM_VOLUME_KEY: &str = "system_volume";
+#[cfg(target_arch = "wasm32")]
+pub const SESSION_TOKEN_KEY: &str = "session_token";
+#[cfg(not(target_arch = "wasm32"))]
+pub const SESSION_TOKEN_FILE: &str = "session_token";
 
 pub const LOADING_BAR_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
 // --- GREEN ---
@@ -1,7 +1,8 @@
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
-#[cfg(target_arch = "wasm32")]
+use protocol::SESSION_TOKEN_PARAM;
+
 use crate::assets::config::ConfigData;
 
 use super::*;
@@ -32,16 +33,13 @@ impl Plugin for InnerPlugin {
                 .run_if(in_state(LevelStates::Connect)),
         );
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            app.add_systems(OnEnter(LevelStates::Connect), connect_game_server)
-                .add_systems(
-                    PreUpdate,
-                    (packet_receive_loop)
-                        .run_if(resource_exists::<Network>)
-                        .run_if(in_state(LevelStates::Connect)),
-                );
-        }
+        app.add_systems(OnEnter(LevelStates::Connect), connect_game_server)
+            .add_systems(
+                PreUpdate,
+                (packet_receive_loop)
+                    .run_if(resource_exists::<Network>)
+                    .run_if(in_state(LevelStates::Connect)),
+            );
     }
 }
 
@@ -51,7 +49,6 @@ fn debug_label() {
     info!("Current Level: Connect");
 }
 
-#[cfg(target_arch = "wasm32")]
 fn connect_game_server(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -66,7 +63,22 @@ fn connect_game_server(
         return;
     };
 
-    match Network::new(&config.server_url) {
+    let url = match load_session_token() {
+        Some(token) => {
+            let separator = if config.server_url.contains('?') {
+                '&'
+            } else {
+                '?'
+            };
+            format!(
+                "{}{}{}={}",
+                config.server_url, separator, SESSION_TOKEN_PARAM, token
+            )
+        }
+        None => config.server_url.clone(),
+    };
+
+    match Network::new(&url) {
         Ok(network) => {
             commands.insert_resource(network);
         }
@@ -78,11 +90,13 @@ fn connect_game_server(
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn packet_receive_loop(mut commands: Commands, network: Res<Network>) {
     for result in network.receiver.try_iter() {
         match result {
             Ok(packet) => match packet {
+                Packet::SessionToken { token } => {
+                    save_session_token(&token);
+                }
                 Packet::Connection(p) => {
                     commands.insert_resource(PlayerInfo {
                         uuid: p.uuid.unwrap(),
@@ -90,6 +104,7 @@ fn packet_receive_loop(mut commands: Commands, network: Res<Network>) {
                         hero: p.hero,
                         win: p.win,
                         lose: p.lose,
+                        draw: p.draw,
                     });
                 }
                 _ => { /* empty */ }
@@ -140,3 +155,39 @@ fn check_connection(
         }
     }
 }
+
+// --- SESSION TOKEN ---
+
+#[cfg(target_arch = "wasm32")]
+fn load_session_token() -> Option<String> {
+    get_local_storage()?.get_item(SESSION_TOKEN_KEY).ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_session_token(token: &str) {
+    if let Some(storage) = get_local_storage() {
+        let _ = storage.set_item(SESSION_TOKEN_KEY, token);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn session_token_path() -> Option<std::path::PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    Some(exe.with_file_name(SESSION_TOKEN_FILE))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_session_token() -> Option<String> {
+    let text = std::fs::read_to_string(session_token_path()?).ok()?;
+    let token = text.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn save_session_token(token: &str) {
+    if let Some(path) = session_token_path()
+        && let Err(e) = std::fs::write(&path, token)
+    {
+        error!("Failed to save session token ({}): {}", path.display(), e);
+    }
+}
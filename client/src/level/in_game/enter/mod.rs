@@ -9,8 +9,8 @@ use protocol::Hero;
 use super::*;
 
 // --- CONSTANTS ---
-const TIMEOUT: f32 = 5.0;
-const MAX_RETRY_COUNT: u32 = 2;
+pub(super) const TIMEOUT: f32 = 5.0;
+pub(super) const MAX_RETRY_COUNT: u32 = 2;
 
 // --- PLUGIN ---
 
@@ -41,7 +41,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::LoadGame)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             PreUpdate,
             handle_received_packets.run_if(in_state(LevelStates::LoadGame)),
@@ -68,7 +67,7 @@ fn load_necessary_assets(
     );
 }
 
-fn load_assets(commands: &mut Commands, asset_server: &AssetServer, heros: &[Hero]) {
+pub(super) fn load_assets(commands: &mut Commands, asset_server: &AssetServer, heros: &[Hero]) {
     let mut loading_assets = InGameAssets::default();
 
     // --- Font Loading ---
@@ -190,7 +189,7 @@ fn load_assets(commands: &mut Commands, asset_server: &AssetServer, heros: &[Her
     commands.insert_resource(loading_assets);
 }
 
-fn setup_loading_minimi(
+pub(super) fn setup_loading_minimi(
     mut query: Query<(&mut ImageNode, &mut AnimationTimer), With<EnterGameLevelEntity>>,
 ) {
     for (mut image_node, mut timer) in query.iter_mut() {
@@ -203,7 +202,6 @@ fn setup_loading_minimi(
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -280,7 +278,7 @@ fn check_and_retry_asset_load_timeout(
 }
 
 #[allow(clippy::type_complexity)]
-fn update_loading_progress(
+pub(super) fn update_loading_progress(
     asset_server: Res<AssetServer>,
     loading_assets: Res<InGameAssets>,
     mut set: ParamSet<(
@@ -310,7 +308,7 @@ fn update_loading_progress(
     }
 }
 
-fn update_loading_minimi(
+pub(super) fn update_loading_minimi(
     mut query: Query<(&mut ImageNode, &mut AnimationTimer), With<EnterGameLevelEntity>>,
     time: Res<Time>,
 ) {
@@ -84,11 +84,15 @@ fn cleanup_scene_timer(mut commands: Commands) {
 fn update_scene_timer(
     mut next_state: ResMut<NextState<LevelStates>>,
     mut scene_timer: ResMut<SceneTimer>,
+    spectate_info: Option<Res<SpectateInfo>>,
     time: Res<Time>,
 ) {
     scene_timer.tick(time.delta_secs());
     if scene_timer.elapsed_sec() >= SCENE_DURATION {
-        next_state.set(LevelStates::LoadGame);
+        match spectate_info {
+            Some(_) => next_state.set(LevelStates::LoadSpectate),
+            None => next_state.set(LevelStates::LoadGame),
+        }
     }
 }
 
@@ -27,7 +27,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::InitGame)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             PreUpdate,
             handle_received_packets.run_if(in_state(LevelStates::InitGame)),
@@ -47,6 +46,7 @@ fn setup_in_game(
     image_assets: Res<Assets<Image>>,
     player_info: Res<PlayerInfo>,
     other_info: Res<OtherInfo>,
+    ruleset: Res<MatchRuleset>,
 ) {
     let mut loading_entities = LoadingEntities::default();
     setup_in_game_entities(
@@ -63,13 +63,15 @@ fn setup_in_game(
         &mut loading_entities,
         &player_info,
         &other_info,
+        ruleset.0.health_count as usize,
+        true,
     );
 
     // --- Resource Insertion ---
     commands.insert_resource(loading_entities);
 }
 
-fn setup_in_game_entities(
+pub(super) fn setup_in_game_entities(
     commands: &mut Commands,
     asset_server: &AssetServer,
     loading_entities: &mut LoadingEntities,
@@ -271,7 +273,7 @@ fn setup_in_game_entities(
                 radius: PROJECTILE_SIZE * 0.5,
             },
             Transform::from_xyz(0.0, 0.0, 0.0),
-            Projectile::default(),
+            Projectile,
             Visibility::Hidden,
             SpawnRequest,
         ))
@@ -279,13 +281,19 @@ fn setup_in_game_entities(
     loading_entities.insert(entity);
 }
 
-fn setup_in_game_interface(
+/// Spawns the HUD of the game.
+/// Only the first `health_count` hearts of each player are shown.
+/// `mark_player` puts a red dot on the side of the player, which spectators do not have.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn setup_in_game_interface(
     commands: &mut Commands,
     asset_server: &AssetServer,
     image_assets: &Assets<Image>,
     loading_entities: &mut LoadingEntities,
     player_info: &PlayerInfo,
     other_info: &OtherInfo,
+    health_count: usize,
+    mark_player: bool,
 ) {
     // --- Spawn Health Bar ---
     let entity = commands
@@ -356,6 +364,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 LeftHealth5,
+                                health_count >= 5,
                                 parent,
                                 loading_entities,
                             );
@@ -364,6 +373,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 LeftHealth4,
+                                health_count >= 4,
                                 parent,
                                 loading_entities,
                             );
@@ -372,6 +382,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 LeftHealth3,
+                                health_count >= 3,
                                 parent,
                                 loading_entities,
                             );
@@ -380,6 +391,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 LeftHealth2,
+                                health_count >= 2,
                                 parent,
                                 loading_entities,
                             );
@@ -388,6 +400,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 LeftHealth1,
+                                health_count >= 1,
                                 parent,
                                 loading_entities,
                             );
@@ -455,6 +468,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 RightHealth1,
+                                health_count >= 1,
                                 parent,
                                 loading_entities,
                             );
@@ -463,6 +477,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 RightHealth2,
+                                health_count >= 2,
                                 parent,
                                 loading_entities,
                             );
@@ -471,6 +486,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 RightHealth3,
+                                health_count >= 3,
                                 parent,
                                 loading_entities,
                             );
@@ -479,6 +495,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 RightHealth4,
+                                health_count >= 4,
                                 parent,
                                 loading_entities,
                             );
@@ -487,6 +504,7 @@ fn setup_in_game_interface(
                                 &texture,
                                 ratio,
                                 RightHealth5,
+                                health_count >= 5,
                                 parent,
                                 loading_entities,
                             );
@@ -707,7 +725,7 @@ fn setup_in_game_interface(
                     SpawnRequest,
                 ))
                 .with_children(|parent| {
-                    if !other_info.left_side {
+                    if mark_player && !other_info.left_side {
                         let entity = parent
                             .spawn((
                                 ImageNode::new(asset_server.load(IMG_PATH_RED_DOT)),
@@ -812,7 +830,7 @@ fn setup_in_game_interface(
                     SpawnRequest,
                 ))
                 .with_children(|parent| {
-                    if other_info.left_side {
+                    if mark_player && other_info.left_side {
                         let entity = parent
                             .spawn((
                                 ImageNode::new(asset_server.load(IMG_PATH_RED_DOT)),
@@ -938,6 +956,7 @@ fn add_health_heart<T: Component>(
     texture: &Handle<Image>,
     ratio: f32,
     tag: T,
+    shown: bool,
     parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
     loading_entities: &mut LoadingEntities,
 ) {
@@ -950,7 +969,10 @@ fn add_health_heart<T: Component>(
                 ..Default::default()
             },
             ImageNode::new(texture.clone()),
-            Visibility::Inherited,
+            match shown {
+                true => Visibility::Inherited,
+                false => Visibility::Hidden,
+            },
             UiAnimationTarget,
             SpawnRequest,
             tag,
@@ -961,7 +983,6 @@ fn add_health_heart<T: Component>(
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
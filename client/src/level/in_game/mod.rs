@@ -2,15 +2,16 @@ mod enter;
 mod init;
 mod prepare;
 mod result;
+mod spectate;
 mod switch;
 
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 use bevy_vector_shapes::prelude::*;
 use protocol::{
-    GRAVITY, Hero, LEFT_CAM_POS_X, LEFT_END_ANGLE, LEFT_PLAYER_POS_Y, LEFT_START_ANGLE,
-    LEFT_THROW_POS_X, LEFT_THROW_POS_Y, MAX_CTRL_TIME, RIGHT_CAM_POS_X, RIGHT_END_ANGLE,
-    RIGHT_START_ANGLE, RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y,
+    Hero, LEFT_CAM_POS_X, LEFT_END_ANGLE, LEFT_PLAYER_POS_Y, LEFT_START_ANGLE, LEFT_THROW_POS_X,
+    LEFT_THROW_POS_Y, PROJECTILE_SIZE, RIGHT_CAM_POS_X, RIGHT_END_ANGLE, RIGHT_START_ANGLE,
+    RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y, Side, TurnState,
 };
 
 use crate::assets::sound::SystemVolume;
@@ -27,6 +28,7 @@ impl Plugin for InnerPlugin {
             .add_plugins(init::InnerPlugin)
             .add_plugins(prepare::InnerPlugin)
             .add_plugins(result::InnerPlugin)
+            .add_plugins(spectate::InnerPlugin)
             .add_plugins(switch::InnerPlugin)
             .add_systems(
                 OnEnter(LevelStates::InGame),
@@ -45,10 +47,7 @@ impl Plugin for InnerPlugin {
                 Update,
                 (
                     update_hud_ingame_timer,
-                    update_hud_player_timer,
                     update_wind_indicator.run_if(resource_exists::<Wind>),
-                    draw_range_indicator,
-                    draw_range_arrow_indicator,
                     update_left_health_heart
                         .run_if(resource_exists_and_changed::<LeftPlayerHealth>),
                     update_right_health_heart
@@ -58,8 +57,19 @@ impl Plugin for InnerPlugin {
                     setup_projectile.run_if(resource_added::<ProjectileObject>),
                     update_projectile
                         .run_if(resource_exists::<Wind>)
-                        .run_if(resource_exists::<ProjectileObject>),
+                        .run_if(resource_exists::<ProjectileObject>)
+                        .run_if(not(replay_paused)),
                     cleanup_projectile.run_if(resource_removed::<ProjectileObject>),
+                    play_hit_reaction.run_if(resource_exists::<ProjectileHit>),
+                )
+                    .run_if(in_state(LevelStates::InGame).or(in_state(LevelStates::Spectate))),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_hud_player_timer,
+                    draw_range_indicator,
+                    draw_range_arrow_indicator,
                     highlight_my_character_position
                         .run_if(not(resource_exists::<TouchPressed>))
                         .run_if(not(resource_exists::<MouseButtonPressed>))
@@ -76,43 +86,37 @@ impl Plugin for InnerPlugin {
                 )
                     .run_if(in_state(LevelStates::InGame)),
             )
-            .add_systems(
-                FixedUpdate,
-                check_collisions.run_if(in_state(LevelStates::InGame)),
-            )
             .add_systems(
                 PostUpdate,
-                (update_camera_position).run_if(in_state(LevelStates::InGame)),
+                (update_camera_position)
+                    .run_if(in_state(LevelStates::InGame).or(in_state(LevelStates::Spectate))),
             );
 
-        #[cfg(target_arch = "wasm32")]
-        {
-            app.add_systems(
-                PreUpdate,
+        app.add_systems(
+            PreUpdate,
+            (
+                update_practice_match.run_if(resource_exists::<PracticeMatch>),
+                handle_received_packets.after(update_practice_match),
                 (
-                    handle_received_packets,
-                    (
-                        handle_mouse_button_pressed
-                            .run_if(not(resource_exists::<MouseButtonPressed>)),
-                        handle_mouse_button_released
-                            .run_if(not(resource_exists::<TouchPressed>))
-                            .run_if(resource_exists::<MouseButtonPressed>),
-                        handle_cursor_movement
-                            .run_if(not(resource_exists::<TouchPressed>))
-                            .run_if(resource_exists::<MouseButtonPressed>),
-                        handle_touch_pressed.run_if(not(resource_exists::<TouchPressed>)),
-                        handle_touch_released
-                            .run_if(not(resource_exists::<MouseButtonPressed>))
-                            .run_if(resource_exists::<TouchPressed>),
-                        handle_touch_movement
-                            .run_if(not(resource_exists::<MouseButtonPressed>))
-                            .run_if(resource_exists::<TouchPressed>),
-                    )
-                        .after(handle_received_packets),
+                    handle_mouse_button_pressed.run_if(not(resource_exists::<MouseButtonPressed>)),
+                    handle_mouse_button_released
+                        .run_if(not(resource_exists::<TouchPressed>))
+                        .run_if(resource_exists::<MouseButtonPressed>),
+                    handle_cursor_movement
+                        .run_if(not(resource_exists::<TouchPressed>))
+                        .run_if(resource_exists::<MouseButtonPressed>),
+                    handle_touch_pressed.run_if(not(resource_exists::<TouchPressed>)),
+                    handle_touch_released
+                        .run_if(not(resource_exists::<MouseButtonPressed>))
+                        .run_if(resource_exists::<TouchPressed>),
+                    handle_touch_movement
+                        .run_if(not(resource_exists::<MouseButtonPressed>))
+                        .run_if(resource_exists::<TouchPressed>),
                 )
-                    .run_if(in_state(LevelStates::InGame)),
-            );
-        }
+                    .after(handle_received_packets),
+            )
+                .run_if(in_state(LevelStates::InGame)),
+        );
     }
 }
 
@@ -122,12 +126,38 @@ fn debug_label() {
     info!("Current Level: InGame");
 }
 
-fn setup_resource(mut commands: Commands) {
-    commands.insert_resource(InGameTimer::default());
-    commands.insert_resource(PlayerTimer::default());
-    commands.insert_resource(LeftPlayerHealth::default());
-    commands.insert_resource(RightPlayerHealth::default());
-    commands.insert_resource(PlaySide::default());
+fn setup_resource(
+    mut commands: Commands,
+    ruleset: Res<MatchRuleset>,
+    resync_info: Option<Res<ResyncInfo>>,
+) {
+    let mut in_game_timer = InGameTimer::default();
+    let mut player_timer = PlayerTimer::default();
+    let mut left_health = LeftPlayerHealth(ruleset.0.health_count as usize);
+    let mut right_health = RightPlayerHealth(ruleset.0.health_count as usize);
+    let mut side = PlaySide::default();
+
+    // --- Returning to a match in progress ---
+    if let Some(info) = resync_info {
+        in_game_timer.miliis = info.total_remaining_millis;
+        player_timer.miliis = info.remaining_millis;
+        left_health.0 = info.left_health_cnt as usize;
+        right_health.0 = info.right_health_cnt as usize;
+        side = match info.turn {
+            TurnState::LeftTurn => PlaySide::Left(None),
+            TurnState::RightTurn => PlaySide::Right(None),
+            TurnState::LeftProjectileThrown => PlaySide::LeftThrown,
+            TurnState::RightProjectileThrown => PlaySide::RightThrown,
+        };
+        commands.insert_resource(Wind::new(info.wind_angle, info.wind_power, &ruleset.0.wind));
+        commands.remove_resource::<ResyncInfo>();
+    }
+
+    commands.insert_resource(in_game_timer);
+    commands.insert_resource(player_timer);
+    commands.insert_resource(left_health);
+    commands.insert_resource(right_health);
+    commands.insert_resource(side);
 }
 
 fn cleanup_title_assets(mut commands: Commands) {
@@ -144,6 +174,7 @@ fn cleanup_title_entities(mut commands: Commands, query: Query<Entity, With<Titl
 
 fn cleanup_resource(mut commands: Commands) {
     commands.remove_resource::<ProjectileObject>();
+    commands.remove_resource::<ProjectileHit>();
     commands.remove_resource::<InGameTimer>();
     commands.remove_resource::<PlayerTimer>();
     commands.remove_resource::<LeftPlayerHealth>();
@@ -162,7 +193,6 @@ fn reset_camera_position(mut query: Query<&mut Transform, With<Camera>>) {
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 #[allow(clippy::too_many_arguments)]
 fn handle_received_packets(
     mut commands: Commands,
@@ -174,107 +204,50 @@ fn handle_received_packets(
     mut in_game_timer: ResMut<InGameTimer>,
     mut projectile: Option<ResMut<ProjectileObject>>,
     mut next_state: ResMut<NextState<LevelStates>>,
+    mut practice: Option<ResMut<PracticeMatch>>,
+    ruleset: Res<MatchRuleset>,
     network: Res<Network>,
 ) {
-    for result in network.try_iter() {
+    let practice_packets = practice.iter_mut().flat_map(|practice| practice.drain());
+    for result in network.try_iter().chain(practice_packets.map(Ok)) {
         match result {
-            Ok(packet) => match packet {
-                Packet::InGameLeftTurn {
-                    total_remaining_millis,
-                    remaining_millis,
-                    left_health_cnt,
-                    right_health_cnt,
-                    control,
-                } => {
-                    *side = PlaySide::Left(control);
-                    in_game_timer.miliis = total_remaining_millis;
-                    player_timer.miliis = remaining_millis;
-                    if left_health.0 != left_health_cnt as usize {
-                        left_health.0 = left_health_cnt as usize;
-                    }
-                    if right_health.0 != right_health_cnt as usize {
-                        right_health.0 = right_health_cnt as usize;
-                    }
-                }
-                Packet::InGameRightTurn {
-                    total_remaining_millis,
-                    remaining_millis,
-                    left_health_cnt,
-                    right_health_cnt,
-                    control,
-                } => {
-                    *side = PlaySide::Right(control);
-                    in_game_timer.miliis = total_remaining_millis;
-                    player_timer.miliis = remaining_millis;
-                    if left_health.0 != left_health_cnt as usize {
-                        left_health.0 = left_health_cnt as usize;
-                    }
-                    if right_health.0 != right_health_cnt as usize {
-                        right_health.0 = right_health_cnt as usize;
-                    }
-                }
-                Packet::InGameTurnSetup {
-                    wind_angle,
-                    wind_power,
-                } => {
-                    commands.insert_resource(Wind::new(wind_angle, wind_power));
-                    commands.remove_resource::<MouseButtonPressed>();
-                    commands.remove_resource::<ProjectileObject>();
-                }
-                Packet::InGameProjectileThrown {
-                    total_remaining_millis,
-                    remaining_millis,
-                    left_health_cnt,
-                    right_health_cnt,
-                    projectile_pos,
-                    projectile_vel,
-                } => {
-                    *side = match *side {
-                        PlaySide::Left(_) => PlaySide::LeftThrown,
-                        PlaySide::Right(_) => PlaySide::RightThrown,
-                        _ => *side,
-                    };
-                    in_game_timer.miliis = total_remaining_millis;
-                    if left_health.0 != left_health_cnt as usize {
-                        left_health.0 = left_health_cnt as usize;
-                    }
-                    if right_health.0 != right_health_cnt as usize {
-                        right_health.0 = right_health_cnt as usize;
-                    }
-
-                    match projectile {
-                        Some(ref mut projectile) => {
-                            projectile.add_snapshot(
-                                total_remaining_millis,
-                                remaining_millis,
-                                projectile_pos.into(),
-                                projectile_vel.into(),
-                            );
-                        }
-                        None => {
-                            commands.insert_resource(ProjectileObject::new(
-                                total_remaining_millis,
-                                remaining_millis,
-                                projectile_pos.into(),
-                                projectile_vel.into(),
-                            ));
+            Ok(packet) => {
+                let packet = apply_match_packet(
+                    &mut commands,
+                    packet,
+                    &mut side,
+                    &mut left_health,
+                    &mut right_health,
+                    &mut player_timer,
+                    &mut in_game_timer,
+                    &mut projectile,
+                    &ruleset,
+                );
+                match packet {
+                    Some(Packet::GameResult {
+                        win,
+                        lose,
+                        draw,
+                        victory,
+                    }) => {
+                        player_info.win = win;
+                        player_info.lose = lose;
+                        player_info.draw = draw;
+                        if victory {
+                            next_state.set(LevelStates::SwitchToGameVictory);
+                        } else {
+                            next_state.set(LevelStates::SwitchToGameDefeat);
                         }
                     }
-                }
-                Packet::GameResult { win, lose, victory } => {
-                    player_info.win = win;
-                    player_info.lose = lose;
-                    if victory {
-                        next_state.set(LevelStates::SwitchToGameVictory);
-                    } else {
-                        next_state.set(LevelStates::SwitchToGameDefeat);
+                    Some(Packet::GameResultDraw { win, lose, draw }) => {
+                        player_info.win = win;
+                        player_info.lose = lose;
+                        player_info.draw = draw;
+                        next_state.set(LevelStates::SwitchToGameDraw);
                     }
+                    _ => { /* empty */ }
                 }
-                Packet::GameResultDraw => {
-                    next_state.set(LevelStates::SwitchToGameDraw);
-                }
-                _ => { /* empty */ }
-            },
+            }
             Err(e) => {
                 commands.insert_resource(ErrorMessage::from(e));
                 next_state.set(LevelStates::Error);
@@ -283,7 +256,119 @@ fn handle_received_packets(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
+fn update_practice_match(mut practice: ResMut<PracticeMatch>, time: Res<Time>) {
+    practice.update(time.delta_secs());
+}
+
+/// Applies a packet of the match to the game state shared by players and spectators.
+/// Returns the packet if it is not about the match.
+/// The resources are only written when they change, so that change detection still works.
+#[allow(clippy::too_many_arguments)]
+fn apply_match_packet(
+    commands: &mut Commands,
+    packet: Packet,
+    side: &mut ResMut<PlaySide>,
+    left_health: &mut ResMut<LeftPlayerHealth>,
+    right_health: &mut ResMut<RightPlayerHealth>,
+    player_timer: &mut ResMut<PlayerTimer>,
+    in_game_timer: &mut ResMut<InGameTimer>,
+    projectile: &mut Option<ResMut<ProjectileObject>>,
+    ruleset: &MatchRuleset,
+) -> Option<Packet> {
+    match packet {
+        Packet::InGameLeftTurn {
+            total_remaining_millis,
+            remaining_millis,
+            left_health_cnt,
+            right_health_cnt,
+            control,
+        } => {
+            **side = PlaySide::Left(control);
+            in_game_timer.miliis = total_remaining_millis;
+            player_timer.miliis = remaining_millis;
+            if left_health.0 != left_health_cnt as usize {
+                left_health.0 = left_health_cnt as usize;
+            }
+            if right_health.0 != right_health_cnt as usize {
+                right_health.0 = right_health_cnt as usize;
+            }
+        }
+        Packet::InGameRightTurn {
+            total_remaining_millis,
+            remaining_millis,
+            left_health_cnt,
+            right_health_cnt,
+            control,
+        } => {
+            **side = PlaySide::Right(control);
+            in_game_timer.miliis = total_remaining_millis;
+            player_timer.miliis = remaining_millis;
+            if left_health.0 != left_health_cnt as usize {
+                left_health.0 = left_health_cnt as usize;
+            }
+            if right_health.0 != right_health_cnt as usize {
+                right_health.0 = right_health_cnt as usize;
+            }
+        }
+        Packet::InGameTurnSetup {
+            wind_angle,
+            wind_power,
+        } => {
+            commands.insert_resource(Wind::new(wind_angle, wind_power, &ruleset.0.wind));
+            commands.remove_resource::<MouseButtonPressed>();
+            commands.remove_resource::<ProjectileObject>();
+        }
+        Packet::InGameProjectileThrown {
+            total_remaining_millis,
+            remaining_millis,
+            left_health_cnt,
+            right_health_cnt,
+            projectile_pos,
+            projectile_vel,
+        } => {
+            **side = match **side {
+                PlaySide::Left(_) => PlaySide::LeftThrown,
+                PlaySide::Right(_) => PlaySide::RightThrown,
+                _ => **side,
+            };
+            in_game_timer.miliis = total_remaining_millis;
+            if left_health.0 != left_health_cnt as usize {
+                left_health.0 = left_health_cnt as usize;
+            }
+            if right_health.0 != right_health_cnt as usize {
+                right_health.0 = right_health_cnt as usize;
+            }
+
+            match projectile {
+                Some(projectile) => {
+                    projectile.add_snapshot(
+                        total_remaining_millis,
+                        remaining_millis,
+                        projectile_pos.into(),
+                        projectile_vel.into(),
+                    );
+                }
+                None => {
+                    commands.insert_resource(ProjectileObject::new(
+                        total_remaining_millis,
+                        remaining_millis,
+                        projectile_pos.into(),
+                        projectile_vel.into(),
+                    ));
+                }
+            }
+        }
+        Packet::InGameHit { target, pos } => {
+            commands.insert_resource(ProjectileHit {
+                target,
+                pos: pos.into(),
+            });
+        }
+        packet => return Some(packet),
+    }
+    None
+}
+
 #[allow(clippy::too_many_arguments)]
 fn handle_mouse_button_pressed(
     mut commands: Commands,
@@ -295,6 +380,7 @@ fn handle_mouse_button_pressed(
     other_info: Res<OtherInfo>,
     play_side: Res<PlaySide>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     match (*play_side, other_info.left_side) {
         (PlaySide::Left(_), false) => {
@@ -306,9 +392,11 @@ fn handle_mouse_button_pressed(
                 && let Ok(point) = camera.viewport_to_world_2d(camera_transform, viewport_position)
                 && Collider2d::contains((collider, transform), point)
             {
-                network
-                    .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle: 0, power: 0 },
+                );
                 commands.insert_resource(MouseButtonPressed);
             }
         }
@@ -321,9 +409,11 @@ fn handle_mouse_button_pressed(
                 && let Ok(point) = camera.viewport_to_world_2d(camera_transform, viewport_position)
                 && Collider2d::contains((collider, transform), point)
             {
-                network
-                    .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle: 0, power: 0 },
+                );
                 commands.insert_resource(MouseButtonPressed);
             }
         }
@@ -331,7 +421,6 @@ fn handle_mouse_button_pressed(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 #[allow(clippy::too_many_arguments)]
 fn handle_mouse_button_released(
     mut commands: Commands,
@@ -340,6 +429,7 @@ fn handle_mouse_button_released(
     mouse_button_events: Res<ButtonInput<MouseButton>>,
     other_info: Res<OtherInfo>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     if mouse_button_events.just_released(MouseButton::Left) {
         commands.remove_resource::<MouseButtonPressed>();
@@ -378,21 +468,23 @@ fn handle_mouse_button_released(
                 (delta * 255.0) as u8
             };
 
-            network
-                .send(&Packet::UpdateThrowParams { angle, power })
-                .unwrap();
+            send_match_packet(
+                &network,
+                &mut practice,
+                Packet::UpdateThrowParams { angle, power },
+            );
         }
-        network.send(&Packet::ThrowProjectile).unwrap();
+        send_match_packet(&network, &mut practice, Packet::ThrowProjectile);
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 fn handle_cursor_movement(
     windows: Query<&Window>,
     cameras: Query<(&Camera, &GlobalTransform)>,
     mut play_side: ResMut<PlaySide>,
     other_info: Res<OtherInfo>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     match (*play_side, other_info.left_side) {
         (PlaySide::Left(_), false) => {
@@ -416,9 +508,11 @@ fn handle_cursor_movement(
                 let angle = (delta * 255.0) as u8;
 
                 *play_side = PlaySide::Left(Some((angle, power)));
-                network
-                    .send(&Packet::UpdateThrowParams { angle, power })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle, power },
+                );
             }
         }
         (PlaySide::Right(_), true) => {
@@ -445,16 +539,17 @@ fn handle_cursor_movement(
                 let angle = (delta * 255.0) as u8;
 
                 *play_side = PlaySide::Right(Some((angle, power)));
-                network
-                    .send(&Packet::UpdateThrowParams { angle, power })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle, power },
+                );
             }
         }
         _ => { /* empty */ }
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 #[allow(clippy::too_many_arguments)]
 fn handle_touch_pressed(
     mut commands: Commands,
@@ -465,6 +560,7 @@ fn handle_touch_pressed(
     other_info: Res<OtherInfo>,
     play_side: Res<PlaySide>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     match (*play_side, other_info.left_side) {
         (PlaySide::Left(_), false) => {
@@ -475,9 +571,11 @@ fn handle_touch_pressed(
                         camera.viewport_to_world_2d(camera_transform, touch.position())
                     && Collider2d::contains((collider, transform), point)
                 {
-                    network
-                        .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
-                        .unwrap();
+                    send_match_packet(
+                        &network,
+                        &mut practice,
+                        Packet::UpdateThrowParams { angle: 0, power: 0 },
+                    );
                     commands.insert_resource(TouchPressed { id: touch.id() });
                     break;
                 }
@@ -491,9 +589,11 @@ fn handle_touch_pressed(
                         camera.viewport_to_world_2d(camera_transform, touch.position())
                     && Collider2d::contains((collider, transform), point)
                 {
-                    network
-                        .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
-                        .unwrap();
+                    send_match_packet(
+                        &network,
+                        &mut practice,
+                        Packet::UpdateThrowParams { angle: 0, power: 0 },
+                    );
                     commands.insert_resource(TouchPressed { id: touch.id() });
                     break;
                 }
@@ -503,7 +603,6 @@ fn handle_touch_pressed(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 #[allow(clippy::too_many_arguments)]
 fn handle_touch_released(
     mut commands: Commands,
@@ -512,6 +611,7 @@ fn handle_touch_released(
     touch_pressed: Res<TouchPressed>,
     other_info: Res<OtherInfo>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     if let Some(touch) = touches.get_released(touch_pressed.id) {
         commands.remove_resource::<TouchPressed>();
@@ -548,15 +648,16 @@ fn handle_touch_released(
                 (delta * 255.0) as u8
             };
 
-            network
-                .send(&Packet::UpdateThrowParams { angle, power })
-                .unwrap();
+            send_match_packet(
+                &network,
+                &mut practice,
+                Packet::UpdateThrowParams { angle, power },
+            );
         }
-        network.send(&Packet::ThrowProjectile).unwrap();
+        send_match_packet(&network, &mut practice, Packet::ThrowProjectile);
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 fn handle_touch_movement(
     cameras: Query<(&Camera, &GlobalTransform)>,
     touches: Res<Touches>,
@@ -564,6 +665,7 @@ fn handle_touch_movement(
     mut play_side: ResMut<PlaySide>,
     other_info: Res<OtherInfo>,
     network: Res<Network>,
+    mut practice: Option<ResMut<PracticeMatch>>,
 ) {
     match (*play_side, other_info.left_side) {
         (PlaySide::Left(_), false) => {
@@ -586,9 +688,11 @@ fn handle_touch_movement(
                 let angle = (delta * 255.0) as u8;
 
                 *play_side = PlaySide::Left(Some((angle, power)));
-                network
-                    .send(&Packet::UpdateThrowParams { angle, power })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle, power },
+                );
             }
         }
         (PlaySide::Right(_), true) => {
@@ -614,17 +718,36 @@ fn handle_touch_movement(
                 let angle = (delta * 255.0) as u8;
 
                 *play_side = PlaySide::Right(Some((angle, power)));
-                network
-                    .send(&Packet::UpdateThrowParams { angle, power })
-                    .unwrap();
+                send_match_packet(
+                    &network,
+                    &mut practice,
+                    Packet::UpdateThrowParams { angle, power },
+                );
             }
         }
         _ => { /* empty */ }
     }
 }
 
+/// Sends the input of the player to the server, or to the practice match when practicing.
+fn send_match_packet(
+    network: &Network,
+    practice: &mut Option<ResMut<PracticeMatch>>,
+    packet: Packet,
+) {
+    match practice {
+        Some(practice) => practice.receive(packet),
+        None => network.send(&packet).unwrap(),
+    }
+}
+
 // --- UPDATE SYSTEMS ---
 
+/// The projectile stops in the air while a replay is paused.
+fn replay_paused(replay: Option<Res<ReplayPlayback>>) -> bool {
+    replay.is_some_and(|replay| replay.is_paused())
+}
+
 fn update_hud_ingame_timer(
     timer: Res<InGameTimer>,
     mut query: Query<&mut Text, With<RemainingTimer>>,
@@ -639,6 +762,7 @@ fn update_hud_player_timer(
     timer: Res<PlayerTimer>,
     side: Res<PlaySide>,
     other_info: Res<OtherInfo>,
+    ruleset: Res<MatchRuleset>,
     mut hud: Query<&mut Visibility, With<UiTurnTimer>>,
     mut timer_bar: Query<(&mut Node, &mut BackgroundColor), With<TurnTimer>>,
 ) {
@@ -652,21 +776,7 @@ fn update_hud_player_timer(
     match (*side, other_info.left_side) {
         (PlaySide::Left(_), false) | (PlaySide::Right(_), true) => {
             *visibility = Visibility::Visible;
-            let p = (timer.miliis as f32 / MAX_CTRL_TIME as f32).clamp(0.0, 1.0);
-            node.width = Val::Percent(p * 100.0);
-
-            const MIN_VAL: f32 = 0.2;
-            const MAX_VAL: f32 = 0.8;
-            let (red, green) = if p < 0.5 {
-                let red = MAX_VAL;
-                let green = MIN_VAL.lerp(MAX_VAL, p * 2.0);
-                (red, green)
-            } else {
-                let red = MAX_VAL.lerp(MIN_VAL, (p - 0.5) * 2.0);
-                let green = MAX_VAL;
-                (red, green)
-            };
-            *color = BackgroundColor(Color::srgb(red, green, MIN_VAL));
+            update_turn_timer_bar(&timer, ruleset.0.max_turn_time, &mut node, &mut color);
         }
         _ => {
             *visibility = Visibility::Hidden;
@@ -674,6 +784,30 @@ fn update_hud_player_timer(
     }
 }
 
+/// Shrinks the turn timer bar, turning it from green to red as the time runs out.
+fn update_turn_timer_bar(
+    timer: &PlayerTimer,
+    max_turn_time: u16,
+    node: &mut Node,
+    color: &mut BackgroundColor,
+) {
+    let p = (timer.miliis as f32 / max_turn_time as f32).clamp(0.0, 1.0);
+    node.width = Val::Percent(p * 100.0);
+
+    const MIN_VAL: f32 = 0.2;
+    const MAX_VAL: f32 = 0.8;
+    let (red, green) = if p < 0.5 {
+        let red = MAX_VAL;
+        let green = MIN_VAL.lerp(MAX_VAL, p * 2.0);
+        (red, green)
+    } else {
+        let red = MAX_VAL.lerp(MIN_VAL, (p - 0.5) * 2.0);
+        let green = MAX_VAL;
+        (red, green)
+    };
+    *color = BackgroundColor(Color::srgb(red, green, MIN_VAL));
+}
+
 fn update_wind_indicator(
     mut query: Query<&mut UiTransform, With<WindIndicator>>,
     wind: Res<Wind>,
@@ -931,24 +1065,19 @@ fn play_swing_sound(
 }
 
 #[allow(clippy::type_complexity)]
-#[allow(clippy::too_many_arguments)]
 fn setup_projectile(
     mut commands: Commands,
     play_side: Res<PlaySide>,
-    other_info: Res<OtherInfo>,
-    player_info: ResMut<PlayerInfo>,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
-    mut query: Query<(
-        &mut Visibility,
-        &mut Sprite,
-        &mut Transform,
-        &mut Projectile,
-    )>,
+    spines: Query<(&Character, &VoiceChannel)>,
+    left_head: Query<&LeftPlayerHead>,
+    right_head: Query<&RightPlayerHead>,
+    mut query: Query<(&mut Visibility, &mut Sprite, &mut Transform), With<Projectile>>,
     projectile: Res<ProjectileObject>,
 ) {
     if let Some(snapshot) = projectile.front()
-        && let Ok((mut visibility, mut sprite, mut transform, mut projectile)) = query.single_mut()
+        && let Ok((mut visibility, mut sprite, mut transform)) = query.single_mut()
     {
         *visibility = Visibility::Hidden;
         sprite.color = sprite.color.with_alpha(1.0);
@@ -956,31 +1085,23 @@ fn setup_projectile(
         transform.translation.x = snapshot.position.x;
         transform.translation.y = snapshot.position.y;
         transform.translation.z = 0.8;
-        projectile.hit = false;
 
-        match (*play_side, other_info.left_side) {
-            (PlaySide::LeftThrown, false) | (PlaySide::RightThrown, true) => {
-                let hero = player_info.hero;
-                let path = HERO_VOICE_SETS[hero as usize]
-                    .shout()
-                    .choose(&mut rand::rng())
-                    .copied()
-                    .unwrap();
-                let source = asset_server.load(path);
-                play_voice_sound(&mut commands, &system_volume, source, VoiceChannel::MySelf);
-            }
-            (PlaySide::LeftThrown, true) | (PlaySide::RightThrown, false) => {
-                let hero = other_info.hero;
-                let path = HERO_VOICE_SETS[hero as usize]
-                    .shout()
-                    .choose(&mut rand::rng())
-                    .copied()
-                    .unwrap();
-                let source = asset_server.load(path);
-                play_voice_sound(&mut commands, &system_volume, source, VoiceChannel::Other);
-            }
-            _ => { /* empty */ }
+        // The thrower is found by side, since spectators are on neither side.
+        let thrower = match *play_side {
+            PlaySide::LeftThrown => left_head.single().ok().map(|head| head.0),
+            PlaySide::RightThrown => right_head.single().ok().map(|head| head.0),
+            _ => None,
         };
+        if let Some((&character, &channel)) = thrower.and_then(|entity| spines.get(entity).ok()) {
+            let hero: Hero = character.into();
+            let path = HERO_VOICE_SETS[hero as usize]
+                .shout()
+                .choose(&mut rand::rng())
+                .copied()
+                .unwrap();
+            let source = asset_server.load(path);
+            play_voice_sound(&mut commands, &system_volume, source, channel);
+        }
     }
 }
 
@@ -989,6 +1110,7 @@ fn update_projectile(
     mut projectile: ResMut<ProjectileObject>,
     play_side: Res<PlaySide>,
     wind: Res<Wind>,
+    ruleset: Res<MatchRuleset>,
     time: Res<Time>,
 ) {
     let elapsed_time = time.delta().as_millis().min(i32::MAX as u128) as i32;
@@ -1019,19 +1141,18 @@ fn update_projectile(
             }
         }
         (Some(prev), None) => {
+            // Runs the simulation of the server from the last snapshot until the next one arrives.
             let t = prev.timepoint - timepoint;
-            let delta_seconds = t as f32 / 1000.0;
-            let mut position = prev.position;
-            let mut velocity = prev.velocity;
-            let wind_vel = wind.velocity();
-            velocity += GRAVITY * delta_seconds;
-            position += (velocity + wind_vel) * delta_seconds;
+            let mut simulation = protocol::Projectile::from_snapshot(
+                prev.position,
+                prev.velocity,
+                wind.velocity(),
+                &ruleset.0,
+            );
+            simulation.advance(t as f32 / 1000.0);
+            let position = simulation.pos;
             let alpha = projectile.get_alpha();
 
-            if position.y <= LEFT_PLAYER_POS_Y {
-                position.y = LEFT_PLAYER_POS_Y;
-            }
-
             if let Ok((mut sprite, mut visibility, mut transform)) = query.single_mut() {
                 sprite.color = sprite.color.with_alpha(alpha);
                 *visibility = Visibility::Visible;
@@ -1089,9 +1210,8 @@ fn update_camera_position(
 }
 
 #[allow(clippy::too_many_arguments)]
-fn check_collisions(
+fn play_hit_reaction(
     mut commands: Commands,
-    play_side: Res<PlaySide>,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
     voices: Query<(Entity, &VoiceSound)>,
@@ -1101,72 +1221,43 @@ fn check_collisions(
         &VoiceChannel,
         &mut CharacterAnimState,
     )>,
-    left_collider: Query<(&Collider2d, &GlobalTransform, &LeftPlayerHead)>,
-    right_collider: Query<(&Collider2d, &GlobalTransform, &RightPlayerHead)>,
-    mut projectile: Query<(&Collider2d, &GlobalTransform, &mut Projectile)>,
+    left_head: Query<&LeftPlayerHead>,
+    right_head: Query<&RightPlayerHead>,
+    projectile: Query<&Transform, With<Projectile>>,
+    projectile_object: Option<Res<ProjectileObject>>,
+    hit: Res<ProjectileHit>,
 ) {
-    match *play_side {
-        PlaySide::LeftThrown => {
-            if let Ok((projectile_collider, projectile_transform, mut projectile)) =
-                projectile.single_mut()
-                && !projectile.hit
-                && let Ok((hero_collider, hero_transform, parent)) = right_collider.single()
-                && Collider2d::intersects(
-                    (hero_collider, hero_transform),
-                    (projectile_collider, projectile_transform),
-                )
-                && let Ok((mut spine, character, channel, mut anim_state)) =
-                    spines.get_mut(parent.0)
-            {
-                projectile.hit = true;
-                *anim_state = CharacterAnimState::InGameHit1;
-                play_character_animation(&mut spine, *character, *anim_state);
-
-                cleanup_voices(channel, &mut commands, &voices);
-                let hero: Hero = (*character).into();
-                let path = HERO_VOICE_SETS[hero as usize]
-                    .hit()
-                    .choose(&mut rand::rng())
-                    .copied()
-                    .unwrap();
-                let source = asset_server.load(path);
-                play_voice_sound(&mut commands, &system_volume, source, *channel);
-
-                let source = asset_server.load(SFX_PATH_EMOTICON_HIT);
-                play_effect_sound(&mut commands, &system_volume, source);
-            }
-        }
-        PlaySide::RightThrown => {
-            if let Ok((projectile_collider, projectile_transform, mut projectile)) =
-                projectile.single_mut()
-                && !projectile.hit
-                && let Ok((hero_collider, hero_transform, parent)) = left_collider.single()
-                && Collider2d::intersects(
-                    (hero_collider, hero_transform),
-                    (projectile_collider, projectile_transform),
-                )
-                && let Ok((mut spine, character, channel, mut anim_state)) =
-                    spines.get_mut(parent.0)
-            {
-                projectile.hit = true;
-                *anim_state = CharacterAnimState::InGameHit1;
-                play_character_animation(&mut spine, *character, *anim_state);
-
-                cleanup_voices(channel, &mut commands, &voices);
-                let hero: Hero = (*character).into();
-                let path = HERO_VOICE_SETS[hero as usize]
-                    .hit()
-                    .choose(&mut rand::rng())
-                    .copied()
-                    .unwrap();
-                let source = asset_server.load(path);
-                play_voice_sound(&mut commands, &system_volume, source, *channel);
-
-                let source = asset_server.load(SFX_PATH_EMOTICON_HIT);
-                play_effect_sound(&mut commands, &system_volume, source);
-            }
-        }
-        _ => { /* empty */ }
+    // The projectile is drawn a little behind the server, so the hit waits for it to arrive.
+    if projectile_object.is_some()
+        && let Ok(transform) = projectile.single()
+        && transform.translation.xy().distance(hit.pos) > PROJECTILE_SIZE
+    {
+        return;
+    }
+    commands.remove_resource::<ProjectileHit>();
+
+    let head = match hit.target {
+        Side::Left => left_head.single().ok().map(|head| head.0),
+        Side::Right => right_head.single().ok().map(|head| head.0),
+    };
+    if let Some(entity) = head
+        && let Ok((mut spine, character, channel, mut anim_state)) = spines.get_mut(entity)
+    {
+        *anim_state = CharacterAnimState::InGameHit1;
+        play_character_animation(&mut spine, *character, *anim_state);
+
+        cleanup_voices(channel, &mut commands, &voices);
+        let hero: Hero = (*character).into();
+        let path = HERO_VOICE_SETS[hero as usize]
+            .hit()
+            .choose(&mut rand::rng())
+            .copied()
+            .unwrap();
+        let source = asset_server.load(path);
+        play_voice_sound(&mut commands, &system_volume, source, *channel);
+
+        let source = asset_server.load(SFX_PATH_EMOTICON_HIT);
+        play_effect_sound(&mut commands, &system_volume, source);
     }
 }
 
@@ -28,7 +28,6 @@ impl Plugin for InnerPlugin {
                 .run_if(in_state(LevelStates::InitPrepareGame)),
         );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             PreUpdate,
             handle_received_packets.run_if(in_state(LevelStates::InitPrepareGame)),
@@ -218,8 +217,8 @@ fn setup_in_prepare_interface(
                                         .spawn((
                                             Node::default(),
                                             Text::new(format!(
-                                                "Win:{} Lose:{}",
-                                                player_info.win, player_info.lose
+                                                "Win:{} Lose:{} Draw:{}",
+                                                player_info.win, player_info.lose, player_info.draw
                                             )),
                                             TextFont::from(asset_server.load(FONT_PATH)),
                                             TextLayout::new_with_justify(Justify::Center),
@@ -377,8 +376,8 @@ fn setup_in_prepare_interface(
                                         .spawn((
                                             Node::default(),
                                             Text::new(format!(
-                                                "Win:{} Lose:{}",
-                                                other_info.win, other_info.lose
+                                                "Win:{} Lose:{} Draw:{}",
+                                                other_info.win, other_info.lose, other_info.draw
                                             )),
                                             TextFont::from(asset_server.load(FONT_PATH)),
                                             TextLayout::new_with_justify(Justify::Center),
@@ -416,7 +415,6 @@ fn setup_loading_minimi(
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -52,7 +52,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::InPrepareGame)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             packet_receive_loop.run_if(in_state(LevelStates::InPrepareGame)),
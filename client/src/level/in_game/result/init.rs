@@ -1,5 +1,5 @@
 // Import necessary Bevy modules.
-use bevy::prelude::*;
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 use bevy_spine::{SkeletonController, SpineBundle, SpineReadyEvent, SpineSync};
 
 use super::*;
@@ -30,7 +30,6 @@ impl Plugin for InnerPlugin {
                 .run_if(in_state(LevelStates::InitGameResult)),
         );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             PreUpdate,
             handle_received_packets.run_if(in_state(LevelStates::InitGameResult)),
@@ -253,6 +252,20 @@ fn setup_game_victory_interface(
                             SpawnRequest,
                         ))
                         .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Text::new("-"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                    GameResultRecordText,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+
                             let entity = parent
                                 .spawn((
                                     Text::new("Press Any Key To Continue"),
@@ -272,6 +285,8 @@ fn setup_game_victory_interface(
                 })
                 .id();
             loading_entities.insert(entity);
+
+            add_rematch_button(loading_entities, parent, asset_server);
         })
         .id();
     loading_entities.insert(entity);
@@ -389,6 +404,20 @@ fn setup_game_defeat_interface(
                             SpawnRequest,
                         ))
                         .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Text::new("-"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                    GameResultRecordText,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+
                             let entity = parent
                                 .spawn((
                                     Text::new("Press Any Key To Continue"),
@@ -408,6 +437,8 @@ fn setup_game_defeat_interface(
                 })
                 .id();
             loading_entities.insert(entity);
+
+            add_rematch_button(loading_entities, parent, asset_server);
         })
         .id();
     loading_entities.insert(entity);
@@ -525,6 +556,20 @@ fn setup_game_draw_interface(
                             SpawnRequest,
                         ))
                         .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Text::new("-"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                    GameResultRecordText,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+
                             let entity = parent
                                 .spawn((
                                     Text::new("Press Any Key To Continue"),
@@ -544,6 +589,84 @@ fn setup_game_draw_interface(
                 })
                 .id();
             loading_entities.insert(entity);
+
+            add_rematch_button(loading_entities, parent, asset_server);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds the rematch button to the corner of a game result interface.
+/// Each status of the rematch has its own label, and only the current one is displayed.
+fn add_rematch_button(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+) {
+    const LABELS: [(RematchStatus, &str, &str); 4] = [
+        (RematchStatus::Available, "Rematch", "rematch"),
+        (RematchStatus::Offered, "Waiting...", "rematch_waiting"),
+        (
+            RematchStatus::OpponentOffered,
+            "Accept Rematch",
+            "rematch_accept",
+        ),
+        (
+            RematchStatus::Declined,
+            "Rematch Declined",
+            "rematch_declined",
+        ),
+    ];
+
+    let entity = parent
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(22.0),
+                height: Val::Vh(9.0),
+                right: Val::Vw(4.0),
+                bottom: Val::Vh(6.0),
+                border: UiRect::all(Val::VMin(0.8)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(30.0)),
+            OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+            BorderColor::all(BORDER_YELLO_COLOR_0),
+            BackgroundColor(BG_YELLO_COLOR_0),
+            Visibility::Inherited,
+            UiAnimationTarget,
+            RematchButton,
+            SpawnRequest,
+            Button,
+        ))
+        .with_children(|parent| {
+            for (status, label, key) in LABELS {
+                let display = match status {
+                    RematchStatus::Available => Display::Flex,
+                    _ => Display::None,
+                };
+                let entity = parent
+                    .spawn((
+                        Node {
+                            display,
+                            ..Default::default()
+                        },
+                        Text::new(label),
+                        TextFont::from(asset_server.load(FONT_PATH)),
+                        TextLayout::new_with_justify(Justify::Center),
+                        TranslatableText(key.into()),
+                        ResizableFont::vertical(1280.0, 36.0),
+                        OriginColor::<TextColor>::new(Color::BLACK),
+                        TextColor::BLACK,
+                        Visibility::Inherited,
+                        RematchText(status),
+                        SpawnRequest,
+                    ))
+                    .id();
+                loading_entities.insert(entity);
+            }
         })
         .id();
     loading_entities.insert(entity);
@@ -568,7 +691,6 @@ fn cleanup_sync_flags(mut commands: Commands) {
 
 // --- PREUPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -587,6 +709,27 @@ fn handle_received_packets(
                 Packet::PrepareInGame => {
                     next_state.set(LevelStates::SwitchToInPrepare);
                 }
+                Packet::InGameResync {
+                    turn,
+                    total_remaining_millis,
+                    remaining_millis,
+                    left_health_cnt,
+                    right_health_cnt,
+                    wind_angle,
+                    wind_power,
+                } => {
+                    commands.insert_resource(ResyncInfo {
+                        turn,
+                        total_remaining_millis,
+                        remaining_millis,
+                        left_health_cnt,
+                        right_health_cnt,
+                        wind_angle,
+                        wind_power,
+                    });
+                    next_state.set(LevelStates::SwitchToInGame);
+                    break; // The rest of the packets are handled in the game.
+                }
                 _ => { /* empty */ }
             },
             Err(e) => {
@@ -638,12 +781,17 @@ fn observe_entiey_creation(
 
 fn check_loading_progress(
     mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
     loading_entities: Res<LoadingEntities>,
-    #[cfg(target_arch = "wasm32")] network: Res<Network>,
+    practice: Option<Res<PracticeMatch>>,
+    network: Res<Network>,
 ) {
     if loading_entities.is_empty() {
-        #[cfg(target_arch = "wasm32")]
-        network.send(&Packet::GameLoadSuccess).unwrap();
+        // A practice match does not wait for the server.
+        match practice {
+            Some(_) => next_state.set(LevelStates::SwitchToInPrepare),
+            None => network.send(&Packet::GameLoadSuccess).unwrap(),
+        }
         commands.insert_resource(SyncFlags);
     }
 }
@@ -5,6 +5,7 @@ mod switch;
 use bevy::{
     input::{ButtonState, mouse::MouseButtonInput, touch::TouchPhase},
     prelude::*,
+    ui::UiSystems,
 };
 
 use super::*;
@@ -17,14 +18,27 @@ impl Plugin for InnerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(init::InnerPlugin)
             .add_plugins(switch::InnerPlugin)
-            .add_systems(OnEnter(LevelStates::InGameResult), debug_label)
+            .add_systems(
+                OnEnter(LevelStates::InGameResult),
+                (
+                    debug_label,
+                    setup_rematch_status,
+                    hide_rematch_button.run_if(resource_exists::<PracticeMatch>),
+                ),
+            )
             .add_systems(
                 OnExit(LevelStates::InGameResult),
                 (
-                    setup_loading_screen,
-                    cleanup_in_game_assets,
+                    (
+                        setup_loading_screen,
+                        cleanup_in_game_assets,
+                        send_rematch_decline_message.run_if(not(resource_exists::<PracticeMatch>)),
+                    )
+                        .run_if(not(resource_equals(RematchStatus::Accepted))),
+                    cleanup_practice_match.after(send_rematch_decline_message),
                     cleanup_in_game_entities,
                     cleanup_background_sounds,
+                    cleanup_rematch_status,
                 ),
             )
             .add_systems(
@@ -33,7 +47,9 @@ impl Plugin for InnerPlugin {
                     handle_keyboard_inputs,
                     handle_mouse_inputs,
                     handle_touch_inputs,
+                    handle_rematch_button_pressed,
                 )
+                    .after(UiSystems::Focus)
                     .run_if(in_state(LevelStates::InGameResult)),
             )
             .add_systems(
@@ -44,14 +60,14 @@ impl Plugin for InnerPlugin {
                     removed_grabbed_component,
                     update_spine_bone_position,
                     update_spine_bone_position_for_mobile,
+                    update_rematch_interfaces.run_if(resource_exists_and_changed::<RematchStatus>),
                 )
                     .run_if(in_state(LevelStates::InGameResult)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
-            packet_receive_loop.run_if(in_state(LevelStates::InGameResult)),
+            handle_received_packets.run_if(in_state(LevelStates::InGameResult)),
         );
     }
 }
@@ -62,6 +78,17 @@ fn debug_label() {
     info!("Current Level: InGameResult");
 }
 
+fn setup_rematch_status(mut commands: Commands) {
+    commands.insert_resource(RematchStatus::Available);
+}
+
+/// A practice match against the bot cannot be played again from the result screen.
+fn hide_rematch_button(mut query: Query<&mut Node, With<RematchButton>>) {
+    for mut node in query.iter_mut() {
+        node.display = Display::None;
+    }
+}
+
 // --- CLEANUP SYSTEMS ---
 
 fn cleanup_in_game_assets(mut commands: Commands) {
@@ -80,6 +107,20 @@ fn cleanup_background_sounds(mut commands: Commands, query: Query<Entity, With<B
     }
 }
 
+fn cleanup_rematch_status(mut commands: Commands) {
+    commands.remove_resource::<RematchStatus>();
+}
+
+fn cleanup_practice_match(mut commands: Commands) {
+    commands.remove_resource::<PracticeMatch>();
+}
+
+/// Lets the opponent know that the player has left the result screen.
+fn send_rematch_decline_message(network: Res<Network>) {
+    let packet = Packet::RematchDecline;
+    network.send(&packet).unwrap();
+}
+
 // --- PREUPDATE SYSTEMS ---
 
 fn handle_keyboard_inputs(
@@ -99,6 +140,7 @@ fn handle_mouse_inputs(
     mut button_inputs: MessageReader<MouseButtonInput>,
     collider_query: Query<(Entity, &Collider2d, &GlobalTransform)>,
     grabbed_query: Query<Entity, With<Grabbed>>,
+    rematch_query: Query<&Interaction, With<RematchButton>>,
     mut next_state: ResMut<NextState<LevelStates>>,
 ) {
     let Ok(window) = windows.single() else { return };
@@ -120,7 +162,9 @@ fn handle_mouse_inputs(
                             continue 'input;
                         }
                     }
-                    next_state.set(LevelStates::LoadTitle);
+                    if !is_rematch_button_touched(&rematch_query) {
+                        next_state.set(LevelStates::LoadTitle);
+                    }
                 }
             }
             (MouseButton::Left, ButtonState::Released) => {
@@ -139,6 +183,7 @@ fn handle_touch_inputs(
     mut touch_inputs: MessageReader<TouchInput>,
     collider_query: Query<(Entity, &Collider2d, &GlobalTransform)>,
     grabbed_query: Query<Entity, With<Grabbed>>,
+    rematch_query: Query<&Interaction, With<RematchButton>>,
     mut next_state: ResMut<NextState<LevelStates>>,
 ) {
     let Ok((camera, camera_transform)) = cameras.single() else {
@@ -157,7 +202,9 @@ fn handle_touch_inputs(
                             continue 'input;
                         }
                     }
-                    next_state.set(LevelStates::LoadTitle);
+                    if !is_rematch_button_touched(&rematch_query) {
+                        next_state.set(LevelStates::LoadTitle);
+                    }
                 }
             }
             TouchPhase::Ended => {
@@ -169,3 +216,134 @@ fn handle_touch_inputs(
         }
     }
 }
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_rematch_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    mut rematch_status: ResMut<RematchStatus>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    interaction_query: Query<
+        (Entity, &Interaction),
+        (With<RematchButton>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, interaction) in interaction_query.iter() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match interaction {
+            Interaction::Pressed => {
+                if matches!(
+                    *rematch_status,
+                    RematchStatus::Available | RematchStatus::OpponentOffered
+                ) {
+                    send_rematch_offer_message(&network);
+                    rematch_status.set_if_neq(RematchStatus::Offered);
+                }
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn update_rematch_interfaces(
+    rematch_status: Res<RematchStatus>,
+    mut query: Query<(&mut Node, &RematchText)>,
+) {
+    for (mut node, text) in query.iter_mut() {
+        node.display = match text.0 == *rematch_status {
+            true => Display::Flex,
+            false => Display::None,
+        };
+    }
+}
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    mut rematch_status: ResMut<RematchStatus>,
+    network: Res<Network>,
+    player_info: Res<PlayerInfo>,
+    other_info: Res<OtherInfo>,
+) {
+    for result in network.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::RematchOffered => {
+                    if *rematch_status == RematchStatus::Available {
+                        rematch_status.set_if_neq(RematchStatus::OpponentOffered);
+                    }
+                }
+                Packet::RematchDeclined => {
+                    rematch_status.set_if_neq(RematchStatus::Declined);
+                }
+                Packet::MatchingSuccess {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    // The assets of the last game are reused if the opponent plays the same hero.
+                    let info = OtherInfo::new(player_info.uuid, left, right);
+                    match info.hero == other_info.hero {
+                        true => next_state.set(LevelStates::InitGame),
+                        false => next_state.set(LevelStates::LoadGame),
+                    }
+                    commands.insert_resource(info);
+                    commands.insert_resource(MatchRuleset(*ruleset));
+                    rematch_status.set_if_neq(RematchStatus::Accepted);
+                }
+                Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(RejoinInfo {
+                        left,
+                        right,
+                        ruleset: *ruleset,
+                    });
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+                return;
+            }
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Returns `true` if the pointer is on the rematch button,
+/// so that pressing the button does not leave the result screen.
+fn is_rematch_button_touched(query: &Query<&Interaction, With<RematchButton>>) -> bool {
+    query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+}
+
+fn send_rematch_offer_message(network: &Network) {
+    let packet = Packet::RematchOffer;
+    network.send(&packet).unwrap();
+}
@@ -15,6 +15,26 @@ impl Plugin for InnerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(defeat::InnerPlugin)
             .add_plugins(draw::InnerPlugin)
-            .add_plugins(victory::InnerPlugin);
+            .add_plugins(victory::InnerPlugin)
+            .add_systems(
+                OnEnter(LevelStates::SwitchToGameVictory),
+                update_record_text,
+            )
+            .add_systems(OnEnter(LevelStates::SwitchToGameDefeat), update_record_text)
+            .add_systems(OnEnter(LevelStates::SwitchToGameDraw), update_record_text);
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn update_record_text(
+    player_info: Res<PlayerInfo>,
+    mut query: Query<&mut Text, With<GameResultRecordText>>,
+) {
+    for mut text in query.iter_mut() {
+        *text = Text::new(format!(
+            "Win:{} Lose:{} Draw:{}",
+            player_info.win, player_info.lose, player_info.draw
+        ));
     }
 }
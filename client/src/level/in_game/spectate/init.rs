@@ -0,0 +1,374 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+use bevy_spine::{SkeletonController, SpineReadyEvent};
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::InitSpectate),
+            (debug_label, setup_spectate),
+        )
+        .add_systems(
+            OnExit(LevelStates::InitSpectate),
+            (cleanup_loading_resource, cleanup_sync_flags),
+        )
+        .add_systems(
+            Update,
+            (
+                update_spawn_progress,
+                observe_entiey_creation,
+                check_loading_progress.run_if(not(resource_exists::<SyncFlags>)),
+                play_animation,
+                update_loading_minimi,
+            )
+                .run_if(in_state(LevelStates::InitSpectate)),
+        );
+
+        app.add_systems(
+            PreUpdate,
+            handle_received_packets.run_if(in_state(LevelStates::InitSpectate)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitSpectate");
+}
+
+fn setup_spectate(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    image_assets: Res<Assets<Image>>,
+    spectate_info: Res<SpectateInfo>,
+    ruleset: Res<MatchRuleset>,
+    replay: Option<Res<ReplayPlayback>>,
+) {
+    // The stage is built as seen by the right player, without marking either of them.
+    let right = &spectate_info.right;
+    let player_info = PlayerInfo {
+        uuid: right.uuid.unwrap_or_default(),
+        name: right.name.clone(),
+        hero: right.hero,
+        win: right.win,
+        lose: right.lose,
+        draw: right.draw,
+    };
+    let left = &spectate_info.left;
+    let other_info = OtherInfo {
+        left_side: true,
+        name: left.name.clone(),
+        hero: left.hero,
+        win: left.win,
+        lose: left.lose,
+        draw: left.draw,
+    };
+
+    let mut loading_entities = LoadingEntities::default();
+    setup_in_game_entities(
+        &mut commands,
+        &asset_server,
+        &mut loading_entities,
+        &player_info,
+        &other_info,
+    );
+    setup_in_game_interface(
+        &mut commands,
+        &asset_server,
+        &image_assets,
+        &mut loading_entities,
+        &player_info,
+        &other_info,
+        ruleset.0.health_count as usize,
+        false,
+    );
+    setup_stop_watching_button(&mut commands, &asset_server, &mut loading_entities);
+    if replay.is_some() {
+        setup_replay_controls(&mut commands, &asset_server, &mut loading_entities);
+    }
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+/// A spectator has no turn to play, so the screen only has a button to leave the match.
+fn setup_stop_watching_button(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(22.0),
+                height: Val::Vh(9.0),
+                right: Val::Vw(4.0),
+                bottom: Val::Vh(6.0),
+                border: UiRect::all(Val::VMin(0.8)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(30.0)),
+            OriginColor::<BackgroundColor>::new(BG_RED_COLOR_0),
+            BorderColor::all(BORDER_RED_COLOR_0),
+            BackgroundColor(BG_RED_COLOR_0),
+            Visibility::Hidden,
+            UiAnimationTarget,
+            StopWatchingButton,
+            SpawnRequest,
+            Button,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new("Stop Watching"),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TranslatableText("stop_watching".into()),
+                    ResizableFont::vertical(1280.0, 36.0),
+                    OriginColor::<TextColor>::new(Color::WHITE),
+                    TextColor::WHITE,
+                    Visibility::Inherited,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// A replay can be paused and moved back and forth in time.
+fn setup_replay_controls(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(40.0),
+                height: Val::Vh(9.0),
+                left: Val::Vw(4.0),
+                bottom: Val::Vh(6.0),
+                column_gap: Val::Vw(1.0),
+                justify_content: JustifyContent::Start,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            UiAnimationTarget,
+            SpawnRequest,
+        ))
+        .with_children(|parent| {
+            for (button, key) in [
+                (ReplayButton::Backward, "replay_backward"),
+                (ReplayButton::Pause, "replay_pause"),
+                (ReplayButton::Forward, "replay_forward"),
+            ] {
+                let entity = parent
+                    .spawn((
+                        Node {
+                            width: Val::Vw(9.0),
+                            height: Val::Percent(100.0),
+                            border: UiRect::all(Val::VMin(0.8)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        BorderRadius::all(Val::Percent(30.0)),
+                        OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+                        BorderColor::all(BORDER_YELLO_COLOR_0),
+                        BackgroundColor(BG_YELLO_COLOR_0),
+                        Visibility::Inherited,
+                        SpawnRequest,
+                        button,
+                        Button,
+                    ))
+                    .with_children(|parent| {
+                        let mut commands = parent.spawn((
+                            Node::default(),
+                            Text::new(key),
+                            TextFont::from(asset_server.load(FONT_PATH)),
+                            TextLayout::new_with_justify(Justify::Center),
+                            TranslatableText(key.into()),
+                            ResizableFont::vertical(1280.0, 32.0),
+                            OriginColor::<TextColor>::new(Color::BLACK),
+                            TextColor::BLACK,
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ));
+                        if button == ReplayButton::Pause {
+                            commands.insert(ReplayPauseText);
+                        }
+                        loading_entities.insert(commands.id());
+                    })
+                    .id();
+                loading_entities.insert(entity);
+            }
+
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new("0:00 / 0:00"),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    ResizableFont::vertical(1280.0, 32.0),
+                    TextColor::WHITE,
+                    Visibility::Inherited,
+                    ReplayTimeText,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- CLEANUP SYSTEMS ---
+
+fn cleanup_sync_flags(mut commands: Commands) {
+    commands.remove_resource::<SyncFlags>();
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+    mut replay: Option<ResMut<ReplayPlayback>>,
+    query: Query<Entity, With<InGameLevelRoot>>,
+) {
+    let replay_packets = replay.iter_mut().flat_map(|replay| replay.drain());
+    for result in network.try_iter().chain(replay_packets.map(Ok)) {
+        match result {
+            Ok(packet) => match packet {
+                Packet::InGameResync {
+                    turn,
+                    total_remaining_millis,
+                    remaining_millis,
+                    left_health_cnt,
+                    right_health_cnt,
+                    wind_angle,
+                    wind_power,
+                } => {
+                    commands.insert_resource(ResyncInfo {
+                        turn,
+                        total_remaining_millis,
+                        remaining_millis,
+                        left_health_cnt,
+                        right_health_cnt,
+                        wind_angle,
+                        wind_power,
+                    });
+                    next_state.set(LevelStates::SwitchToInGame);
+                    break; // The rest of the packets are handled in the game.
+                }
+                Packet::WatchEnded => {
+                    for entity in query.iter() {
+                        commands.entity(entity).despawn();
+                    }
+                    leave_ended_match(&mut commands, &mut next_state);
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn update_spawn_progress(
+    loading_assets: Res<LoadingEntities>,
+    mut set: ParamSet<(
+        Query<&mut Node, With<EnterGameLoadingBar>>,
+        Query<&mut Node, With<EnterGameLoadingCursor>>,
+    )>,
+) {
+    let progress = loading_assets.percent();
+
+    if let Ok(mut node) = set.p0().single_mut() {
+        node.width = Val::Percent(progress * 100.0);
+    }
+
+    if let Ok(mut node) = set.p1().single_mut() {
+        node.left = Val::Percent(progress * 100.0);
+    }
+}
+
+fn observe_entiey_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(InGameLevelEntity);
+        if child_of.is_none() {
+            commands.insert(InGameLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    mut commands: Commands,
+    loading_entities: Res<LoadingEntities>,
+    network: Res<Network>,
+    replay: Option<ResMut<ReplayPlayback>>,
+) {
+    if loading_entities.is_empty() {
+        match replay {
+            // A replay starts from the beginning without asking the server.
+            Some(mut replay) => replay.seek(0),
+            None => network.send(&Packet::GameLoadSuccess).unwrap(),
+        }
+        commands.insert_resource(SyncFlags);
+    }
+}
+
+#[allow(unreachable_patterns)]
+fn play_animation(
+    mut commands: Commands,
+    mut spine_ready_event: MessageReader<SpineReadyEvent>,
+    mut spine_query: Query<&mut Spine>,
+) {
+    for event in spine_ready_event.read() {
+        let mut spine = spine_query.get_mut(event.entity).unwrap();
+        let Spine(SkeletonController {
+            skeleton,
+            animation_state,
+            ..
+        }) = spine.as_mut();
+
+        skeleton.set_skin_by_name("Normal").unwrap();
+        animation_state
+            .set_animation_by_name(0, IDLE, true)
+            .unwrap();
+
+        commands
+            .entity(event.entity)
+            .insert((CharacterAnimState::InGame, SpawnRequest));
+    }
+}
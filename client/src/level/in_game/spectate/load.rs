@@ -0,0 +1,127 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::LoadSpectate),
+            (
+                debug_label,
+                setup_timeout_retry,
+                load_necessary_assets,
+                setup_loading_minimi,
+            ),
+        )
+        .add_systems(OnExit(LevelStates::LoadSpectate), cleanup_timeout_retry)
+        .add_systems(
+            Update,
+            (
+                check_loading_progress,
+                check_and_retry_asset_load_timeout,
+                update_loading_progress,
+                update_loading_minimi,
+            )
+                .run_if(in_state(LevelStates::LoadSpectate)),
+        );
+
+        app.add_systems(
+            PreUpdate,
+            handle_received_packets.run_if(in_state(LevelStates::LoadSpectate)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: LoadSpectate");
+}
+
+fn load_necessary_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    spectate_info: Res<SpectateInfo>,
+) {
+    load_assets(
+        &mut commands,
+        &asset_server,
+        &[spectate_info.left.hero, spectate_info.right.hero],
+    );
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+) {
+    for result in network.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::WatchEnded => {
+                    leave_ended_match(&mut commands, &mut next_state);
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn check_loading_progress(
+    asset_server: Res<AssetServer>,
+    loading_assets: Res<InGameAssets>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    let all_loaded = loading_assets
+        .ids()
+        .iter()
+        .all(|&id| asset_server.is_loaded_with_dependencies(id));
+
+    if all_loaded {
+        next_state.set(LevelStates::InitSpectate);
+    }
+}
+
+fn check_and_retry_asset_load_timeout(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    mut counter: ResMut<RetryCounter>,
+    mut scene_timer: ResMut<SceneTimer>,
+    spectate_info: Res<SpectateInfo>,
+    time: Res<Time>,
+) {
+    scene_timer.tick(time.delta_secs());
+    if scene_timer.elapsed_sec() >= TIMEOUT {
+        scene_timer.reset();
+
+        counter.0 += 1;
+        if counter.0 > MAX_RETRY_COUNT {
+            error!("Asset load request timed out.");
+            commands.insert_resource(ErrorMessage::new(
+                "asset_load_timeout",
+                "Asset load request timed out.\nPlease refresh your browser.",
+            ));
+            next_state.set(LevelStates::Error);
+        } else {
+            load_assets(
+                &mut commands,
+                &asset_server,
+                &[spectate_info.left.hero, spectate_info.right.hero],
+            );
+        }
+    }
+}
@@ -0,0 +1,378 @@
+mod init;
+mod load;
+
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::enter::{
+    MAX_RETRY_COUNT, TIMEOUT, load_assets, setup_loading_minimi, update_loading_minimi,
+    update_loading_progress,
+};
+use super::init::{setup_in_game_entities, setup_in_game_interface};
+
+use super::*;
+
+/// How far the replay moves when seeking (milliseconds).
+const REPLAY_SEEK_MILLIS: u32 = 5_000;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(load::InnerPlugin)
+            .add_systems(
+                OnEnter(LevelStates::Spectate),
+                (
+                    debug_label,
+                    setup_resource,
+                    cleanup_title_assets,
+                    cleanup_title_entities,
+                ),
+            )
+            .add_systems(
+                OnExit(LevelStates::Spectate),
+                (
+                    cleanup_resource,
+                    reset_camera_position,
+                    setup_loading_screen,
+                    cleanup_in_game_assets,
+                    cleanup_in_game_entities,
+                    cleanup_background_sounds,
+                    cleanup_spectate_info,
+                    cleanup_replay_playback,
+                ),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    update_replay_playback.run_if(resource_exists::<ReplayPlayback>),
+                    handle_received_packets.after(update_replay_playback),
+                    handle_keyboard_inputs,
+                    handle_stop_watching_button_pressed,
+                    handle_replay_keyboard_inputs.run_if(resource_exists::<ReplayPlayback>),
+                    handle_replay_button_pressed.run_if(resource_exists::<ReplayPlayback>),
+                )
+                    .run_if(in_state(LevelStates::Spectate)),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_hud_turn_timer,
+                    update_replay_interfaces.run_if(resource_exists::<ReplayPlayback>),
+                )
+                    .run_if(in_state(LevelStates::Spectate)),
+            );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: Spectate");
+}
+
+// --- CLEANUP SYSTEMS ---
+
+fn cleanup_in_game_assets(mut commands: Commands) {
+    commands.remove_resource::<InGameAssets>();
+}
+
+fn cleanup_in_game_entities(mut commands: Commands, query: Query<Entity, With<InGameLevelRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn cleanup_background_sounds(mut commands: Commands, query: Query<Entity, With<BackgroundSound>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn cleanup_spectate_info(mut commands: Commands) {
+    commands.remove_resource::<SpectateInfo>();
+}
+
+fn cleanup_replay_playback(mut commands: Commands) {
+    commands.remove_resource::<ReplayPlayback>();
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+#[allow(clippy::too_many_arguments)]
+fn handle_received_packets(
+    mut commands: Commands,
+    mut side: ResMut<PlaySide>,
+    mut left_health: ResMut<LeftPlayerHealth>,
+    mut right_health: ResMut<RightPlayerHealth>,
+    mut player_timer: ResMut<PlayerTimer>,
+    mut in_game_timer: ResMut<InGameTimer>,
+    mut projectile: Option<ResMut<ProjectileObject>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+    mut replay: Option<ResMut<ReplayPlayback>>,
+    ruleset: Res<MatchRuleset>,
+) {
+    let replay_packets = replay.iter_mut().flat_map(|replay| replay.drain());
+    for result in network.try_iter().chain(replay_packets.map(Ok)) {
+        match result {
+            Ok(packet) => {
+                let packet = apply_match_packet(
+                    &mut commands,
+                    packet,
+                    &mut side,
+                    &mut left_health,
+                    &mut right_health,
+                    &mut player_timer,
+                    &mut in_game_timer,
+                    &mut projectile,
+                    &ruleset,
+                );
+                match packet {
+                    Some(Packet::WatchEnded) => {
+                        next_state.set(LevelStates::LoadTitle);
+                    }
+                    // The replay was moved to another time.
+                    Some(Packet::InGameResync {
+                        turn,
+                        total_remaining_millis,
+                        remaining_millis,
+                        left_health_cnt,
+                        right_health_cnt,
+                        wind_angle,
+                        wind_power,
+                    }) => {
+                        *side = match turn {
+                            TurnState::LeftTurn => PlaySide::Left(None),
+                            TurnState::RightTurn => PlaySide::Right(None),
+                            TurnState::LeftProjectileThrown => PlaySide::LeftThrown,
+                            TurnState::RightProjectileThrown => PlaySide::RightThrown,
+                        };
+                        in_game_timer.miliis = total_remaining_millis;
+                        player_timer.miliis = remaining_millis;
+                        left_health.0 = left_health_cnt as usize;
+                        right_health.0 = right_health_cnt as usize;
+                        commands.insert_resource(Wind::new(
+                            wind_angle,
+                            wind_power,
+                            &ruleset.0.wind,
+                        ));
+                        commands.remove_resource::<ProjectileObject>();
+                        commands.remove_resource::<ProjectileHit>();
+                    }
+                    _ => { /* empty */ }
+                }
+            }
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+fn handle_keyboard_inputs(
+    network: Res<Network>,
+    replay: Option<Res<ReplayPlayback>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        send_stop_watching_message(&network, replay.is_some());
+        next_state.set(LevelStates::LoadTitle);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_stop_watching_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    replay: Option<Res<ReplayPlayback>>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    interaction_query: Query<
+        (Entity, &Interaction),
+        (With<StopWatchingButton>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, interaction) in interaction_query.iter() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match interaction {
+            Interaction::Pressed => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                send_stop_watching_message(&network, replay.is_some());
+                next_state.set(LevelStates::LoadTitle);
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+fn handle_replay_keyboard_inputs(
+    mut replay: ResMut<ReplayPlayback>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Space) {
+        replay.toggle_pause();
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        seek_replay(&mut replay, ReplayButton::Backward);
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        seek_replay(&mut replay, ReplayButton::Forward);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_replay_button_pressed(
+    mut commands: Commands,
+    mut replay: ResMut<ReplayPlayback>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    interaction_query: Query<
+        (Entity, &ReplayButton, &Interaction),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, &button, interaction) in interaction_query.iter() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match interaction {
+            Interaction::Pressed => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                match button {
+                    ReplayButton::Pause => replay.toggle_pause(),
+                    _ => seek_replay(&mut replay, button),
+                }
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+fn update_replay_playback(mut replay: ResMut<ReplayPlayback>, time: Res<Time>) {
+    replay.update(time.delta_secs());
+}
+
+// --- UPDATE SYSTEMS ---
+
+/// Shows the time left in the turn of either player.
+fn update_hud_turn_timer(
+    timer: Res<PlayerTimer>,
+    side: Res<PlaySide>,
+    ruleset: Res<MatchRuleset>,
+    mut hud: Query<&mut Visibility, With<UiTurnTimer>>,
+    mut timer_bar: Query<(&mut Node, &mut BackgroundColor), With<TurnTimer>>,
+) {
+    let Ok(mut visibility) = hud.single_mut() else {
+        return;
+    };
+    let Ok((mut node, mut color)) = timer_bar.single_mut() else {
+        return;
+    };
+
+    match *side {
+        PlaySide::Left(_) | PlaySide::Right(_) => {
+            *visibility = Visibility::Visible;
+            update_turn_timer_bar(&timer, ruleset.0.max_turn_time, &mut node, &mut color);
+        }
+        _ => {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// Shows the time of the replay and whether it is paused.
+fn update_replay_interfaces(
+    mut commands: Commands,
+    replay: Res<ReplayPlayback>,
+    mut paused: Local<bool>,
+    mut time_query: Query<&mut Text, With<ReplayTimeText>>,
+    pause_query: Query<Entity, With<ReplayPauseText>>,
+) {
+    for mut text in time_query.iter_mut() {
+        *text = Text::new(format!(
+            "{} / {}",
+            format_replay_time(replay.elapsed_millis()),
+            format_replay_time(replay.length_millis())
+        ));
+    }
+
+    if *paused != replay.is_paused() {
+        *paused = replay.is_paused();
+        let key = match *paused {
+            true => "replay_play",
+            false => "replay_pause",
+        };
+        for entity in pause_query.iter() {
+            commands.entity(entity).insert(TranslatableText(key.into()));
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+fn seek_replay(replay: &mut ReplayPlayback, button: ReplayButton) {
+    let millis = match button {
+        ReplayButton::Backward => replay.elapsed_millis().saturating_sub(REPLAY_SEEK_MILLIS),
+        _ => replay.elapsed_millis().saturating_add(REPLAY_SEEK_MILLIS),
+    };
+    replay.seek(millis);
+}
+
+fn format_replay_time(millis: u32) -> String {
+    let seconds = millis / 1000;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Leaves a match that ended before the spectator could load it.
+fn leave_ended_match(commands: &mut Commands, next_state: &mut NextState<LevelStates>) {
+    commands.remove_resource::<InGameAssets>();
+    commands.remove_resource::<SpectateInfo>();
+    commands.insert_resource(ErrorMessage::new("watch_ended", "The match is over."));
+    next_state.set(LevelStates::SwitchToTitleMessage);
+}
+
+/// A replay is watched without the server, so there is nothing to tell it.
+fn send_stop_watching_message(network: &Network, is_replay: bool) {
+    if is_replay {
+        return;
+    }
+    let packet = Packet::StopWatching;
+    network.send(&packet).unwrap();
+}
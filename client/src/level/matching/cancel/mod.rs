@@ -31,7 +31,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::InMatchingCancel)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             handle_received_packets.run_if(in_state(LevelStates::InMatchingCancel)),
@@ -75,7 +74,7 @@ fn handle_keyboard_input(
 #[allow(clippy::too_many_arguments)]
 fn handle_button_interaction(
     mut commands: Commands,
-    #[cfg(target_arch = "wasm32")] network: Res<Network>,
+    network: Res<Network>,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -98,7 +97,6 @@ fn handle_button_interaction(
 
         match (button, interaction) {
             (PNButton::Positive, Interaction::Pressed) => {
-                #[cfg(target_arch = "wasm32")]
                 send_cancel_game_message(&network);
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
                 play_effect_sound(&mut commands, &system_volume, source);
@@ -119,7 +117,6 @@ fn handle_button_interaction(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -136,21 +133,18 @@ fn handle_received_packets(
                     play_effect_sound(&mut commands, &system_volume, source);
                     next_state.set(LevelStates::InTitle);
                 }
-                Packet::MatchingSuccess { left, right } => {
-                    let (other, left_side) =
-                        if left.uuid.is_some_and(|uuid| uuid == player_info.uuid) {
-                            (right, false)
-                        } else {
-                            (left, true)
-                        };
-
-                    commands.insert_resource(OtherInfo {
-                        left_side,
-                        name: other.name.clone(),
-                        hero: other.hero,
-                        win: other.win,
-                        lose: other.lose,
-                    });
+                Packet::MatchingSuccess {
+                    left,
+                    right,
+                    ruleset,
+                }
+                | Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(OtherInfo::new(player_info.uuid, left, right));
+                    commands.insert_resource(MatchRuleset(*ruleset));
                     next_state.set(LevelStates::SwitchToLoadGame);
                 }
                 _ => { /* empty */ }
@@ -165,7 +159,6 @@ fn handle_received_packets(
 
 // --- UTILITIES ---
 
-#[cfg(target_arch = "wasm32")]
 fn send_cancel_game_message(network: &Network) {
     let packet = Packet::TryCancelGame;
     network.send(&packet).unwrap();
@@ -24,7 +24,6 @@ impl Plugin for InnerPlugin {
                 .run_if(in_state(LevelStates::InitMatching)),
         );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             packet_receive_loop.run_if(in_state(LevelStates::InitMatching)),
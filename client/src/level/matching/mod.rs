@@ -5,7 +5,6 @@ mod switch;
 // Import necessary Bevy modules.
 use bevy::prelude::*;
 
-#[cfg(target_arch = "wasm32")]
 use crate::assets::locale::Locale;
 use crate::assets::sound::SystemVolume;
 
@@ -33,7 +32,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::InMatching)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             handle_received_packets.run_if(in_state(LevelStates::InMatching)),
@@ -110,7 +108,6 @@ fn handle_pn_button_interaction(
 
 // --- UPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -137,21 +134,18 @@ fn handle_received_packets(
                         });
                     }
                 }
-                Packet::MatchingSuccess { left, right } => {
-                    let (other, left_side) =
-                        if left.uuid.is_some_and(|uuid| uuid == player_info.uuid) {
-                            (right, false)
-                        } else {
-                            (left, true)
-                        };
-
-                    commands.insert_resource(OtherInfo {
-                        left_side,
-                        name: other.name.clone(),
-                        hero: other.hero,
-                        win: other.win,
-                        lose: other.lose,
-                    });
+                Packet::MatchingSuccess {
+                    left,
+                    right,
+                    ruleset,
+                }
+                | Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(OtherInfo::new(player_info.uuid, left, right));
+                    commands.insert_resource(MatchRuleset(*ruleset));
                     next_state.set(LevelStates::SwitchToLoadGame);
                 }
                 _ => { /* empty */ }
@@ -24,6 +24,7 @@ use crate::{
     translatable_text::*,
 };
 
+use crate::network::*;
 #[cfg(target_arch = "wasm32")]
 use crate::web::*;
 
@@ -67,7 +68,12 @@ pub enum LevelStates {
     InitMatching,       // -> InitMatchingCancel, Error
     InitMatchingCancel, // -> InitInTitleMessage, Error
     InitInTitleMessage, // -> InitLeaderBoard, Error
-    InitLeaderBoard,    // -> InitEnterGame, Error
+    InitLeaderBoard,    // -> InitHeroSelect, Error
+    InitHeroSelect,     // -> InitRename, Error
+    InitRename,         // -> InitProfile, Error
+    InitProfile,        // -> InitRoom, Error
+    InitRoom,           // -> InitWatch, Error
+    InitWatch,          // -> InitEnterGame, Error
     InitEnterGame,      // -> InitTitle, Error
     InitTitle,          // -> InTitle, Error
     InTitle,            // -> SwitchToInMatching, SwitchToInOption, Error
@@ -88,11 +94,11 @@ pub enum LevelStates {
     SwitchToInPrepare, // -> InPrepareGame, Error
     InPrepareGame,     // -> SwitchToInGame, Error
 
-    SwitchToLoadGame, // -> LoadGame
+    SwitchToLoadGame, // -> LoadGame, LoadSpectate
     LoadGame,         // -> SwitchToTitleMessage, InitGame, Error
     InitGame,         // -> SwitchToTitleMessage, InitPrepareGame, Error
 
-    SwitchToInGame, // -> InGame, Error
+    SwitchToInGame, // -> InGame, Spectate, Error
     InGame,         // -> Error
 
     InitGameResult, // -> SwitchToTitleMessage, SwitchToInPrepare, Error
@@ -103,6 +109,25 @@ pub enum LevelStates {
 
     SwitchToLeaderBoard,
     LeaderBoard,
+
+    SwitchToHeroSelect, // -> HeroSelect
+    HeroSelect,         // -> InTitle, LoadTitle, Error
+
+    SwitchToRename, // -> Rename
+    Rename,         // -> InTitle, LoadTitle, SwitchToTitleMessage, Error
+
+    SwitchToProfile, // -> Profile
+    Profile,         // -> InTitle, SwitchToTitleMessage, SwitchToLoadGame, Error
+
+    SwitchToRoom, // -> Room
+    Room,         // -> InTitle, SwitchToTitleMessage, SwitchToLoadGame, Error
+
+    SwitchToWatch, // -> Watch
+    Watch,         // -> InTitle, SwitchToTitleMessage, SwitchToLoadGame, Error
+
+    LoadSpectate, // -> SwitchToTitleMessage, InitSpectate, Error
+    InitSpectate, // -> SwitchToTitleMessage, SwitchToInGame, Error
+    Spectate,     // -> LoadTitle, Error
 }
 
 // --- UPDATE SYSTEMS ---
@@ -2,7 +2,11 @@ use std::collections::VecDeque;
 
 // Import necessary Bevy modules.
 use bevy::{asset::UntypedAssetId, platform::collections::HashSet, prelude::*};
-use protocol::{Hero, MAX_HEALTH_COUNT, RankItem, THROW_END_TIME, uuid::Uuid};
+use protocol::{
+    BotAim, BotLevel, COLLIDER_DATA, Circle, Hero, HeroStats, LiveMatch, MatchRecord, MatchRng,
+    NUM_HEROS, NameError, PlayData, RANKING_PAGE_SIZE, RankItem, Replay, ReplaySim, Ruleset, Side,
+    THROW_END_TIME, TurnState, WindRule, rand::Rng, uuid::Uuid,
+};
 
 use super::*;
 
@@ -136,6 +140,7 @@ pub struct PlayerInfo {
     pub hero: Hero,
     pub win: u16,
     pub lose: u16,
+    pub draw: u16,
 }
 
 #[derive(Resource)]
@@ -145,6 +150,444 @@ pub struct OtherInfo {
     pub hero: Hero,
     pub win: u16,
     pub lose: u16,
+    pub draw: u16,
+}
+
+impl OtherInfo {
+    pub fn new(player_uuid: Uuid, left: PlayData, right: PlayData) -> Self {
+        let (other, left_side) = if left.uuid.is_some_and(|uuid| uuid == player_uuid) {
+            (right, false)
+        } else {
+            (left, true)
+        };
+
+        Self {
+            left_side,
+            name: other.name,
+            hero: other.hero,
+            win: other.win,
+            lose: other.lose,
+            draw: other.draw,
+        }
+    }
+}
+
+/// The match to return to after reconnecting to the server.
+#[derive(Resource)]
+pub struct RejoinInfo {
+    pub left: PlayData,
+    pub right: PlayData,
+    pub ruleset: Ruleset,
+}
+
+/// The game state received when returning to a match in progress.
+#[derive(Resource)]
+pub struct ResyncInfo {
+    pub turn: TurnState,
+    pub total_remaining_millis: i32,
+    pub remaining_millis: u16,
+    pub left_health_cnt: u8,
+    pub right_health_cnt: u8,
+    pub wind_angle: u8,
+    pub wind_power: u8,
+}
+
+/// The name being typed in the rename popup.
+#[derive(Resource)]
+pub struct NameDraft(pub String);
+
+/// The invite code being typed in the room popup.
+#[derive(Resource)]
+pub struct RoomCodeDraft(pub String);
+
+/// A room request waiting for the server.
+/// Removed when the player is neither hosting nor joining a room.
+#[derive(Resource)]
+pub enum RoomStatus {
+    /// The code is `None` until the server opens the room.
+    Hosting(Option<String>),
+    Joining,
+}
+
+/// Progress of the rematch on the game result screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub enum RematchStatus {
+    Available,
+    Offered,
+    OpponentOffered,
+    Declined,
+    /// The server has started the rematch.
+    Accepted,
+}
+
+/// The matches in progress shown in the watch popup.
+#[derive(Resource)]
+pub struct LiveMatches(pub Vec<LiveMatch>);
+
+/// The match being watched.
+/// Present from the start of loading until the spectator leaves the match.
+#[derive(Resource)]
+pub struct SpectateInfo {
+    pub left: PlayData,
+    pub right: PlayData,
+}
+
+#[derive(Clone, Copy)]
+enum PracticeState {
+    PlayerTurn,
+    BotTurn,
+    PlayerThrown { hit: bool },
+    BotThrown { hit: bool },
+    Finished,
+}
+
+/// A match against a bot played without the server.
+/// The player is on the left side and the bot on the right side.
+/// The turns are played like on the server, and the packets the server would send are queued.
+#[derive(Resource)]
+pub struct PracticeMatch {
+    state: PracticeState,
+    player_collider: Circle,
+    bot_collider: Circle,
+    level: BotLevel,
+    bot_aim: BotAim,
+    rng: MatchRng,
+    ruleset: Ruleset,
+    projectile: protocol::Projectile,
+    control: Option<(u8, u8)>,
+    wind_vel: Vec2,
+    player_health: usize,
+    bot_health: usize,
+    total_remaining_millis: i32,
+    remaining_millis: u16,
+    /// Time not yet played, shorter than a millisecond.
+    carry_secs: f32,
+    /// The record of the player, which a practice match does not change.
+    record: (u16, u16, u16),
+    packets: VecDeque<Packet>,
+}
+
+impl PracticeMatch {
+    pub fn new(player_info: &PlayerInfo, bot_hero: Hero, ruleset: &Ruleset) -> Self {
+        let player_collider = *COLLIDER_DATA.get(&player_info.hero).unwrap();
+        let bot_collider = *COLLIDER_DATA.get(&bot_hero).unwrap();
+        let mut rng = MatchRng::new(rand::random());
+        let level: BotLevel = rng.bot.random();
+        let (wind_angle, wind_power) = ruleset.wind.pick(&mut rng.wind);
+        let wind_vel = ruleset.wind.velocity(wind_angle, wind_power);
+
+        let mut packets = VecDeque::new();
+        packets.push_back(Packet::InGameTurnSetup {
+            wind_angle,
+            wind_power,
+        });
+
+        Self {
+            state: PracticeState::PlayerTurn,
+            player_collider,
+            bot_collider,
+            level,
+            bot_aim: BotAim::new(
+                Side::Right,
+                level,
+                &player_collider,
+                wind_vel,
+                ruleset,
+                &mut rng.bot,
+            ),
+            rng,
+            projectile: protocol::Projectile::new(Side::Left, None, wind_vel, ruleset),
+            control: None,
+            wind_vel,
+            player_health: ruleset.health_count as usize,
+            bot_health: ruleset.health_count as usize,
+            total_remaining_millis: ruleset.max_play_time,
+            remaining_millis: ruleset.max_turn_time,
+            ruleset: ruleset.clone(),
+            carry_secs: 0.0,
+            record: (player_info.win, player_info.lose, player_info.draw),
+            packets,
+        }
+    }
+
+    /// Takes a packet the player would send to the server.
+    pub fn receive(&mut self, packet: Packet) {
+        match (self.state, packet) {
+            (PracticeState::PlayerTurn, Packet::UpdateThrowParams { angle, power }) => {
+                self.control = Some((angle, power));
+            }
+            (PracticeState::PlayerTurn, Packet::ThrowProjectile) => {
+                self.throw(Side::Left);
+            }
+            _ => { /* empty */ }
+        }
+    }
+
+    /// Returns the packets the server would have sent so far.
+    pub fn drain(&mut self) -> std::collections::vec_deque::Drain<'_, Packet> {
+        self.packets.drain(..)
+    }
+
+    pub fn update(&mut self, delta_secs: f32) {
+        if matches!(self.state, PracticeState::Finished) {
+            return;
+        }
+
+        self.carry_secs += delta_secs;
+        let elapsed = ((self.carry_secs * 1000.0) as u32).min(u16::MAX as u32) as u16;
+        if elapsed == 0 {
+            return;
+        }
+        self.carry_secs -= elapsed as f32 / 1000.0;
+        self.total_remaining_millis -= elapsed as i32;
+        let delta_time = elapsed as f32 / 1000.0;
+
+        if let PracticeState::BotTurn = self.state {
+            self.control = Some(self.bot_aim.update(delta_time, &mut self.rng.bot));
+            if self.bot_aim.is_ready() {
+                self.throw(Side::Right);
+            }
+        }
+
+        match self.state {
+            PracticeState::PlayerTurn => {
+                self.remaining_millis = self.remaining_millis.saturating_sub(elapsed);
+                self.packets.push_back(Packet::InGameLeftTurn {
+                    total_remaining_millis: self.total_remaining_millis,
+                    remaining_millis: self.remaining_millis,
+                    left_health_cnt: self.player_health as u8,
+                    right_health_cnt: self.bot_health as u8,
+                    control: self.control,
+                });
+
+                if self.remaining_millis == 0 {
+                    self.setup_turn(PracticeState::BotTurn);
+                }
+            }
+            PracticeState::BotTurn => {
+                self.remaining_millis = self.remaining_millis.saturating_sub(elapsed);
+                self.packets.push_back(Packet::InGameRightTurn {
+                    total_remaining_millis: self.total_remaining_millis,
+                    remaining_millis: self.remaining_millis,
+                    left_health_cnt: self.player_health as u8,
+                    right_health_cnt: self.bot_health as u8,
+                    control: self.control,
+                });
+
+                if self.remaining_millis == 0 {
+                    self.setup_turn(PracticeState::PlayerTurn);
+                }
+            }
+            PracticeState::PlayerThrown { mut hit } => {
+                if let Some(pos) = self
+                    .projectile
+                    .step(delta_time, Side::Right, &self.bot_collider)
+                    && !hit
+                {
+                    hit = true;
+                    self.bot_health -= 1;
+                    self.packets.push_back(Packet::InGameHit {
+                        target: Side::Right,
+                        pos: pos.into(),
+                    });
+                }
+                self.state = PracticeState::PlayerThrown { hit };
+                self.update_projectile(elapsed);
+
+                if self.remaining_millis == 0 {
+                    match self.bot_health {
+                        0 => self.finish(),
+                        _ => self.setup_turn(PracticeState::BotTurn),
+                    }
+                }
+            }
+            PracticeState::BotThrown { mut hit } => {
+                if let Some(pos) =
+                    self.projectile
+                        .step(delta_time, Side::Left, &self.player_collider)
+                    && !hit
+                {
+                    hit = true;
+                    self.player_health -= 1;
+                    self.packets.push_back(Packet::InGameHit {
+                        target: Side::Left,
+                        pos: pos.into(),
+                    });
+                }
+                self.state = PracticeState::BotThrown { hit };
+                self.update_projectile(elapsed);
+
+                if self.remaining_millis == 0 {
+                    match self.player_health {
+                        0 => self.finish(),
+                        _ => self.setup_turn(PracticeState::PlayerTurn),
+                    }
+                }
+            }
+            PracticeState::Finished => { /* empty */ }
+        }
+
+        if self.total_remaining_millis <= 0
+            && matches!(
+                self.state,
+                PracticeState::PlayerTurn | PracticeState::BotTurn
+            )
+        {
+            self.finish();
+        }
+    }
+
+    fn throw(&mut self, side: Side) {
+        self.projectile =
+            protocol::Projectile::new(side, self.control, self.wind_vel, &self.ruleset);
+        self.state = match side {
+            Side::Left => PracticeState::PlayerThrown { hit: false },
+            Side::Right => PracticeState::BotThrown { hit: false },
+        };
+        self.remaining_millis = THROW_END_TIME;
+    }
+
+    fn update_projectile(&mut self, elapsed: u16) {
+        if self.projectile.is_landed() {
+            self.remaining_millis = self.remaining_millis.saturating_sub(elapsed);
+        }
+
+        self.packets.push_back(Packet::InGameProjectileThrown {
+            total_remaining_millis: self.total_remaining_millis,
+            remaining_millis: self.remaining_millis,
+            left_health_cnt: self.player_health as u8,
+            right_health_cnt: self.bot_health as u8,
+            projectile_pos: self.projectile.pos.into(),
+            projectile_vel: self.projectile.vel.into(),
+        });
+    }
+
+    fn setup_turn(&mut self, state: PracticeState) {
+        let (wind_angle, wind_power) = self.ruleset.wind.pick(&mut self.rng.wind);
+        self.wind_vel = self.ruleset.wind.velocity(wind_angle, wind_power);
+        self.packets.push_back(Packet::InGameTurnSetup {
+            wind_angle,
+            wind_power,
+        });
+
+        if let PracticeState::BotTurn = state {
+            self.bot_aim = BotAim::new(
+                Side::Right,
+                self.level,
+                &self.player_collider,
+                self.wind_vel,
+                &self.ruleset,
+                &mut self.rng.bot,
+            );
+        }
+
+        self.state = state;
+        self.remaining_millis = self.ruleset.max_turn_time;
+        self.control = None;
+    }
+
+    fn finish(&mut self) {
+        let (win, lose, draw) = self.record;
+        let packet = match self.player_health.cmp(&self.bot_health) {
+            std::cmp::Ordering::Equal => Packet::GameResultDraw { win, lose, draw },
+            ordering => Packet::GameResult {
+                win,
+                lose,
+                draw,
+                victory: ordering.is_gt(),
+            },
+        };
+        self.packets.push_back(packet);
+        self.state = PracticeState::Finished;
+    }
+}
+
+/// A recorded match being watched.
+/// The match is played again from its inputs, and the packets the server sent are queued.
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    sim: ReplaySim,
+    /// Length of the whole match (milliseconds).
+    length_millis: u32,
+    /// Time not yet played, shorter than a tick.
+    carry_secs: f32,
+    paused: bool,
+    packets: VecDeque<Packet>,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        let mut sim = ReplaySim::new(replay.clone());
+        while !sim.is_finished() {
+            sim.update();
+        }
+        let length_millis = sim.elapsed_millis();
+
+        Self {
+            sim: ReplaySim::new(replay),
+            length_millis,
+            carry_secs: 0.0,
+            paused: false,
+            packets: VecDeque::new(),
+        }
+    }
+
+    /// Returns the packet that brings the game to the current state of the replay.
+    pub fn resync(&self) -> Packet {
+        self.sim.resync()
+    }
+
+    /// Returns the packets the server sent so far.
+    pub fn drain(&mut self) -> std::collections::vec_deque::Drain<'_, Packet> {
+        self.packets.drain(..)
+    }
+
+    pub fn update(&mut self, delta_secs: f32) {
+        if self.paused {
+            return;
+        }
+
+        let tick_secs = self.sim.replay().tick_millis as f32 / 1000.0;
+        self.carry_secs += delta_secs;
+        while self.carry_secs >= tick_secs && !self.sim.is_finished() {
+            self.carry_secs -= tick_secs;
+            self.packets.extend(self.sim.update());
+        }
+    }
+
+    /// Moves the replay to the given time (milliseconds).
+    /// The match is played again from the start when going back.
+    pub fn seek(&mut self, millis: u32) {
+        let millis = millis.min(self.length_millis);
+        if millis < self.sim.elapsed_millis() {
+            self.sim = ReplaySim::new(self.sim.replay().clone());
+        }
+        while self.sim.elapsed_millis() < millis && !self.sim.is_finished() {
+            self.sim.update();
+        }
+
+        self.carry_secs = 0.0;
+        self.packets.clear();
+        self.packets.push_back(self.sim.resync());
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Time played so far (milliseconds).
+    pub fn elapsed_millis(&self) -> u32 {
+        self.sim.elapsed_millis()
+    }
+
+    /// Length of the whole match (milliseconds).
+    pub fn length_millis(&self) -> u32 {
+        self.length_millis
+    }
 }
 
 #[derive(Default, Resource)]
@@ -195,7 +638,6 @@ impl ErrorMessage {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
 impl From<NetError> for ErrorMessage {
     fn from(e: NetError) -> Self {
         match e {
@@ -216,6 +658,25 @@ impl From<NetError> for ErrorMessage {
     }
 }
 
+impl From<NameError> for ErrorMessage {
+    fn from(e: NameError) -> Self {
+        match e {
+            NameError::InvalidLength => ErrorMessage::new(
+                "name_invalid_length",
+                "The name must be 2 to 12 characters long.",
+            ),
+            NameError::InvalidCharacter => ErrorMessage::new(
+                "name_invalid_character",
+                "The name contains characters that cannot be used.",
+            ),
+            NameError::Denied => ErrorMessage::new("name_denied", "This name cannot be used."),
+            NameError::Duplicated => {
+                ErrorMessage::new("name_duplicated", "This name is already taken.")
+            }
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct SyncFlags;
 
@@ -246,17 +707,23 @@ pub enum PlaySide {
     RightThrown,
 }
 
+/// Rules of the match being played or watched, sent by the server when the match starts.
+#[derive(Resource)]
+pub struct MatchRuleset(pub Ruleset);
+
 #[derive(Resource)]
 pub struct Wind {
     angle: f32,
     power: f32,
+    velocity: Vec2,
 }
 
 impl Wind {
-    pub fn new(angle: u8, power: u8) -> Self {
+    pub fn new(angle: u8, power: u8, rule: &WindRule) -> Self {
         Self {
             angle: angle as f32 / 255.0 * TAU,
             power: power as f32 / 255.0,
+            velocity: rule.velocity(angle, power),
         }
     }
 
@@ -269,8 +736,9 @@ impl Wind {
         self.power
     }
 
+    /// The velocity the wind adds to a projectile in the air.
     pub fn velocity(&self) -> Vec2 {
-        Vec2::new(self.angle.cos(), self.angle.sin()) * self.power
+        self.velocity
     }
 }
 
@@ -279,6 +747,7 @@ impl Default for Wind {
         Self {
             angle: 0.0,
             power: 0.0,
+            velocity: Vec2::ZERO,
         }
     }
 }
@@ -286,21 +755,9 @@ impl Default for Wind {
 #[derive(Resource)]
 pub struct LeftPlayerHealth(pub usize);
 
-impl Default for LeftPlayerHealth {
-    fn default() -> Self {
-        Self(MAX_HEALTH_COUNT)
-    }
-}
-
 #[derive(Resource)]
 pub struct RightPlayerHealth(pub usize);
 
-impl Default for RightPlayerHealth {
-    fn default() -> Self {
-        Self(MAX_HEALTH_COUNT)
-    }
-}
-
 pub struct Snapshot {
     pub timepoint: i32,
     pub position: Vec2,
@@ -391,18 +848,145 @@ impl ProjectileObject {
     }
 }
 
+/// A hit reported by the server, waiting for the drawn projectile to reach it.
+#[derive(Resource)]
+pub struct ProjectileHit {
+    pub target: Side,
+    pub pos: Vec2,
+}
+
+/// A leaderboard the player can browse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankingBoard {
+    /// Every player of the season, ordered by rating.
+    Season(u32),
+    /// Players of the hero, ordered by their wins with it.
+    Hero(Hero),
+}
+
 #[derive(Resource)]
 pub struct RankingData {
+    pub board: RankingBoard,
+    /// Only known from the results of season leaderboards.
+    pub current_season: Option<u32>,
     pub my_rank: Option<u32>,
-    pub top_list: Vec<RankItem>,
+    pub my_rating: Option<i32>,
+    pub total: u32,
+    pub items: Vec<RankItem>,
 }
 
 impl RankingData {
-    pub fn new(my_rank: Option<u32>, mut top_list: Vec<RankItem>) -> Self {
-        top_list.sort_by_key(|i| i.rank);
-        Self { my_rank, top_list }
+    pub fn new(
+        board: RankingBoard,
+        current_season: Option<u32>,
+        my_rank: Option<u32>,
+        my_rating: Option<i32>,
+        total: u32,
+        mut items: Vec<RankItem>,
+    ) -> Self {
+        items.sort_by_key(|i| i.rank);
+        Self {
+            board,
+            current_season,
+            my_rank,
+            my_rating,
+            total,
+            items,
+        }
+    }
+}
+
+/// The part of the leaderboard currently shown.
+#[derive(Default, Resource)]
+pub struct RankingPage {
+    /// `None` until the first result arrives.
+    pub board: Option<RankingBoard>,
+    /// The last season shown. The hero leaderboards are not seasonal.
+    pub season: Option<u32>,
+    pub current_season: u32,
+    /// Zero-based position of the first shown item.
+    pub start: u32,
+    pub total: u32,
+}
+
+impl RankingPage {
+    pub fn hero(&self) -> Option<Hero> {
+        match self.board {
+            Some(RankingBoard::Hero(hero)) => Some(hero),
+            _ => None,
+        }
+    }
+
+    /// The board of the previous or next hero filter.
+    /// The filters go around from all players through every hero.
+    pub fn cycle_hero(&self, forward: bool) -> Option<RankingBoard> {
+        let num_filters = NUM_HEROS + 1;
+        let filter = self.hero().map_or(0, |hero| hero.index() + 1);
+        let filter = match forward {
+            true => (filter + 1) % num_filters,
+            false => (filter + num_filters - 1) % num_filters,
+        };
+        match filter {
+            0 => self.season.map(RankingBoard::Season),
+            _ => Hero::new(filter - 1).map(RankingBoard::Hero),
+        }
+    }
+
+    pub fn prev_season(&self) -> Option<u32> {
+        self.season
+            .filter(|&season| season > 1)
+            .map(|season| season - 1)
+    }
+
+    pub fn next_season(&self) -> Option<u32> {
+        self.season
+            .filter(|&season| season < self.current_season)
+            .map(|season| season + 1)
+    }
+
+    pub fn current_page(&self) -> u32 {
+        self.start / RANKING_PAGE_SIZE
+    }
+
+    pub fn num_pages(&self) -> u32 {
+        self.total.div_ceil(RANKING_PAGE_SIZE).max(1)
+    }
+
+    /// The page before the shown items, if any.
+    /// A view around the player may start in the middle of a page,
+    /// so the page that contains the item just before it is returned.
+    pub fn prev_page(&self) -> Option<u32> {
+        (self.start > 0).then(|| (self.start - 1) / RANKING_PAGE_SIZE)
+    }
+
+    pub fn next_page(&self) -> Option<u32> {
+        let next = self.current_page() + 1;
+        (next * RANKING_PAGE_SIZE < self.total).then_some(next)
+    }
+}
+
+/// Statistics of every player for each hero that has been played.
+#[derive(Default, Resource)]
+pub struct HeroStatsData {
+    pub overall: Vec<HeroStats>,
+}
+
+impl HeroStatsData {
+    pub fn get(&self, hero: Hero) -> Option<&HeroStats> {
+        self.overall.iter().find(|stats| stats.hero == hero)
     }
 }
 
+/// Lifetime record and recent games of the player.
+#[derive(Resource)]
+pub struct ProfileData {
+    pub rating: i32,
+    pub wins: u16,
+    pub losses: u16,
+    pub draws: u16,
+    /// Most recent first.
+    pub history: Vec<MatchRecord>,
+}
+
 #[derive(Resource)]
 pub struct GreetingFlag;
@@ -111,17 +111,30 @@ pub fn cleanup_loading_resource(mut commands: Commands) {
 
 // --- UPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 pub fn packet_receive_loop(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
     network: Res<Network>,
 ) {
     for result in network.receiver.try_iter() {
-        if let Err(e) = result {
-            commands.insert_resource(ErrorMessage::from(e));
-            next_state.set(LevelStates::Error);
-            return;
+        match result {
+            Ok(Packet::RejoinGame {
+                left,
+                right,
+                ruleset,
+            }) => {
+                commands.insert_resource(RejoinInfo {
+                    left,
+                    right,
+                    ruleset: *ruleset,
+                });
+            }
+            Ok(_) => { /* empty */ }
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+                return;
+            }
         }
     }
 }
@@ -0,0 +1,295 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+use protocol::NUM_HEROS;
+
+use super::*;
+
+// --- CONSTANTS ---
+
+const NUM_COLUMNS: usize = 8;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::InitHeroSelect),
+            (debug_label, setup_hero_select),
+        )
+        .add_systems(
+            OnExit(LevelStates::InitHeroSelect),
+            cleanup_loading_resource,
+        )
+        .add_systems(
+            Update,
+            (
+                update_entity_spawn_progress,
+                observe_entity_creation,
+                check_loading_progress,
+            )
+                .run_if(in_state(LevelStates::InitHeroSelect)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitHeroSelect");
+}
+
+fn setup_hero_select(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    player_info: Res<PlayerInfo>,
+) {
+    let mut loading_entities = LoadingEntities::default();
+    setup_hero_select_interface(
+        &mut commands,
+        &asset_server,
+        &mut loading_entities,
+        player_info.hero,
+    );
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+fn setup_hero_select_interface(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+    current: Hero,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            SpawnRequest,
+            ZIndex(3),
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(70.0),
+                        height: Val::Percent(86.0),
+                        border: UiRect::all(Val::VMin(1.25)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Percent(10.0)),
+                    BorderColor::all(BORDER_GREEN_COLOR_0),
+                    BackgroundColor(BG_GREEN_COLOR_3),
+                    Visibility::Inherited,
+                    UiAnimationTarget,
+                    SpawnRequest,
+                ))
+                .with_children(|parent| {
+                    // --- Title ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(10.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Select Hero"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("hero_select".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    OriginColor::<TextColor>::fill(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    // --- Hero List ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(94.0),
+                                height: Val::Percent(63.0),
+                                flex_direction: FlexDirection::Row,
+                                flex_wrap: FlexWrap::Wrap,
+                                justify_content: JustifyContent::Center,
+                                align_content: AlignContent::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            for hero in (0..NUM_HEROS).filter_map(Hero::new) {
+                                spawn_hero_button(
+                                    parent,
+                                    asset_server,
+                                    loading_entities,
+                                    hero,
+                                    hero == current,
+                                );
+                            }
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Exit Button ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(40.0),
+                                height: Val::Percent(11.0),
+                                border: UiRect::all(Val::VMin(0.8)),
+                                flex_direction: FlexDirection::Column,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(30.0)),
+                            OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+                            BorderColor::all(BORDER_YELLO_COLOR_0),
+                            BackgroundColor(BG_YELLO_COLOR_0),
+                            Visibility::Inherited,
+                            PNButton::Positive,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Back"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("back".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    OriginColor::<TextColor>::fill(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+                })
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+fn spawn_hero_button(
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+    hero: Hero,
+    selected: bool,
+) {
+    let (bg_color, border_color) = if selected {
+        (BG_YELLO_COLOR_0, BORDER_YELLO_COLOR_0)
+    } else {
+        (BG_GREEN_COLOR_2, BORDER_GREEN_COLOR_2)
+    };
+
+    let entity = parent
+        .spawn((
+            Node {
+                width: Val::Percent(100.0 / NUM_COLUMNS as f32 - 1.5),
+                height: Val::Percent(13.0),
+                margin: UiRect::all(Val::VMin(0.5)),
+                border: UiRect::all(Val::VMin(0.5)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(20.0)),
+            OriginColor::<BackgroundColor>::new(bg_color),
+            BorderColor::all(border_color),
+            BackgroundColor(bg_color),
+            Visibility::Inherited,
+            HeroButton(hero),
+            SpawnRequest,
+            Button,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(hero.to_string()),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    ResizableFont::vertical(1280.0, 24.0),
+                    OriginColor::<TextColor>::new(Color::BLACK),
+                    TextColor::BLACK,
+                    Visibility::Inherited,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn observe_entity_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(HeroSelectLevelEntity);
+        if child_of.is_none() {
+            commands.insert(TitleLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    loading_entities: Res<LoadingEntities>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if loading_entities.is_empty() {
+        next_state.set(LevelStates::InitRename);
+    }
+}
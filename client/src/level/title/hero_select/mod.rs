@@ -0,0 +1,162 @@
+mod init;
+mod switch;
+
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(switch::InnerPlugin)
+            .add_systems(OnEnter(LevelStates::HeroSelect), debug_label)
+            .add_systems(OnExit(LevelStates::HeroSelect), hide_hero_select_interfaces)
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_keyboard_inputs,
+                    handle_pn_button_pressed,
+                    handle_hero_button_pressed,
+                )
+                    .run_if(in_state(LevelStates::HeroSelect)),
+            );
+
+        app.add_systems(
+            Update,
+            packet_receive_loop.run_if(in_state(LevelStates::HeroSelect)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: HeroSelect");
+}
+
+// --- CLEANUP SYSTEMS --
+
+fn hide_hero_select_interfaces(
+    mut query: Query<&mut Visibility, (With<HeroSelectLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_keyboard_inputs(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(LevelStates::InTitle);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_pn_button_pressed(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &PNButton, &Interaction),
+        (
+            With<HeroSelectLevelEntity>,
+            Changed<Interaction>,
+            With<Button>,
+        ),
+    >,
+) {
+    for (entity, &pn_button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match (pn_button, interaction) {
+            (PNButton::Positive, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::InTitle);
+            }
+            (PNButton::Positive, Interaction::Hovered) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_hero_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut player_info: ResMut<PlayerInfo>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    root_query: Query<Entity, With<TitleLevelRoot>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &HeroButton, &Interaction),
+        (
+            With<HeroSelectLevelEntity>,
+            Changed<Interaction>,
+            With<Button>,
+        ),
+    >,
+) {
+    for (entity, &HeroButton(hero), interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match interaction {
+            Interaction::Pressed => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                if hero == player_info.hero {
+                    next_state.set(LevelStates::InTitle);
+                } else {
+                    change_player_hero(
+                        &mut commands,
+                        &network,
+                        &mut player_info,
+                        &mut next_state,
+                        &root_query,
+                        hero,
+                    );
+                }
+                return;
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
@@ -33,7 +33,6 @@ impl Plugin for InnerPlugin {
                     .run_if(in_state(LevelStates::InitTitle)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             packet_receive_loop.run_if(in_state(LevelStates::InitTitle)),
@@ -255,8 +254,8 @@ fn setup_title_interface(
                                         .spawn((
                                             Node::default(),
                                             Text::new(format!(
-                                                "Win:{} Lose:{}",
-                                                player_info.win, player_info.lose
+                                                "Win:{} Lose:{} Draw:{}",
+                                                player_info.win, player_info.lose, player_info.draw
                                             )),
                                             TextFont::from(asset_server.load(FONT_PATH)),
                                             TextLayout::new_with_justify(Justify::Center),
@@ -304,13 +303,13 @@ fn setup_title_interface(
                         .id();
                     loading_entities.insert(entity);
 
-                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
 
                     let entity = parent
                         .spawn((
                             Node {
                                 width: Val::Percent(100.0),
-                                height: Val::Percent(16.0),
+                                height: Val::Percent(12.8),
                                 border: UiRect::all(Val::VMin(1.25)),
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
@@ -339,7 +338,7 @@ fn setup_title_interface(
                                     Text::new("Game Start"),
                                     TextFont::from(asset_server.load(FONT_PATH)),
                                     TextLayout::new_with_justify(Justify::Center),
-                                    ResizableFont::vertical(1280.0, 52.0),
+                                    ResizableFont::vertical(1280.0, 46.0),
                                     TranslatableText("game_start".into()),
                                     OriginColor::<TextColor>::new(Color::BLACK),
                                     TextColor::BLACK,
@@ -352,13 +351,61 @@ fn setup_title_interface(
                         .id();
                     loading_entities.insert(entity);
 
-                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
 
                     let entity = parent
                         .spawn((
                             Node {
                                 width: Val::Percent(100.0),
-                                height: Val::Percent(16.0),
+                                height: Val::Percent(12.8),
+                                border: UiRect::all(Val::VMin(1.25)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(30.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_0),
+                            BorderColor::all(BORDER_GREEN_COLOR_0),
+                            BackgroundColor(BG_GREEN_COLOR_0),
+                            TitleButton::HeroSelect,
+                            Visibility::Inherited,
+                            BoxShadow::new(
+                                Color::BLACK.with_alpha(0.8),
+                                Val::VMin(1.0),
+                                Val::VMin(1.0),
+                                Val::VMin(1.0),
+                                Val::Px(1.0),
+                            ),
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Select Hero"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 46.0),
+                                    TranslatableText("hero_select".into()),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
+
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(12.8),
                                 border: UiRect::all(Val::VMin(1.25)),
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
@@ -387,7 +434,7 @@ fn setup_title_interface(
                                     Text::new("Settings"),
                                     TextFont::from(asset_server.load(FONT_PATH)),
                                     TextLayout::new_with_justify(Justify::Center),
-                                    ResizableFont::vertical(1280.0, 52.0),
+                                    ResizableFont::vertical(1280.0, 46.0),
                                     TranslatableText("game_settings".into()),
                                     OriginColor::<TextColor>::new(Color::BLACK),
                                     TextColor::BLACK,
@@ -400,13 +447,13 @@ fn setup_title_interface(
                         .id();
                     loading_entities.insert(entity);
 
-                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
 
                     let entity = parent
                         .spawn((
                             Node {
                                 width: Val::Percent(100.0),
-                                height: Val::Percent(16.0),
+                                height: Val::Percent(12.8),
                                 border: UiRect::all(Val::VMin(1.25)),
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
@@ -435,7 +482,7 @@ fn setup_title_interface(
                                     Text::new("Ranking"),
                                     TextFont::from(asset_server.load(FONT_PATH)),
                                     TextLayout::new_with_justify(Justify::Center),
-                                    ResizableFont::vertical(1280.0, 52.0),
+                                    ResizableFont::vertical(1280.0, 46.0),
                                     TranslatableText("game_rank".into()),
                                     OriginColor::<TextColor>::new(Color::BLACK),
                                     TextColor::BLACK,
@@ -448,13 +495,13 @@ fn setup_title_interface(
                         .id();
                     loading_entities.insert(entity);
 
-                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
 
                     let entity = parent
                         .spawn((
                             Node {
                                 width: Val::Percent(100.0),
-                                height: Val::Percent(16.0),
+                                height: Val::Percent(12.8),
                                 border: UiRect::all(Val::VMin(1.25)),
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
@@ -483,7 +530,7 @@ fn setup_title_interface(
                                     Text::new("How to play"),
                                     TextFont::from(asset_server.load(FONT_PATH)),
                                     TextLayout::new_with_justify(Justify::Center),
-                                    ResizableFont::vertical(1280.0, 52.0),
+                                    ResizableFont::vertical(1280.0, 46.0),
                                     TranslatableText("how_to_play".into()),
                                     OriginColor::<TextColor>::new(Color::BLACK),
                                     TextColor::BLACK,
@@ -506,15 +553,180 @@ fn setup_title_interface(
                     Node {
                         width: Val::Percent(26.0),
                         height: Val::Percent(90.0),
-                        flex_direction: FlexDirection::Row,
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::FlexEnd,
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::FlexEnd,
+                        align_items: AlignItems::Center,
                         ..Default::default()
                     },
                     Visibility::Inherited,
                     SpawnRequest,
                 ))
                 .with_children(|parent| {
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                height: Val::Percent(8.0),
+                                border: UiRect::all(Val::VMin(1.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(50.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_0),
+                            BorderColor::all(BORDER_GREEN_COLOR_0),
+                            BackgroundColor(BG_GREEN_COLOR_0),
+                            TitleButton::Practice,
+                            Visibility::Inherited,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Practice"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TranslatableText("practice".into()),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                height: Val::Percent(8.0),
+                                border: UiRect::all(Val::VMin(1.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(50.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_0),
+                            BorderColor::all(BORDER_GREEN_COLOR_0),
+                            BackgroundColor(BG_GREEN_COLOR_0),
+                            TitleButton::Watch,
+                            Visibility::Inherited,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Watch"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TranslatableText("watch".into()),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                height: Val::Percent(8.0),
+                                border: UiRect::all(Val::VMin(1.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(50.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_0),
+                            BorderColor::all(BORDER_GREEN_COLOR_0),
+                            BackgroundColor(BG_GREEN_COLOR_0),
+                            TitleButton::Room,
+                            Visibility::Inherited,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Room"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TranslatableText("room".into()),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                height: Val::Percent(8.0),
+                                border: UiRect::all(Val::VMin(1.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(50.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_0),
+                            BorderColor::all(BORDER_GREEN_COLOR_0),
+                            BackgroundColor(BG_GREEN_COLOR_0),
+                            TitleButton::Profile,
+                            Visibility::Inherited,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Profile"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 36.0),
+                                    TranslatableText("profile".into()),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    // Leaves room for the decorations above the name plate.
+                    add_vertical_space(loading_entities, parent, Val::Percent(8.0));
+
                     let entity = parent
                         .spawn((
                             Node {
@@ -526,10 +738,13 @@ fn setup_title_interface(
                                 ..Default::default()
                             },
                             BorderRadius::all(Val::Percent(50.0)),
+                            OriginColor::<BackgroundColor>::new(BG_GREEN_COLOR_3),
                             BorderColor::all(BORDER_GREEN_COLOR_0),
                             BackgroundColor(BG_GREEN_COLOR_3),
+                            TitleButton::Rename,
                             Visibility::Inherited,
                             SpawnRequest,
+                            Button,
                         ))
                         .with_children(|parent| {
                             let entity = parent
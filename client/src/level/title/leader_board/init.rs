@@ -1,5 +1,5 @@
 // Import necessary Bevy modules.
-use bevy::prelude::*;
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 
 use super::*;
 
@@ -97,6 +97,7 @@ fn setup_leader_board_interface(
                             Node {
                                 width: Val::Percent(90.0),
                                 height: Val::Percent(12.0),
+                                flex_direction: FlexDirection::Row,
                                 justify_content: JustifyContent::Center,
                                 align_items: AlignItems::Center,
                                 ..Default::default()
@@ -105,21 +106,84 @@ fn setup_leader_board_interface(
                             SpawnRequest,
                         ))
                         .with_children(|parent| {
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::PrevSeason,
+                                Val::Percent(10.0),
+                                "<",
+                            );
+
                             let entity = parent
                                 .spawn((
-                                    Node::default(),
-                                    Text::new("Ranking"),
-                                    TextFont::from(asset_server.load(FONT_PATH)),
-                                    TextLayout::new_with_justify(Justify::Center),
-                                    TranslatableText("game_rank".into()),
-                                    ResizableFont::vertical(1280.0, 42.0),
-                                    OriginColor::<TextColor>::fill(Color::BLACK),
-                                    TextColor::BLACK,
+                                    Node {
+                                        width: Val::Percent(60.0),
+                                        height: Val::Percent(100.0),
+                                        flex_direction: FlexDirection::Column,
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
                                     Visibility::Inherited,
                                     SpawnRequest,
                                 ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("Ranking"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("game_rank".into()),
+                                            ResizableFont::vertical(1280.0, 42.0),
+                                            OriginColor::<TextColor>::new(Color::BLACK),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("Season "),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("season".into()),
+                                            ResizableFont::vertical(1280.0, 24.0),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    TextSpan::new("-"),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    ResizableFont::vertical(1280.0, 24.0),
+                                                    TextColor::BLACK,
+                                                    SpawnRequest,
+                                                    RankSeasonText,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
                                 .id();
                             loading_entities.insert(entity);
+
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::NextSeason,
+                                Val::Percent(10.0),
+                                ">",
+                            );
                         })
                         .id();
                     loading_entities.insert(entity);
@@ -131,7 +195,7 @@ fn setup_leader_board_interface(
                         .spawn((
                             Node {
                                 width: Val::Percent(90.0),
-                                height: Val::Percent(67.0),
+                                height: Val::Percent(48.0),
                                 border: UiRect::all(Val::VMin(0.5)),
                                 flex_direction: FlexDirection::Column,
                                 justify_content: JustifyContent::Center,
@@ -195,7 +259,7 @@ fn setup_leader_board_interface(
                                     let entity = parent
                                         .spawn((
                                             Node {
-                                                width: Val::Percent(45.0),
+                                                width: Val::Percent(25.0),
                                                 height: Val::Percent(100.0),
                                                 justify_content: JustifyContent::Center,
                                                 align_items: AlignItems::Center,
@@ -254,6 +318,38 @@ fn setup_leader_board_interface(
                                         .id();
                                     loading_entities.insert(entity);
 
+                                    // --- Rating ---
+                                    let entity = parent
+                                        .spawn((
+                                            Node {
+                                                width: Val::Percent(10.0),
+                                                height: Val::Percent(100.0),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..Default::default()
+                                            },
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    Node::default(),
+                                                    Text::new("Rating"),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    TextLayout::new_with_justify(Justify::Center),
+                                                    TranslatableText("rating".into()),
+                                                    ResizableFont::vertical(1280.0, 21.0),
+                                                    TextColor::BLACK,
+                                                    Visibility::Inherited,
+                                                    SpawnRequest,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
+
                                     // --- Win ---
                                     let entity = parent
                                         .spawn((
@@ -317,214 +413,349 @@ fn setup_leader_board_interface(
                                         })
                                         .id();
                                     loading_entities.insert(entity);
+
+                                    // --- Draw ---
+                                    let entity = parent
+                                        .spawn((
+                                            Node {
+                                                width: Val::Percent(10.0),
+                                                height: Val::Percent(100.0),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..Default::default()
+                                            },
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    Node::default(),
+                                                    Text::new("Draw"),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    TextLayout::new_with_justify(Justify::Center),
+                                                    TranslatableText("draw".into()),
+                                                    ResizableFont::vertical(1280.0, 21.0),
+                                                    TextColor::BLACK,
+                                                    Visibility::Inherited,
+                                                    SpawnRequest,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
                                 })
                                 .id();
                             loading_entities.insert(entity);
 
                             // --- Content ---
-                            const LINE_COLOR: [Color; 2] =
-                                [Color::WHITE, Color::srgb(0.88, 0.88, 0.88)];
-                            for i in 0..10 {
-                                let entity = parent
+                            let entity =
+                                parent
                                     .spawn((
                                         Node {
                                             width: Val::Percent(100.0),
-                                            height: Val::Percent(8.0),
-                                            justify_content: JustifyContent::Center,
-                                            align_items: AlignItems::Center,
+                                            height: Val::Percent(80.0),
+                                            flex_direction: FlexDirection::Column,
+                                            overflow: Overflow::scroll_y(),
                                             ..Default::default()
                                         },
-                                        BackgroundColor(LINE_COLOR[i % 2]),
                                         Visibility::Inherited,
                                         SpawnRequest,
+                                        RankList,
                                     ))
                                     .with_children(|parent| {
-                                        // --- Rank ---
-                                        let entity = parent
-                                            .spawn((
-                                                Node {
-                                                    width: Val::Percent(10.0),
-                                                    height: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::Center,
-                                                    align_items: AlignItems::Center,
-                                                    ..Default::default()
-                                                },
-                                                Visibility::Inherited,
-                                                SpawnRequest,
-                                            ))
-                                            .with_children(|parent| {
-                                                let entity = parent
-                                                    .spawn((
-                                                        Node::default(),
-                                                        Text::new(format!("{}", i + 1)),
-                                                        TextFont::from(
-                                                            asset_server.load(FONT_PATH),
-                                                        ),
-                                                        TextLayout::new_with_justify(
-                                                            Justify::Center,
-                                                        ),
-                                                        ResizableFont::vertical(1280.0, 20.0),
-                                                        TextColor::BLACK,
-                                                        Visibility::Inherited,
-                                                        SpawnRequest,
-                                                    ))
-                                                    .id();
-                                                loading_entities.insert(entity);
-                                            })
-                                            .id();
-                                        loading_entities.insert(entity);
-
-                                        // --- Uuid ---
-                                        let entity = parent
-                                            .spawn((
-                                                Node {
-                                                    width: Val::Percent(45.0),
-                                                    height: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::Center,
-                                                    align_items: AlignItems::Center,
-                                                    ..Default::default()
-                                                },
-                                                Visibility::Inherited,
-                                                SpawnRequest,
-                                            ))
-                                            .with_children(|parent| {
-                                                let entity = parent
-                                                    .spawn((
-                                                        Node::default(),
-                                                        Text::new("-"),
-                                                        TextFont::from(
-                                                            asset_server.load(FONT_PATH),
-                                                        ),
-                                                        TextLayout::new_with_justify(
-                                                            Justify::Center,
-                                                        ),
-                                                        ResizableFont::vertical(1280.0, 20.0),
-                                                        TextColor::BLACK,
-                                                        Visibility::Inherited,
-                                                        SpawnRequest,
-                                                        RankItemUuid,
-                                                        RankEntry(i),
-                                                    ))
-                                                    .id();
-                                                loading_entities.insert(entity);
-                                            })
-                                            .id();
-                                        loading_entities.insert(entity);
-
-                                        // --- Name ---
-                                        let entity = parent
-                                            .spawn((
-                                                Node {
-                                                    width: Val::Percent(25.0),
-                                                    height: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::Center,
-                                                    align_items: AlignItems::Center,
-                                                    ..Default::default()
-                                                },
-                                                Visibility::Inherited,
-                                                SpawnRequest,
-                                            ))
-                                            .with_children(|parent| {
-                                                let entity = parent
+                                        for i in 0..RANKING_PAGE_SIZE as usize {
+                                            let entity =
+                                                parent
                                                     .spawn((
-                                                        Node::default(),
-                                                        Text::new("-"),
-                                                        TextFont::from(
-                                                            asset_server.load(FONT_PATH),
-                                                        ),
-                                                        TextLayout::new_with_justify(
-                                                            Justify::Center,
-                                                        ),
-                                                        ResizableFont::vertical(1280.0, 20.0),
-                                                        TextColor::BLACK,
+                                                        Node {
+                                                            width: Val::Percent(100.0),
+                                                            height: Val::Percent(10.0),
+                                                            flex_shrink: 0.0,
+                                                            justify_content: JustifyContent::Center,
+                                                            align_items: AlignItems::Center,
+                                                            ..Default::default()
+                                                        },
+                                                        BackgroundColor(LINE_COLOR[i % 2]),
                                                         Visibility::Inherited,
                                                         SpawnRequest,
-                                                        RankItemName,
-                                                        RankEntry(i),
-                                                    ))
-                                                    .id();
-                                                loading_entities.insert(entity);
-                                            })
-                                            .id();
-                                        loading_entities.insert(entity);
-
-                                        // --- Win ---
-                                        let entity = parent
-                                            .spawn((
-                                                Node {
-                                                    width: Val::Percent(10.0),
-                                                    height: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::Center,
-                                                    align_items: AlignItems::Center,
-                                                    ..Default::default()
-                                                },
-                                                Visibility::Inherited,
-                                                SpawnRequest,
-                                            ))
-                                            .with_children(|parent| {
-                                                let entity = parent
-                                                    .spawn((
-                                                        Node::default(),
-                                                        Text::new("-"),
-                                                        TextFont::from(
-                                                            asset_server.load(FONT_PATH),
-                                                        ),
-                                                        TextLayout::new_with_justify(
-                                                            Justify::Center,
-                                                        ),
-                                                        ResizableFont::vertical(1280.0, 20.0),
-                                                        TextColor::BLACK,
-                                                        Visibility::Inherited,
-                                                        SpawnRequest,
-                                                        RankItemWins,
-                                                        RankEntry(i),
-                                                    ))
-                                                    .id();
-                                                loading_entities.insert(entity);
-                                            })
-                                            .id();
-                                        loading_entities.insert(entity);
-
-                                        // --- Lose ---
-                                        let entity = parent
-                                            .spawn((
-                                                Node {
-                                                    width: Val::Percent(10.0),
-                                                    height: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::Center,
-                                                    align_items: AlignItems::Center,
-                                                    ..Default::default()
-                                                },
-                                                Visibility::Inherited,
-                                                SpawnRequest,
-                                            ))
-                                            .with_children(|parent| {
-                                                let entity = parent
-                                                    .spawn((
-                                                        Node::default(),
-                                                        Text::new("-"),
-                                                        TextFont::from(
-                                                            asset_server.load(FONT_PATH),
-                                                        ),
-                                                        TextLayout::new_with_justify(
-                                                            Justify::Center,
-                                                        ),
-                                                        ResizableFont::vertical(1280.0, 21.0),
-                                                        TextColor::BLACK,
-                                                        Visibility::Inherited,
-                                                        RankItemLosses,
-                                                        SpawnRequest,
+                                                        RankRow,
                                                         RankEntry(i),
                                                     ))
+                                                    .with_children(|parent| {
+                                                        // --- Rank ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(10.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 20.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            SpawnRequest,
+                                                            RankItemRank,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Uuid ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(25.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 20.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            SpawnRequest,
+                                                            RankItemUuid,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Name ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(25.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 20.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            SpawnRequest,
+                                                            RankItemName,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Rating ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(10.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 20.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            SpawnRequest,
+                                                            RankItemRating,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Win ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(10.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 20.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            SpawnRequest,
+                                                            RankItemRecord::Wins,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Lose ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(10.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 21.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            RankItemRecord::Losses,
+                                                            SpawnRequest,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+
+                                                        // --- Draw ---
+                                                        let entity = parent
+                                                            .spawn((
+                                                                Node {
+                                                                    width: Val::Percent(10.0),
+                                                                    height: Val::Percent(100.0),
+                                                                    justify_content:
+                                                                        JustifyContent::Center,
+                                                                    align_items: AlignItems::Center,
+                                                                    ..Default::default()
+                                                                },
+                                                                Visibility::Inherited,
+                                                                SpawnRequest,
+                                                            ))
+                                                            .with_children(|parent| {
+                                                                let entity = parent
+                                                        .spawn((
+                                                            Node::default(),
+                                                            Text::new("-"),
+                                                            TextFont::from(
+                                                                asset_server.load(FONT_PATH),
+                                                            ),
+                                                            TextLayout::new_with_justify(
+                                                                Justify::Center,
+                                                            ),
+                                                            ResizableFont::vertical(1280.0, 21.0),
+                                                            TextColor::BLACK,
+                                                            Visibility::Inherited,
+                                                            RankItemRecord::Draws,
+                                                            SpawnRequest,
+                                                            RankEntry(i),
+                                                        ))
+                                                        .id();
+                                                                loading_entities.insert(entity);
+                                                            })
+                                                            .id();
+                                                        loading_entities.insert(entity);
+                                                    })
                                                     .id();
-                                                loading_entities.insert(entity);
-                                            })
-                                            .id();
-                                        loading_entities.insert(entity);
+                                            loading_entities.insert(entity);
+                                        }
                                     })
                                     .id();
-                                loading_entities.insert(entity);
-                            }
+                            loading_entities.insert(entity);
 
                             // --- My Rank ---
                             let entity = parent
@@ -578,7 +809,7 @@ fn setup_leader_board_interface(
                                     let entity = parent
                                         .spawn((
                                             Node {
-                                                width: Val::Percent(45.0),
+                                                width: Val::Percent(25.0),
                                                 height: Val::Percent(100.0),
                                                 justify_content: JustifyContent::Center,
                                                 align_items: AlignItems::Center,
@@ -636,6 +867,38 @@ fn setup_leader_board_interface(
                                         .id();
                                     loading_entities.insert(entity);
 
+                                    // --- Rating ---
+                                    let entity = parent
+                                        .spawn((
+                                            Node {
+                                                width: Val::Percent(10.0),
+                                                height: Val::Percent(100.0),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..Default::default()
+                                            },
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    Node::default(),
+                                                    Text::new("-"),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    TextLayout::new_with_justify(Justify::Center),
+                                                    ResizableFont::vertical(1280.0, 20.0),
+                                                    TextColor::BLACK,
+                                                    Visibility::Inherited,
+                                                    SpawnRequest,
+                                                    RankItemRating,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
+
                                     // --- Win ---
                                     let entity = parent
                                         .spawn((
@@ -660,7 +923,7 @@ fn setup_leader_board_interface(
                                                     TextColor::BLACK,
                                                     Visibility::Inherited,
                                                     SpawnRequest,
-                                                    RankItemWins,
+                                                    RankItemRecord::Wins,
                                                 ))
                                                 .id();
                                             loading_entities.insert(entity);
@@ -691,7 +954,7 @@ fn setup_leader_board_interface(
                                                     ResizableFont::vertical(1280.0, 21.0),
                                                     TextColor::BLACK,
                                                     Visibility::Inherited,
-                                                    RankItemLosses,
+                                                    RankItemRecord::Losses,
                                                     SpawnRequest,
                                                 ))
                                                 .id();
@@ -699,6 +962,172 @@ fn setup_leader_board_interface(
                                         })
                                         .id();
                                     loading_entities.insert(entity);
+
+                                    // --- Draw ---
+                                    let entity = parent
+                                        .spawn((
+                                            Node {
+                                                width: Val::Percent(10.0),
+                                                height: Val::Percent(100.0),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..Default::default()
+                                            },
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    Node::default(),
+                                                    Text::new(format!("{}", player_info.draw)),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    TextLayout::new_with_justify(Justify::Center),
+                                                    ResizableFont::vertical(1280.0, 21.0),
+                                                    TextColor::BLACK,
+                                                    Visibility::Inherited,
+                                                    RankItemRecord::Draws,
+                                                    SpawnRequest,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    // --- Hero Filter ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(7.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::PrevHero,
+                                Val::Percent(12.0),
+                                "<",
+                            );
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(30.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("All Heroes"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("all_heroes".into()),
+                                            ResizableFont::vertical(1280.0, 28.0),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                            RankAllHeroesText,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+
+                                    let entity = parent
+                                        .spawn((
+                                            Node {
+                                                display: Display::None,
+                                                ..Default::default()
+                                            },
+                                            Text::new("-"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            ResizableFont::vertical(1280.0, 28.0),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                            RankHeroText,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::NextHero,
+                                Val::Percent(12.0),
+                                ">",
+                            );
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(4.0));
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(42.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("Win Rate "),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("hero_win_rate".into()),
+                                            ResizableFont::vertical(1280.0, 28.0),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .with_children(|parent| {
+                                            let entity = parent
+                                                .spawn((
+                                                    TextSpan::new("-"),
+                                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                                    ResizableFont::vertical(1280.0, 28.0),
+                                                    TextColor::BLACK,
+                                                    SpawnRequest,
+                                                    RankHeroStatsText,
+                                                ))
+                                                .id();
+                                            loading_entities.insert(entity);
+                                        })
+                                        .id();
+                                    loading_entities.insert(entity);
                                 })
                                 .id();
                             loading_entities.insert(entity);
@@ -706,6 +1135,86 @@ fn setup_leader_board_interface(
                         .id();
                     loading_entities.insert(entity);
 
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    // --- Page Controls ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(8.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::PrevPage,
+                                Val::Percent(12.0),
+                                "<",
+                            );
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(24.0),
+                                        height: Val::Percent(100.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("- / -"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            ResizableFont::vertical(1280.0, 28.0),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                            RankPageText,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::NextPage,
+                                Val::Percent(12.0),
+                                ">",
+                            );
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(6.0));
+
+                            add_page_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                LeaderBoardButton::AroundMe,
+                                Val::Percent(28.0),
+                                "around_me",
+                            );
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
                     add_vertical_space(loading_entities, parent, Val::Percent(3.0));
 
                     // --- Exit Button ---
@@ -738,7 +1247,7 @@ fn setup_leader_board_interface(
                                     TextLayout::new_with_justify(Justify::Center),
                                     TranslatableText("back".into()),
                                     ResizableFont::vertical(1280.0, 42.0),
-                                    OriginColor::<TextColor>::fill(Color::BLACK),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
                                     TextColor::BLACK,
                                     Visibility::Inherited,
                                     SpawnRequest,
@@ -758,6 +1267,56 @@ fn setup_leader_board_interface(
     loading_entities.insert(entity);
 }
 
+/// Adds a yellow button that moves through the leaderboard.
+/// Labels other than arrows are translated.
+fn add_page_button(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    button: LeaderBoardButton,
+    width: Val,
+    label: &str,
+) {
+    let entity = parent
+        .spawn((
+            Node {
+                width,
+                height: Val::Percent(100.0),
+                border: UiRect::all(Val::VMin(0.5)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(30.0)),
+            OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+            BorderColor::all(BORDER_YELLO_COLOR_0),
+            BackgroundColor(BG_YELLO_COLOR_0),
+            Visibility::Inherited,
+            SpawnRequest,
+            button,
+            Button,
+        ))
+        .with_children(|parent| {
+            let mut commands = parent.spawn((
+                Node::default(),
+                Text::new(label),
+                TextFont::from(asset_server.load(FONT_PATH)),
+                TextLayout::new_with_justify(Justify::Center),
+                ResizableFont::vertical(1280.0, 28.0),
+                OriginColor::<TextColor>::new(Color::BLACK),
+                TextColor::BLACK,
+                Visibility::Inherited,
+                SpawnRequest,
+            ));
+            if label.chars().any(char::is_alphabetic) {
+                commands.insert(TranslatableText(label.into()));
+            }
+            loading_entities.insert(commands.id());
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
 // --- UPDATE SYSTEMS ---
 
 fn observe_entity_creation(
@@ -783,6 +1342,6 @@ fn check_loading_progress(
     mut next_state: ResMut<NextState<LevelStates>>,
 ) {
     if loading_entities.is_empty() {
-        next_state.set(LevelStates::InitEnterGame);
+        next_state.set(LevelStates::InitHeroSelect);
     }
 }
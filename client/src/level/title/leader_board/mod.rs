@@ -2,10 +2,17 @@ mod init;
 mod switch;
 
 // Import necessary Bevy modules.
-use bevy::prelude::*;
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
 
 use super::*;
 
+const LINE_COLOR: [Color; 2] = [Color::WHITE, Color::srgb(0.88, 0.88, 0.88)];
+const MY_LINE_COLOR: Color = BG_GREEN_COLOR_1;
+const SCROLL_LINE_HEIGHT: f32 = 21.0;
+
 // --- PLUGIN ---
 
 pub struct InnerPlugin;
@@ -14,14 +21,21 @@ impl Plugin for InnerPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(init::InnerPlugin)
             .add_plugins(switch::InnerPlugin)
-            .add_systems(OnEnter(LevelStates::LeaderBoard), debug_label)
+            .add_systems(
+                OnEnter(LevelStates::LeaderBoard),
+                (debug_label, setup_ranking_page, setup_hero_stats),
+            )
             .add_systems(
                 OnExit(LevelStates::LeaderBoard),
                 (cleanup_ranking_data, hide_leaderboard_interfaces),
             )
             .add_systems(
                 PreUpdate,
-                (handle_keyboard_inputs, handle_pn_button_pressed)
+                (
+                    handle_keyboard_inputs,
+                    handle_pn_button_pressed,
+                    handle_leaderboard_button_pressed,
+                )
                     .run_if(in_state(LevelStates::LeaderBoard)),
             )
             .add_systems(
@@ -29,9 +43,19 @@ impl Plugin for InnerPlugin {
                 setup_leaderboard_interfaces
                     .run_if(resource_added::<RankingData>)
                     .run_if(in_state(LevelStates::LeaderBoard)),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_page_text.run_if(resource_changed::<RankingPage>),
+                    update_hero_filter_text.run_if(
+                        resource_changed::<RankingPage>.or(resource_changed::<HeroStatsData>),
+                    ),
+                    scroll_rank_list,
+                )
+                    .run_if(in_state(LevelStates::LeaderBoard)),
             );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
             handle_received_packets.run_if(in_state(LevelStates::LeaderBoard)),
@@ -45,10 +69,21 @@ fn debug_label() {
     info!("Current Level: LeaderBoard");
 }
 
+fn setup_ranking_page(mut commands: Commands) {
+    commands.insert_resource(RankingPage::default());
+}
+
+fn setup_hero_stats(mut commands: Commands, network: Res<Network>) {
+    commands.insert_resource(HeroStatsData::default());
+    send_hero_stats_query(&network);
+}
+
 // --- CLEANUP SYSTEMS --
 
 fn cleanup_ranking_data(mut commands: Commands) {
     commands.remove_resource::<RankingData>();
+    commands.remove_resource::<RankingPage>();
+    commands.remove_resource::<HeroStatsData>();
 }
 
 fn hide_leaderboard_interfaces(
@@ -62,11 +97,21 @@ fn hide_leaderboard_interfaces(
 // --- PREUPDATE SYSTEMS ---
 
 fn handle_keyboard_inputs(
+    network: Res<Network>,
+    ranking_page: Res<RankingPage>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<LevelStates>>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
         next_state.set(LevelStates::InTitle);
+    } else if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        if let Some(page) = ranking_page.prev_page() {
+            send_query(&network, ranking_page.board, RankingView::Page(page));
+        }
+    } else if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        if let Some(page) = ranking_page.next_page() {
+            send_query(&network, ranking_page.board, RankingView::Page(page));
+        }
     }
 }
 
@@ -113,9 +158,76 @@ fn handle_pn_button_pressed(
     }
 }
 
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_leaderboard_button_pressed(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    network: Res<Network>,
+    ranking_page: Res<RankingPage>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &LeaderBoardButton, &Interaction),
+        (
+            With<LeaderBoardLevelEntity>,
+            Changed<Interaction>,
+            With<Button>,
+        ),
+    >,
+) {
+    for (entity, &button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match interaction {
+            Interaction::Pressed => {
+                let board = ranking_page.board;
+                let query = match button {
+                    LeaderBoardButton::PrevSeason => ranking_page
+                        .prev_season()
+                        .map(|season| (Some(RankingBoard::Season(season)), RankingView::Page(0))),
+                    LeaderBoardButton::NextSeason => ranking_page
+                        .next_season()
+                        .map(|season| (Some(RankingBoard::Season(season)), RankingView::Page(0))),
+                    LeaderBoardButton::PrevHero => ranking_page
+                        .cycle_hero(false)
+                        .map(|board| (Some(board), RankingView::Page(0))),
+                    LeaderBoardButton::NextHero => ranking_page
+                        .cycle_hero(true)
+                        .map(|board| (Some(board), RankingView::Page(0))),
+                    LeaderBoardButton::PrevPage => ranking_page
+                        .prev_page()
+                        .map(|page| (board, RankingView::Page(page))),
+                    LeaderBoardButton::NextPage => ranking_page
+                        .next_page()
+                        .map(|page| (board, RankingView::Page(page))),
+                    LeaderBoardButton::AroundMe => Some((board, RankingView::AroundMe)),
+                };
+                if let Some((board, view)) = query {
+                    send_query(&network, board, view);
+                }
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
 // --- UPDATE SYSTEMS ---
 
-#[cfg(target_arch = "wasm32")]
 fn handle_received_packets(
     mut commands: Commands,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -124,8 +236,51 @@ fn handle_received_packets(
     for result in network.receiver.try_iter() {
         match result {
             Ok(packet) => match packet {
-                Packet::RankingResult { my_rank, top_list } => {
-                    commands.insert_resource(RankingData::new(my_rank, top_list));
+                Packet::RankingResult {
+                    season,
+                    current_season,
+                    my_rank,
+                    my_rating,
+                    total,
+                    items,
+                } => {
+                    commands.insert_resource(RankingData::new(
+                        RankingBoard::Season(season),
+                        Some(current_season),
+                        my_rank,
+                        my_rating,
+                        total,
+                        items,
+                    ));
+                }
+                Packet::HeroRankingResult {
+                    hero,
+                    my_rank,
+                    total,
+                    items,
+                } => {
+                    commands.insert_resource(RankingData::new(
+                        RankingBoard::Hero(hero),
+                        None,
+                        my_rank,
+                        None,
+                        total,
+                        items,
+                    ));
+                }
+                Packet::HeroStatsResult { overall, .. } => {
+                    commands.insert_resource(HeroStatsData { overall });
+                }
+                Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(RejoinInfo {
+                        left,
+                        right,
+                        ruleset: *ruleset,
+                    });
                 }
                 _ => { /* empty */ }
             },
@@ -141,47 +296,78 @@ fn handle_received_packets(
 fn setup_leaderboard_interfaces(
     mut commands: Commands,
     ranking_data: Res<RankingData>,
+    mut ranking_page: ResMut<RankingPage>,
+    player_info: Res<PlayerInfo>,
+    mut row_query: Query<(&mut BackgroundColor, &RankEntry), With<RankRow>>,
+    mut scroll_query: Query<&mut ScrollPosition, With<RankList>>,
     mut sets: ParamSet<(
+        Query<(&mut Text, &RankEntry), With<RankItemRank>>,
         Query<(&mut Text, &RankEntry), With<RankItemUuid>>,
         Query<(&mut Text, &RankEntry), With<RankItemName>>,
-        Query<(&mut Text, &RankEntry), With<RankItemWins>>,
-        Query<(&mut Text, &RankEntry), With<RankItemLosses>>,
+        Query<(&mut Text, &RankEntry), With<RankItemRating>>,
+        Query<(&mut Text, &RankEntry, &RankItemRecord)>,
         Query<&mut Text, With<RankItemNum>>,
+        Query<&mut Text, (With<RankItemRating>, Without<RankEntry>)>,
     )>,
 ) {
+    let my_uuid = player_info.uuid.to_string();
+    for (mut background_color, entry) in row_query.iter_mut() {
+        *background_color = match ranking_data.items.get(entry.0) {
+            Some(item) if item.uuid == my_uuid => BackgroundColor(MY_LINE_COLOR),
+            _ => BackgroundColor(LINE_COLOR[entry.0 % 2]),
+        };
+    }
+
+    for mut scroll_position in scroll_query.iter_mut() {
+        scroll_position.y = 0.0;
+    }
+
     for (mut text, entry) in sets.p0().iter_mut() {
-        if let Some(item) = ranking_data.top_list.get(entry.0) {
-            *text = Text::new(item.uuid.to_string());
+        if let Some(item) = ranking_data.items.get(entry.0) {
+            *text = Text::new(format!("{}", item.rank));
         } else {
             *text = Text::new("-");
         }
     }
 
     for (mut text, entry) in sets.p1().iter_mut() {
-        if let Some(item) = ranking_data.top_list.get(entry.0) {
-            *text = Text::new(&item.name);
+        if let Some(item) = ranking_data.items.get(entry.0) {
+            *text = Text::new(item.uuid.to_string());
         } else {
             *text = Text::new("-");
         }
     }
 
     for (mut text, entry) in sets.p2().iter_mut() {
-        if let Some(item) = ranking_data.top_list.get(entry.0) {
-            *text = Text::new(format!("{}", item.wins));
+        if let Some(item) = ranking_data.items.get(entry.0) {
+            *text = Text::new(&item.name);
         } else {
             *text = Text::new("-");
         }
     }
 
     for (mut text, entry) in sets.p3().iter_mut() {
-        if let Some(item) = ranking_data.top_list.get(entry.0) {
-            *text = Text::new(format!("{}", item.losses));
+        if let Some(item) = ranking_data.items.get(entry.0) {
+            *text = Text::new(format!("{}", item.rating));
         } else {
             *text = Text::new("-");
         }
     }
 
-    if let Ok(mut text) = sets.p4().single_mut() {
+    for (mut text, entry, record) in sets.p4().iter_mut() {
+        if let Some(item) = ranking_data.items.get(entry.0) {
+            let value = match record {
+                RankItemRecord::Wins => item.wins,
+                RankItemRecord::Losses => item.losses,
+                RankItemRecord::Draws => item.draws,
+            };
+            *text = Text::new(format!("{}", value));
+        } else {
+            *text = Text::new("-");
+        }
+    }
+
+    if let Ok(mut text) = sets.p5().single_mut() {
         if let Some(rank) = ranking_data.my_rank {
             *text = Text::new(format!("{}", rank));
         } else {
@@ -189,5 +375,110 @@ fn setup_leaderboard_interfaces(
         }
     }
 
+    if let Ok(mut text) = sets.p6().single_mut() {
+        if let Some(rating) = ranking_data.my_rating {
+            *text = Text::new(format!("{}", rating));
+        } else {
+            *text = Text::new("-");
+        }
+    }
+
+    // An empty page keeps its position so that the page label stays valid.
+    let start = ranking_data
+        .items
+        .first()
+        .map(|item| item.rank - 1)
+        .unwrap_or_default();
+    ranking_page.board = Some(ranking_data.board);
+    if let RankingBoard::Season(season) = ranking_data.board {
+        ranking_page.season = Some(season);
+    }
+    if let Some(current_season) = ranking_data.current_season {
+        ranking_page.current_season = current_season;
+    }
+    ranking_page.start = start;
+    ranking_page.total = ranking_data.total;
+
     commands.remove_resource::<RankingData>();
 }
+
+fn update_page_text(
+    ranking_page: Res<RankingPage>,
+    mut text_query: Query<&mut Text, With<RankPageText>>,
+    mut span_query: Query<&mut TextSpan, With<RankSeasonText>>,
+) {
+    if let Ok(mut text) = text_query.single_mut() {
+        *text = Text::new(format!(
+            "{} / {}",
+            ranking_page.current_page() + 1,
+            ranking_page.num_pages()
+        ));
+    }
+
+    if let Ok(mut span) = span_query.single_mut()
+        && let Some(season) = ranking_page.season
+    {
+        *span = TextSpan::new(format!("{}", season));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_hero_filter_text(
+    ranking_page: Res<RankingPage>,
+    hero_stats: Res<HeroStatsData>,
+    mut all_heroes_query: Query<&mut Node, (With<RankAllHeroesText>, Without<RankHeroText>)>,
+    mut hero_query: Query<(&mut Node, &mut Text), (With<RankHeroText>, Without<RankAllHeroesText>)>,
+    mut span_query: Query<&mut TextSpan, With<RankHeroStatsText>>,
+) {
+    let hero = ranking_page.hero();
+    if let Ok(mut node) = all_heroes_query.single_mut() {
+        node.display = match hero {
+            Some(_) => Display::None,
+            None => Display::Flex,
+        };
+    }
+
+    if let Ok((mut node, mut text)) = hero_query.single_mut() {
+        node.display = match hero {
+            Some(_) => Display::Flex,
+            None => Display::None,
+        };
+        if let Some(hero) = hero {
+            *text = Text::new(hero.to_string());
+        }
+    }
+
+    if let Ok(mut span) = span_query.single_mut() {
+        let stats = hero.and_then(|hero| hero_stats.get(hero));
+        *span = match stats.and_then(|stats| Some((stats.win_rate()?, stats.games))) {
+            Some((win_rate, games)) => TextSpan::new(format!("{:.1}% ({})", win_rate, games)),
+            None => TextSpan::new("-"),
+        };
+    }
+}
+
+fn scroll_rank_list(
+    mut mouse_wheel_reader: MessageReader<MouseWheel>,
+    mut query: Query<&mut ScrollPosition, With<RankList>>,
+) {
+    for mouse_wheel in mouse_wheel_reader.read() {
+        let delta = match mouse_wheel.unit {
+            MouseScrollUnit::Line => mouse_wheel.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => mouse_wheel.y,
+        };
+        for mut scroll_position in query.iter_mut() {
+            scroll_position.y -= delta;
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Queries the given leaderboard, or the current season while it is unknown.
+fn send_query(network: &Network, board: Option<RankingBoard>, view: RankingView) {
+    match board {
+        Some(RankingBoard::Season(season)) => send_season_ranking_query(network, season, view),
+        Some(RankingBoard::Hero(hero)) => send_hero_ranking_query(network, hero, view),
+        None => send_ranking_query(network, view),
+    }
+}
@@ -1,7 +1,12 @@
+mod hero_select;
 mod init;
 mod leader_board;
 mod load;
 mod message;
+mod profile;
+mod rename;
+mod room;
+mod watch;
 
 // Import necessary Bevy modules.
 use bevy::{
@@ -10,6 +15,7 @@ use bevy::{
     prelude::*,
 };
 use bevy_spine::{SkeletonController, Spine, SpineReadyEvent};
+use protocol::{RANKING_PAGE_SIZE, RankingView, Ruleset};
 
 use crate::assets::{locale::Locale, sound::SystemVolume};
 
@@ -21,10 +27,15 @@ pub struct InnerPlugin;
 
 impl Plugin for InnerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(init::InnerPlugin)
+        app.add_plugins(hero_select::InnerPlugin)
+            .add_plugins(init::InnerPlugin)
             .add_plugins(leader_board::InnerPlugin)
             .add_plugins(load::InnerPlugin)
             .add_plugins(message::InnerPlugin)
+            .add_plugins(profile::InnerPlugin)
+            .add_plugins(rename::InnerPlugin)
+            .add_plugins(room::InnerPlugin)
+            .add_plugins(watch::InnerPlugin)
             .add_systems(
                 OnEnter(LevelStates::InTitle),
                 (
@@ -63,10 +74,14 @@ impl Plugin for InnerPlugin {
             (change_hero, reverse_spine_forward).run_if(in_state(LevelStates::InTitle)),
         );
 
-        #[cfg(target_arch = "wasm32")]
         app.add_systems(
             Update,
-            packet_receive_loop.run_if(in_state(LevelStates::InTitle)),
+            (
+                packet_receive_loop,
+                enter_rejoined_game.run_if(resource_exists::<RejoinInfo>),
+            )
+                .chain()
+                .run_if(in_state(LevelStates::InTitle)),
         );
     }
 }
@@ -165,6 +180,7 @@ fn hide_title_entities(
 #[cfg(not(feature = "no-debugging-title"))]
 fn change_hero(
     mut commands: Commands,
+    network: Res<Network>,
     mut player_info: ResMut<PlayerInfo>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -172,15 +188,17 @@ fn change_hero(
 ) {
     use protocol::NUM_HEROS;
     if keyboard_input.just_pressed(KeyCode::F3) {
-        commands.remove_resource::<TitleAssets>();
-        for entity in query.iter() {
-            commands.entity(entity).despawn();
-        }
-
         let index = player_info.hero as usize;
         let next = (index + 1) % NUM_HEROS;
-        player_info.hero = Hero::new(next).unwrap();
-        next_state.set(LevelStates::LoadTitle);
+        let hero = Hero::new(next).unwrap();
+        change_player_hero(
+            &mut commands,
+            &network,
+            &mut player_info,
+            &mut next_state,
+            &query,
+            hero,
+        );
     }
 }
 
@@ -203,7 +221,8 @@ fn reverse_spine_forward(
 fn handle_button_interaction(
     mut commands: Commands,
     locale: Res<Locale>,
-    #[cfg(target_arch = "wasm32")] network: Res<Network>,
+    network: Res<Network>,
+    player_info: Res<PlayerInfo>,
     asset_server: Res<AssetServer>,
     system_volume: Res<SystemVolume>,
     mut next_state: ResMut<NextState<LevelStates>>,
@@ -225,35 +244,72 @@ fn handle_button_interaction(
         );
 
         match (button, interaction) {
+            (TitleButton::Rename, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToRename);
+            }
             (TitleButton::GameStart, Interaction::Pressed) => {
-                #[cfg(target_arch = "wasm32")]
                 send_enter_game_message(&network);
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
                 play_effect_sound(&mut commands, &system_volume, source);
                 next_state.set(LevelStates::SwitchToInMatching);
             }
+            (TitleButton::HeroSelect, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToHeroSelect);
+            }
             (TitleButton::Option, Interaction::Pressed) => {
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
                 play_effect_sound(&mut commands, &system_volume, source);
                 next_state.set(LevelStates::SwitchToInOption);
             }
             (TitleButton::Ranking, Interaction::Pressed) => {
-                #[cfg(target_arch = "wasm32")]
-                send_ranking_query(&network);
+                send_ranking_query(&network, RankingView::Page(0));
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
                 play_effect_sound(&mut commands, &system_volume, source);
                 next_state.set(LevelStates::SwitchToLeaderBoard);
             }
+            (TitleButton::Profile, Interaction::Pressed) => {
+                send_profile_query(&network);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToProfile);
+            }
+            (TitleButton::Room, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToRoom);
+            }
+            (TitleButton::Watch, Interaction::Pressed) => {
+                send_live_match_query(&network);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToWatch);
+            }
+            (TitleButton::Practice, Interaction::Pressed) => {
+                setup_practice_match(&mut commands, &player_info);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::SwitchToLoadGame);
+            }
             (TitleButton::HowToPlay, Interaction::Pressed) => {
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
                 play_effect_sound(&mut commands, &system_volume, source);
                 #[cfg(target_arch = "wasm32")]
                 start_game_tutorial(&locale.to_string());
             }
-            (TitleButton::GameStart, Interaction::Hovered)
+            (TitleButton::Rename, Interaction::Hovered)
+            | (TitleButton::GameStart, Interaction::Hovered)
+            | (TitleButton::HeroSelect, Interaction::Hovered)
             | (TitleButton::Option, Interaction::Hovered)
             | (TitleButton::Ranking, Interaction::Hovered)
-            | (TitleButton::HowToPlay, Interaction::Hovered) => {
+            | (TitleButton::HowToPlay, Interaction::Hovered)
+            | (TitleButton::Profile, Interaction::Hovered)
+            | (TitleButton::Room, Interaction::Hovered)
+            | (TitleButton::Watch, Interaction::Hovered)
+            | (TitleButton::Practice, Interaction::Hovered) => {
                 let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
                 play_effect_sound(&mut commands, &system_volume, source);
             }
@@ -336,16 +392,123 @@ fn handle_touch_inputs(
     }
 }
 
+// --- UPDATE SYSTEMS ---
+
+fn enter_rejoined_game(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    rejoin_info: Res<RejoinInfo>,
+    player_info: Res<PlayerInfo>,
+) {
+    let left = rejoin_info.left.clone();
+    let right = rejoin_info.right.clone();
+    commands.remove_resource::<RejoinInfo>();
+    commands.insert_resource(OtherInfo::new(player_info.uuid, left, right));
+    commands.insert_resource(MatchRuleset(rejoin_info.ruleset.clone()));
+    next_state.set(LevelStates::SwitchToLoadGame);
+}
+
 // --- UTILITIES ---
 
-#[cfg(target_arch = "wasm32")]
+/// Starts a match against a bot that is played without the server.
+fn setup_practice_match(commands: &mut Commands, player_info: &PlayerInfo) {
+    let hero: Hero = rand::random();
+    let ruleset = Ruleset::default();
+    commands.insert_resource(PracticeMatch::new(player_info, hero, &ruleset));
+    commands.insert_resource(MatchRuleset(ruleset));
+    commands.insert_resource(OtherInfo {
+        left_side: false,
+        name: format!("Bot {}", hero),
+        hero,
+        win: 0,
+        lose: 0,
+        draw: 0,
+    });
+}
+
 fn send_enter_game_message(network: &Network) {
     let packet = Packet::EnterGame;
     network.send(&packet).unwrap();
 }
 
-#[cfg(target_arch = "wasm32")]
-fn send_ranking_query(network: &Network) {
-    let packet = Packet::RankingQuery;
+fn send_ranking_query(network: &Network, view: RankingView) {
+    let packet = Packet::RankingQuery { view };
+    network.send(&packet).unwrap();
+}
+
+fn send_season_ranking_query(network: &Network, season: u32, view: RankingView) {
+    let packet = Packet::SeasonRankingQuery { season, view };
+    network.send(&packet).unwrap();
+}
+
+fn send_hero_ranking_query(network: &Network, hero: Hero, view: RankingView) {
+    let packet = Packet::HeroRankingQuery { hero, view };
+    network.send(&packet).unwrap();
+}
+
+fn send_hero_stats_query(network: &Network) {
+    let packet = Packet::HeroStatsQuery;
+    network.send(&packet).unwrap();
+}
+
+fn send_profile_query(network: &Network) {
+    let packet = Packet::ProfileQuery;
     network.send(&packet).unwrap();
 }
+
+fn send_create_room_message(network: &Network) {
+    let packet = Packet::CreateRoom;
+    network.send(&packet).unwrap();
+}
+
+fn send_join_room_message(network: &Network, code: &str) {
+    let packet = Packet::JoinRoom {
+        code: code.to_string(),
+    };
+    network.send(&packet).unwrap();
+}
+
+fn send_live_match_query(network: &Network) {
+    let packet = Packet::LiveMatchQuery;
+    network.send(&packet).unwrap();
+}
+
+fn send_watch_match_message(network: &Network, id: u64) {
+    let packet = Packet::WatchMatch { id };
+    network.send(&packet).unwrap();
+}
+
+fn send_change_hero_message(network: &Network, hero: Hero) {
+    let packet = Packet::ChangeHero { hero };
+    network.send(&packet).unwrap();
+}
+
+/// Saves the new hero on the server and reloads the title level with it.
+fn change_player_hero(
+    commands: &mut Commands,
+    network: &Network,
+    player_info: &mut PlayerInfo,
+    next_state: &mut NextState<LevelStates>,
+    query: &Query<Entity, With<TitleLevelRoot>>,
+    hero: Hero,
+) {
+    send_change_hero_message(network, hero);
+
+    commands.remove_resource::<GreetingFlag>();
+    player_info.hero = hero;
+    reload_title_level(commands, next_state, query);
+}
+
+/// Rebuilds the title level to show the changed player information.
+fn reload_title_level(
+    commands: &mut Commands,
+    next_state: &mut NextState<LevelStates>,
+    query: &Query<Entity, With<TitleLevelRoot>>,
+) {
+    commands.remove_resource::<TitleAssets>();
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    next_state.set(LevelStates::LoadTitle);
+}
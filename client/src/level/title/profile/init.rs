@@ -0,0 +1,461 @@
+// Import necessary Bevy modules.
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::InitProfile),
+            (debug_label, setup_profile),
+        )
+        .add_systems(OnExit(LevelStates::InitProfile), cleanup_loading_resource)
+        .add_systems(
+            Update,
+            (
+                update_entity_spawn_progress,
+                observe_entity_creation,
+                check_loading_progress,
+            )
+                .run_if(in_state(LevelStates::InitProfile)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitProfile");
+}
+
+fn setup_profile(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut loading_entities = LoadingEntities::default();
+    setup_profile_interface(&mut commands, &asset_server, &mut loading_entities);
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+fn setup_profile_interface(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            SpawnRequest,
+            ZIndex(3),
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(60.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::VMin(1.25)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Percent(15.0)),
+                    BorderColor::all(BORDER_GREEN_COLOR_0),
+                    BackgroundColor(BG_GREEN_COLOR_3),
+                    Visibility::Inherited,
+                    UiAnimationTarget,
+                    SpawnRequest,
+                ))
+                .with_children(|parent| {
+                    // --- Title ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(12.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Profile"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("profile".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Lifetime Record ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(12.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::SpaceBetween,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            for (stat, key, label) in [
+                                (ProfileStat::Rating, "rating", "Rating"),
+                                (ProfileStat::Wins, "win", "Wins"),
+                                (ProfileStat::Losses, "lose", "Losses"),
+                                (ProfileStat::Draws, "draw", "Draws"),
+                            ] {
+                                add_stat_box(
+                                    loading_entities,
+                                    parent,
+                                    asset_server,
+                                    stat,
+                                    key,
+                                    label,
+                                );
+                            }
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Match History ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(52.0),
+                                border: UiRect::all(Val::VMin(0.5)),
+                                flex_direction: FlexDirection::Column,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderColor::all(Color::BLACK),
+                            BackgroundColor(Color::WHITE),
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            // --- Title ---
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(12.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    BackgroundColor(BG_YELLO_COLOR_0),
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .with_children(|parent| {
+                                    for (column, key, label) in [
+                                        (HistoryColumn::Result, "result", "Result"),
+                                        (HistoryColumn::Hero, "hero", "Hero"),
+                                        (HistoryColumn::Opponent, "opponent", "Opponent"),
+                                        (
+                                            HistoryColumn::OpponentHero,
+                                            "opponent_hero",
+                                            "Their Hero",
+                                        ),
+                                        (HistoryColumn::Hits, "hits", "Hits"),
+                                        (HistoryColumn::Duration, "play_time", "Time"),
+                                    ] {
+                                        add_table_cell(
+                                            loading_entities,
+                                            parent,
+                                            asset_server,
+                                            column,
+                                            label,
+                                            TranslatableText(key.into()),
+                                        );
+                                    }
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+
+                            // --- Content ---
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(100.0),
+                                        height: Val::Percent(88.0),
+                                        flex_direction: FlexDirection::Column,
+                                        overflow: Overflow::scroll_y(),
+                                        ..Default::default()
+                                    },
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                    HistoryList,
+                                ))
+                                .with_children(|parent| {
+                                    for i in 0..MATCH_HISTORY_SIZE {
+                                        let entity = parent
+                                            .spawn((
+                                                Node {
+                                                    width: Val::Percent(100.0),
+                                                    height: Val::Percent(12.5),
+                                                    flex_shrink: 0.0,
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..Default::default()
+                                                },
+                                                BackgroundColor(LINE_COLOR[i % 2]),
+                                                Visibility::Inherited,
+                                                SpawnRequest,
+                                                HistoryRow,
+                                                HistoryEntry(i),
+                                                Button,
+                                            ))
+                                            .with_children(|parent| {
+                                                for column in [
+                                                    HistoryColumn::Result,
+                                                    HistoryColumn::Hero,
+                                                    HistoryColumn::Opponent,
+                                                    HistoryColumn::OpponentHero,
+                                                    HistoryColumn::Hits,
+                                                    HistoryColumn::Duration,
+                                                ] {
+                                                    add_table_cell(
+                                                        loading_entities,
+                                                        parent,
+                                                        asset_server,
+                                                        column,
+                                                        "-",
+                                                        (column, HistoryEntry(i)),
+                                                    );
+                                                }
+                                            })
+                                            .id();
+                                        loading_entities.insert(entity);
+                                    }
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Exit Button ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(40.0),
+                                height: Val::Percent(12.0),
+                                border: UiRect::all(Val::VMin(0.8)),
+                                flex_direction: FlexDirection::Column,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(30.0)),
+                            OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+                            BorderColor::all(BORDER_YELLO_COLOR_0),
+                            BackgroundColor(BG_YELLO_COLOR_0),
+                            Visibility::Inherited,
+                            PNButton::Positive,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Back"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("back".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    OriginColor::<TextColor>::new(Color::BLACK),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+                })
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds a box that shows a translated label above a value of the lifetime record.
+fn add_stat_box(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    stat: ProfileStat,
+    key: &str,
+    label: &str,
+) {
+    let entity = parent
+        .spawn((
+            Node {
+                width: Val::Percent(23.0),
+                height: Val::Percent(100.0),
+                border: UiRect::all(Val::VMin(0.5)),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(20.0)),
+            BorderColor::all(BORDER_GREEN_COLOR_0),
+            BackgroundColor(BG_GREEN_COLOR_2),
+            Visibility::Inherited,
+            SpawnRequest,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(label),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TranslatableText(key.into()),
+                    ResizableFont::vertical(1280.0, 22.0),
+                    TextColor::BLACK,
+                    Visibility::Inherited,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new("-"),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    ResizableFont::vertical(1280.0, 32.0),
+                    TextColor::BLACK,
+                    Visibility::Inherited,
+                    SpawnRequest,
+                    stat,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds a cell of the history table, sized by the column it belongs to.
+fn add_table_cell(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    column: HistoryColumn,
+    label: &str,
+    bundle: impl Bundle,
+) {
+    let width = match column {
+        HistoryColumn::Result => 12.0,
+        HistoryColumn::Hero => 20.0,
+        HistoryColumn::Opponent => 28.0,
+        HistoryColumn::OpponentHero => 20.0,
+        HistoryColumn::Hits => 10.0,
+        HistoryColumn::Duration => 10.0,
+    };
+
+    let entity = parent
+        .spawn((
+            Node {
+                width: Val::Percent(width),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Inherited,
+            SpawnRequest,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(label),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    ResizableFont::vertical(1280.0, 20.0),
+                    TextColor::BLACK,
+                    Visibility::Inherited,
+                    SpawnRequest,
+                    bundle,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn observe_entity_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(ProfileLevelEntity);
+        if child_of.is_none() {
+            commands.insert(TitleLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    loading_entities: Res<LoadingEntities>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if loading_entities.is_empty() {
+        next_state.set(LevelStates::InitRoom);
+    }
+}
@@ -0,0 +1,324 @@
+mod init;
+mod switch;
+
+// Import necessary Bevy modules.
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+use protocol::{MATCH_HISTORY_SIZE, MatchOutcome, MatchRecord, PlayData, ReplayPlayer};
+
+use super::*;
+
+const LINE_COLOR: [Color; 2] = [Color::WHITE, Color::srgb(0.88, 0.88, 0.88)];
+const WIN_COLOR: Color = Color::srgb(0.16, 0.55, 0.22);
+const LOSS_COLOR: Color = Color::srgb(0.78, 0.2, 0.24);
+const DRAW_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+const SCROLL_LINE_HEIGHT: f32 = 21.0;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(switch::InnerPlugin)
+            .add_systems(OnEnter(LevelStates::Profile), debug_label)
+            .add_systems(
+                OnExit(LevelStates::Profile),
+                (cleanup_profile_data, hide_profile_interfaces),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_keyboard_inputs,
+                    handle_pn_button_pressed,
+                    handle_history_row_pressed.run_if(resource_exists::<ProfileData>),
+                )
+                    .run_if(in_state(LevelStates::Profile)),
+            )
+            .add_systems(
+                Update,
+                (
+                    setup_profile_interfaces.run_if(resource_added::<ProfileData>),
+                    scroll_history_list,
+                )
+                    .run_if(in_state(LevelStates::Profile)),
+            );
+
+        app.add_systems(
+            Update,
+            handle_received_packets.run_if(in_state(LevelStates::Profile)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: Profile");
+}
+
+// --- CLEANUP SYSTEMS --
+
+fn cleanup_profile_data(mut commands: Commands) {
+    commands.remove_resource::<ProfileData>();
+}
+
+fn hide_profile_interfaces(
+    mut query: Query<&mut Visibility, (With<ProfileLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_keyboard_inputs(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(LevelStates::InTitle);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_pn_button_pressed(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &PNButton, &Interaction),
+        (With<ProfileLevelEntity>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, &pn_button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match (pn_button, interaction) {
+            (PNButton::Positive, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::InTitle);
+            }
+            (PNButton::Positive, Interaction::Hovered) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+/// Asks the server for the replay of a game in the match history.
+#[allow(clippy::type_complexity)]
+fn handle_history_row_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    profile_data: Res<ProfileData>,
+    interaction_query: Query<
+        (&HistoryEntry, &Interaction),
+        (With<HistoryRow>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entry, interaction) in interaction_query.iter() {
+        let Some(id) = profile_data
+            .history
+            .get(entry.0)
+            .and_then(|record| record.replay_id)
+        else {
+            continue;
+        };
+
+        match interaction {
+            Interaction::Pressed => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                network.send(&Packet::ReplayQuery { id }).unwrap();
+            }
+            Interaction::Hovered => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+) {
+    for result in network.receiver.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::ProfileResult {
+                    rating,
+                    wins,
+                    losses,
+                    draws,
+                    history,
+                } => {
+                    commands.insert_resource(ProfileData {
+                        rating,
+                        wins,
+                        losses,
+                        draws,
+                        history,
+                    });
+                }
+                Packet::ReplayResult {
+                    replay: Some(replay),
+                } => {
+                    commands.insert_resource(SpectateInfo {
+                        left: replay_play_data(&replay.left),
+                        right: replay_play_data(&replay.right),
+                    });
+                    commands.insert_resource(MatchRuleset(replay.ruleset.clone()));
+                    commands.insert_resource(ReplayPlayback::new(*replay));
+                    next_state.set(LevelStates::SwitchToLoadGame);
+                }
+                Packet::ReplayResult { replay: None } => {
+                    commands.insert_resource(ErrorMessage::new(
+                        "replay_expired",
+                        "The replay is no longer available.",
+                    ));
+                    next_state.set(LevelStates::SwitchToTitleMessage);
+                }
+                Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(RejoinInfo {
+                        left,
+                        right,
+                        ruleset: *ruleset,
+                    });
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn setup_profile_interfaces(
+    profile_data: Res<ProfileData>,
+    mut stat_query: Query<(&mut Text, &ProfileStat)>,
+    mut cell_query: Query<
+        (&mut Text, &mut TextColor, &HistoryColumn, &HistoryEntry),
+        Without<ProfileStat>,
+    >,
+    mut scroll_query: Query<&mut ScrollPosition, With<HistoryList>>,
+) {
+    for (mut text, stat) in stat_query.iter_mut() {
+        let value = match stat {
+            ProfileStat::Rating => profile_data.rating.to_string(),
+            ProfileStat::Wins => profile_data.wins.to_string(),
+            ProfileStat::Losses => profile_data.losses.to_string(),
+            ProfileStat::Draws => profile_data.draws.to_string(),
+        };
+        *text = Text::new(value);
+    }
+
+    for (mut text, mut text_color, &column, entry) in cell_query.iter_mut() {
+        let Some(record) = profile_data.history.get(entry.0) else {
+            *text = Text::new("-");
+            *text_color = TextColor::BLACK;
+            continue;
+        };
+
+        *text = Text::new(history_cell(record, column));
+        *text_color = match column {
+            HistoryColumn::Result => TextColor(outcome_color(record.outcome)),
+            _ => TextColor::BLACK,
+        };
+    }
+
+    for mut scroll_position in scroll_query.iter_mut() {
+        scroll_position.y = 0.0;
+    }
+}
+
+fn scroll_history_list(
+    mut mouse_wheel_reader: MessageReader<MouseWheel>,
+    mut query: Query<&mut ScrollPosition, With<HistoryList>>,
+) {
+    for mouse_wheel in mouse_wheel_reader.read() {
+        let delta = match mouse_wheel.unit {
+            MouseScrollUnit::Line => mouse_wheel.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => mouse_wheel.y,
+        };
+        for mut scroll_position in query.iter_mut() {
+            scroll_position.y -= delta;
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Formats a column of a game in the match history.
+fn history_cell(record: &MatchRecord, column: HistoryColumn) -> String {
+    match column {
+        HistoryColumn::Result => match record.outcome {
+            MatchOutcome::Win => "W".into(),
+            MatchOutcome::Loss => "L".into(),
+            MatchOutcome::Draw => "D".into(),
+        },
+        HistoryColumn::Hero => record.hero.to_string(),
+        HistoryColumn::Opponent => match record.opponent_is_bot {
+            true => format!("{} (Bot)", record.opponent_name),
+            false => record.opponent_name.clone(),
+        },
+        HistoryColumn::OpponentHero => record.opponent_hero.to_string(),
+        HistoryColumn::Hits => format!("{} : {}", record.hits_dealt, record.hits_taken),
+        HistoryColumn::Duration => {
+            let seconds = record.duration_millis / 1000;
+            format!("{}:{:02}", seconds / 60, seconds % 60)
+        }
+    }
+}
+
+/// A replay does not keep the records of the players.
+fn replay_play_data(player: &ReplayPlayer) -> PlayData {
+    PlayData {
+        uuid: None,
+        name: player.name.clone(),
+        hero: player.hero,
+        win: 0,
+        lose: 0,
+        draw: 0,
+    }
+}
+
+fn outcome_color(outcome: MatchOutcome) -> Color {
+    match outcome {
+        MatchOutcome::Win => WIN_COLOR,
+        MatchOutcome::Loss => LOSS_COLOR,
+        MatchOutcome::Draw => DRAW_COLOR,
+    }
+}
@@ -0,0 +1,322 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::InitRename),
+            (debug_label, setup_rename),
+        )
+        .add_systems(OnExit(LevelStates::InitRename), cleanup_loading_resource)
+        .add_systems(
+            Update,
+            (
+                update_entity_spawn_progress,
+                observe_entity_creation,
+                check_loading_progress,
+            )
+                .run_if(in_state(LevelStates::InitRename)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitRename");
+}
+
+fn setup_rename(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut loading_entities = LoadingEntities::default();
+    setup_rename_interface(&mut commands, &asset_server, &mut loading_entities);
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+fn setup_rename_interface(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            SpawnRequest,
+            ZIndex(3),
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(50.0),
+                        border: UiRect::all(Val::VMin(1.25)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Percent(30.0)),
+                    BorderColor::all(BORDER_GREEN_COLOR_0),
+                    BackgroundColor(BG_GREEN_COLOR_3),
+                    Visibility::Inherited,
+                    UiAnimationTarget,
+                    SpawnRequest,
+                ))
+                .with_children(|parent| {
+                    // --- Title ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(16.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Change Name"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("rename".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
+
+                    // --- Input Field ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(80.0),
+                                height: Val::Percent(20.0),
+                                border: UiRect::all(Val::VMin(0.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(10.0)),
+                            BorderColor::all(Color::BLACK),
+                            BackgroundColor(Color::WHITE),
+                            Visibility::Inherited,
+                            RenameInputField,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new(""),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 40.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    RenameInputText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Hint ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(12.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Enter a name of 2 to 12 characters."),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("rename_hint".into()),
+                                    ResizableFont::vertical(1280.0, 28.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    RenameHintText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+
+                    // --- Buttons ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(20.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(44.0),
+                                        height: Val::Percent(100.0),
+                                        border: UiRect::all(Val::VMin(0.8)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    BorderRadius::all(Val::Percent(30.0)),
+                                    OriginColor::<BackgroundColor>::new(BG_YELLO_COLOR_0),
+                                    BorderColor::all(BORDER_YELLO_COLOR_0),
+                                    BackgroundColor(BG_YELLO_COLOR_0),
+                                    Visibility::Inherited,
+                                    PNButton::Positive,
+                                    SpawnRequest,
+                                    Button,
+                                ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("Okay"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("okay".into()),
+                                            ResizableFont::vertical(1280.0, 42.0),
+                                            OriginColor::<TextColor>::new(Color::BLACK),
+                                            TextColor::BLACK,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(5.0));
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Percent(44.0),
+                                        height: Val::Percent(100.0),
+                                        border: UiRect::all(Val::VMin(0.8)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..Default::default()
+                                    },
+                                    BorderRadius::all(Val::Percent(30.0)),
+                                    OriginColor::<BackgroundColor>::new(BG_RED_COLOR_0),
+                                    BorderColor::all(BORDER_RED_COLOR_0),
+                                    BackgroundColor(BG_RED_COLOR_0),
+                                    Visibility::Inherited,
+                                    PNButton::Negative,
+                                    SpawnRequest,
+                                    Button,
+                                ))
+                                .with_children(|parent| {
+                                    let entity = parent
+                                        .spawn((
+                                            Node::default(),
+                                            Text::new("Cancel"),
+                                            TextFont::from(asset_server.load(FONT_PATH)),
+                                            TextLayout::new_with_justify(Justify::Center),
+                                            TranslatableText("cancel".into()),
+                                            ResizableFont::vertical(1280.0, 42.0),
+                                            OriginColor::<TextColor>::new(Color::WHITE),
+                                            TextColor::WHITE,
+                                            Visibility::Inherited,
+                                            SpawnRequest,
+                                        ))
+                                        .id();
+                                    loading_entities.insert(entity);
+                                })
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+                })
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn observe_entity_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(RenameLevelEntity);
+        if child_of.is_none() {
+            commands.insert(TitleLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    loading_entities: Res<LoadingEntities>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if loading_entities.is_empty() {
+        next_state.set(LevelStates::InitProfile);
+    }
+}
@@ -0,0 +1,260 @@
+mod init;
+mod switch;
+
+// Import necessary Bevy modules.
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+    window::{Ime, PrimaryWindow},
+};
+use protocol::MAX_NAME_LENGTH;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(switch::InnerPlugin)
+            .add_systems(OnEnter(LevelStates::Rename), (debug_label, enable_ime))
+            .add_systems(
+                OnExit(LevelStates::Rename),
+                (cleanup_name_draft, disable_ime, hide_rename_interfaces),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_keyboard_inputs,
+                    handle_text_inputs,
+                    handle_input_field_pressed,
+                    handle_pn_button_pressed,
+                )
+                    .run_if(in_state(LevelStates::Rename)),
+            )
+            .add_systems(
+                Update,
+                update_input_text.run_if(resource_exists_and_changed::<NameDraft>),
+            );
+
+        app.add_systems(
+            Update,
+            handle_received_packets.run_if(in_state(LevelStates::Rename)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: Rename");
+}
+
+fn enable_ime(mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = windows.single_mut() {
+        window.ime_enabled = true;
+    }
+}
+
+// --- CLEANUP SYSTEMS --
+
+fn cleanup_name_draft(mut commands: Commands) {
+    commands.remove_resource::<NameDraft>();
+}
+
+fn disable_ime(mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = windows.single_mut() {
+        window.ime_enabled = false;
+    }
+}
+
+fn hide_rename_interfaces(
+    mut query: Query<&mut Visibility, (With<RenameLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_keyboard_inputs(
+    network: Res<Network>,
+    name_draft: Res<NameDraft>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(LevelStates::InTitle);
+    } else if keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter]) {
+        send_change_name_message(&network, &name_draft.0);
+    }
+}
+
+fn handle_text_inputs(
+    mut name_draft: ResMut<NameDraft>,
+    mut keyboard_inputs: MessageReader<KeyboardInput>,
+    mut ime_inputs: MessageReader<Ime>,
+) {
+    for event in keyboard_inputs.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Backspace => {
+                name_draft.0.pop();
+            }
+            Key::Space => push_text(&mut name_draft.0, " "),
+            Key::Character(text) => push_text(&mut name_draft.0, text.as_str()),
+            _ => { /* empty */ }
+        }
+    }
+
+    for event in ime_inputs.read() {
+        if let Ime::Commit { value, .. } = event {
+            push_text(&mut name_draft.0, value);
+        }
+    }
+}
+
+#[allow(unused_mut)]
+#[allow(unused_variables)]
+fn handle_input_field_pressed(
+    mut name_draft: ResMut<NameDraft>,
+    hint_query: Query<&Text, With<RenameHintText>>,
+    interaction_query: Query<&Interaction, (With<RenameInputField>, Changed<Interaction>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // Browsers do not send composed text to the canvas, so the name is typed in a dialog.
+        #[cfg(target_arch = "wasm32")]
+        {
+            let message = hint_query
+                .single()
+                .map(|text| text.0.clone())
+                .unwrap_or_default();
+            if let Some(name) = prompt_text(&message, &name_draft.0) {
+                name_draft.0.clear();
+                push_text(&mut name_draft.0, &name);
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_pn_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    name_draft: Res<NameDraft>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &PNButton, &Interaction),
+        (With<RenameLevelEntity>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, &pn_button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match (pn_button, interaction) {
+            (PNButton::Positive, Interaction::Pressed) => {
+                send_change_name_message(&network, &name_draft.0);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (PNButton::Negative, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::InTitle);
+            }
+            (_, Interaction::Hovered) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn update_input_text(
+    name_draft: Res<NameDraft>,
+    mut query: Query<&mut Text, With<RenameInputText>>,
+) {
+    for mut text in query.iter_mut() {
+        *text = Text::new(&name_draft.0);
+    }
+}
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    mut player_info: ResMut<PlayerInfo>,
+    network: Res<Network>,
+    query: Query<Entity, With<TitleLevelRoot>>,
+) {
+    for result in network.receiver.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::NameChanged { name } => {
+                    player_info.name = name;
+                    reload_title_level(&mut commands, &mut next_state, &query);
+                }
+                Packet::NameRejected { reason } => {
+                    commands.insert_resource(ErrorMessage::from(reason));
+                    next_state.set(LevelStates::SwitchToTitleMessage);
+                }
+                Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(RejoinInfo {
+                        left,
+                        right,
+                        ruleset: *ruleset,
+                    });
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Appends the typed text to the name, up to the maximum length.
+fn push_text(name: &mut String, text: &str) {
+    let length = name.chars().count();
+    let chars = text.chars().filter(|c| !c.is_control());
+    name.extend(chars.take(MAX_NAME_LENGTH.saturating_sub(length)));
+}
+
+fn send_change_name_message(network: &Network, name: &str) {
+    let packet = Packet::ChangeName {
+        name: name.to_string(),
+    };
+    network.send(&packet).unwrap();
+}
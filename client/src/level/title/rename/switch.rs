@@ -0,0 +1,84 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::*;
+
+// --- CONSTANTS ---
+const SCENE_DURATION: f32 = UI_POPUP_DURATION;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::SwitchToRename),
+            (
+                debug_label,
+                setup_scene_timer,
+                setup_name_draft,
+                show_rename_entities,
+                setup_rename_interfaces,
+                play_popup_sounds,
+            ),
+        )
+        .add_systems(OnExit(LevelStates::SwitchToRename), cleanup_scene_timer)
+        .add_systems(
+            Update,
+            update_scene_timer.run_if(in_state(LevelStates::SwitchToRename)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: SwitchToRename");
+}
+
+fn setup_scene_timer(mut commands: Commands) {
+    commands.insert_resource(SceneTimer::default());
+}
+
+fn setup_name_draft(mut commands: Commands, player_info: Res<PlayerInfo>) {
+    commands.insert_resource(NameDraft(player_info.name.clone()));
+}
+
+fn show_rename_entities(
+    mut query: Query<&mut Visibility, (With<RenameLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn setup_rename_interfaces(
+    mut commands: Commands,
+    query: Query<Entity, (With<UiAnimationTarget>, With<RenameLevelEntity>)>,
+) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(UiBackOutScale::new(SCENE_DURATION, Vec2::ZERO, Vec2::ONE));
+    }
+}
+
+// --- CLEANUP SYSTEMS ---
+
+fn cleanup_scene_timer(mut commands: Commands) {
+    commands.remove_resource::<SceneTimer>();
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn update_scene_timer(
+    mut next_state: ResMut<NextState<LevelStates>>,
+    mut scene_timer: ResMut<SceneTimer>,
+    time: Res<Time>,
+) {
+    scene_timer.tick(time.delta_secs());
+    if scene_timer.elapsed_sec() >= SCENE_DURATION {
+        next_state.set(LevelStates::Rename);
+    }
+}
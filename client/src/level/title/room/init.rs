@@ -0,0 +1,343 @@
+// Import necessary Bevy modules.
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(LevelStates::InitRoom), (debug_label, setup_room))
+            .add_systems(OnExit(LevelStates::InitRoom), cleanup_loading_resource)
+            .add_systems(
+                Update,
+                (
+                    update_entity_spawn_progress,
+                    observe_entity_creation,
+                    check_loading_progress,
+                )
+                    .run_if(in_state(LevelStates::InitRoom)),
+            );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitRoom");
+}
+
+fn setup_room(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut loading_entities = LoadingEntities::default();
+    setup_room_interface(&mut commands, &asset_server, &mut loading_entities);
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+fn setup_room_interface(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            SpawnRequest,
+            ZIndex(3),
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(50.0),
+                        border: UiRect::all(Val::VMin(1.25)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Percent(30.0)),
+                    BorderColor::all(BORDER_GREEN_COLOR_0),
+                    BackgroundColor(BG_GREEN_COLOR_3),
+                    Visibility::Inherited,
+                    UiAnimationTarget,
+                    SpawnRequest,
+                ))
+                .with_children(|parent| {
+                    // --- Title ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(16.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Private Room"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("room".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
+
+                    // --- Input Field ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(80.0),
+                                height: Val::Percent(20.0),
+                                border: UiRect::all(Val::VMin(0.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            BorderRadius::all(Val::Percent(10.0)),
+                            BorderColor::all(Color::BLACK),
+                            BackgroundColor(Color::WHITE),
+                            Visibility::Inherited,
+                            RoomInputField,
+                            SpawnRequest,
+                            Button,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new(""),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    ResizableFont::vertical(1280.0, 40.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    RoomInputText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(3.0));
+
+                    // --- Hint ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(12.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Enter a code to join a room, or create a new one."),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("room_hint".into()),
+                                    ResizableFont::vertical(1280.0, 28.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    RoomHintText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        display: Display::None,
+                                        ..Default::default()
+                                    },
+                                    Text::new("Share the code and wait for your opponent."),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("room_waiting".into()),
+                                    ResizableFont::vertical(1280.0, 28.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    RoomWaitingText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(5.0));
+
+                    // --- Buttons ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(20.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            add_room_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                RoomButton::Create,
+                                "Create",
+                                "create_room",
+                            );
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(4.0));
+
+                            add_room_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                RoomButton::Join,
+                                "Join",
+                                "join_room",
+                            );
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(4.0));
+
+                            add_room_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                RoomButton::Close,
+                                "Cancel",
+                                "cancel",
+                            );
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+                })
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds a button of the room popup. Closing the popup is red, the others are yellow.
+fn add_room_button(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    button: RoomButton,
+    label: &str,
+    key: &str,
+) {
+    let (background_color, border_color, text_color) = match button {
+        RoomButton::Close => (BG_RED_COLOR_0, BORDER_RED_COLOR_0, Color::WHITE),
+        _ => (BG_YELLO_COLOR_0, BORDER_YELLO_COLOR_0, Color::BLACK),
+    };
+
+    let entity = parent
+        .spawn((
+            Node {
+                width: Val::Percent(30.0),
+                height: Val::Percent(100.0),
+                border: UiRect::all(Val::VMin(0.8)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(30.0)),
+            OriginColor::<BackgroundColor>::new(background_color),
+            BorderColor::all(border_color),
+            BackgroundColor(background_color),
+            Visibility::Inherited,
+            SpawnRequest,
+            button,
+            Button,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(label),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TranslatableText(key.into()),
+                    ResizableFont::vertical(1280.0, 36.0),
+                    OriginColor::<TextColor>::new(text_color),
+                    TextColor(text_color),
+                    Visibility::Inherited,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn observe_entity_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(RoomLevelEntity);
+        if child_of.is_none() {
+            commands.insert(TitleLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    loading_entities: Res<LoadingEntities>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if loading_entities.is_empty() {
+        next_state.set(LevelStates::InitWatch);
+    }
+}
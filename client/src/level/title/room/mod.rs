@@ -0,0 +1,320 @@
+mod init;
+mod switch;
+
+// Import necessary Bevy modules.
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use protocol::ROOM_CODE_LENGTH;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(switch::InnerPlugin)
+            .add_systems(OnEnter(LevelStates::Room), debug_label)
+            .add_systems(
+                OnExit(LevelStates::Room),
+                (cleanup_room_resources, hide_room_interfaces),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_keyboard_inputs,
+                    handle_text_inputs,
+                    handle_input_field_pressed,
+                    handle_room_button_pressed,
+                )
+                    .run_if(in_state(LevelStates::Room)),
+            )
+            .add_systems(
+                Update,
+                update_room_interfaces
+                    .run_if(
+                        resource_exists_and_changed::<RoomCodeDraft>
+                            .or(resource_exists_and_changed::<RoomStatus>),
+                    )
+                    .run_if(in_state(LevelStates::Room)),
+            );
+
+        app.add_systems(
+            Update,
+            handle_received_packets.run_if(in_state(LevelStates::Room)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: Room");
+}
+
+// --- CLEANUP SYSTEMS --
+
+fn cleanup_room_resources(mut commands: Commands) {
+    commands.remove_resource::<RoomCodeDraft>();
+    commands.remove_resource::<RoomStatus>();
+}
+
+fn hide_room_interfaces(
+    mut query: Query<&mut Visibility, (With<RoomLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_keyboard_inputs(
+    mut commands: Commands,
+    network: Res<Network>,
+    room_code_draft: Res<RoomCodeDraft>,
+    room_status: Option<Res<RoomStatus>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        close_room_popup(&network, room_status.as_deref(), &mut next_state);
+    } else if keyboard_input.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+        && room_status.is_none()
+    {
+        join_room(&mut commands, &network, &room_code_draft.0);
+    }
+}
+
+fn handle_text_inputs(
+    mut room_code_draft: ResMut<RoomCodeDraft>,
+    room_status: Option<Res<RoomStatus>>,
+    mut keyboard_inputs: MessageReader<KeyboardInput>,
+) {
+    for event in keyboard_inputs.read() {
+        if !event.state.is_pressed() || room_status.is_some() {
+            continue;
+        }
+
+        match &event.logical_key {
+            Key::Backspace => {
+                room_code_draft.0.pop();
+            }
+            Key::Character(text) => push_code(&mut room_code_draft.0, text.as_str()),
+            _ => { /* empty */ }
+        }
+    }
+}
+
+#[allow(unused_mut)]
+#[allow(unused_variables)]
+fn handle_input_field_pressed(
+    mut room_code_draft: ResMut<RoomCodeDraft>,
+    room_status: Option<Res<RoomStatus>>,
+    hint_query: Query<&Text, With<RoomHintText>>,
+    interaction_query: Query<&Interaction, (With<RoomInputField>, Changed<Interaction>)>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction != Interaction::Pressed || room_status.is_some() {
+            continue;
+        }
+
+        // Browsers do not send key events to the canvas on mobile, so the code is typed in a dialog.
+        #[cfg(target_arch = "wasm32")]
+        {
+            let message = hint_query
+                .single()
+                .map(|text| text.0.clone())
+                .unwrap_or_default();
+            if let Some(code) = prompt_text(&message, &room_code_draft.0) {
+                room_code_draft.0.clear();
+                push_code(&mut room_code_draft.0, &code);
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_room_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    room_code_draft: Res<RoomCodeDraft>,
+    room_status: Option<Res<RoomStatus>>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &RoomButton, &Interaction),
+        (With<RoomLevelEntity>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, &button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match (button, interaction) {
+            (RoomButton::Create, Interaction::Pressed) => {
+                if room_status.is_none() {
+                    send_create_room_message(&network);
+                    commands.insert_resource(RoomStatus::Hosting(None));
+                }
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (RoomButton::Join, Interaction::Pressed) => {
+                if room_status.is_none() {
+                    join_room(&mut commands, &network, &room_code_draft.0);
+                }
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (RoomButton::Close, Interaction::Pressed) => {
+                close_room_popup(&network, room_status.as_deref(), &mut next_state);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (_, Interaction::Hovered) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn update_room_interfaces(
+    room_code_draft: Res<RoomCodeDraft>,
+    room_status: Option<Res<RoomStatus>>,
+    mut input_query: Query<&mut Text, With<RoomInputText>>,
+    mut hint_query: Query<&mut Node, (With<RoomHintText>, Without<RoomWaitingText>)>,
+    mut waiting_query: Query<&mut Node, (With<RoomWaitingText>, Without<RoomHintText>)>,
+) {
+    // The code of a hosted room replaces the draft, with a placeholder until the room is open.
+    let hosted_code = match room_status.as_deref() {
+        Some(RoomStatus::Hosting(code)) => Some(code.as_deref().unwrap_or("...")),
+        _ => None,
+    };
+
+    for mut text in input_query.iter_mut() {
+        *text = Text::new(hosted_code.unwrap_or(&room_code_draft.0));
+    }
+
+    for mut node in hint_query.iter_mut() {
+        node.display = match hosted_code {
+            Some(_) => Display::None,
+            None => Display::Flex,
+        };
+    }
+
+    for mut node in waiting_query.iter_mut() {
+        node.display = match hosted_code {
+            Some(_) => Display::Flex,
+            None => Display::None,
+        };
+    }
+}
+
+fn handle_received_packets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+    player_info: Res<PlayerInfo>,
+) {
+    for result in network.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::RoomCreated { code } => {
+                    commands.insert_resource(RoomStatus::Hosting(Some(code)));
+                }
+                Packet::RoomNotFound => {
+                    commands.insert_resource(ErrorMessage::new(
+                        "room_not_found",
+                        "The room could not be found.",
+                    ));
+                    next_state.set(LevelStates::SwitchToTitleMessage);
+                }
+                Packet::CancelSuccess => {
+                    let source = asset_server.load(SFX_PATH_COMMON_POPUP_CLOSE);
+                    play_effect_sound(&mut commands, &system_volume, source);
+                    next_state.set(LevelStates::InTitle);
+                }
+                Packet::MatchingSuccess {
+                    left,
+                    right,
+                    ruleset,
+                }
+                | Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(OtherInfo::new(player_info.uuid, left, right));
+                    commands.insert_resource(MatchRuleset(*ruleset));
+                    next_state.set(LevelStates::SwitchToLoadGame);
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Appends the typed letters and digits to the code, up to the code length.
+fn push_code(code: &mut String, text: &str) {
+    let length = code.chars().count();
+    let chars = text
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_uppercase());
+    code.extend(chars.take(ROOM_CODE_LENGTH.saturating_sub(length)));
+}
+
+/// Asks to join the room once the whole code is typed.
+fn join_room(commands: &mut Commands, network: &Network, code: &str) {
+    if code.chars().count() == ROOM_CODE_LENGTH {
+        send_join_room_message(network, code);
+        commands.insert_resource(RoomStatus::Joining);
+    }
+}
+
+/// Leaves the popup, closing the room first if the player is hosting one.
+/// The popup stays open while a join request is waiting for the server.
+fn close_room_popup(
+    network: &Network,
+    room_status: Option<&RoomStatus>,
+    next_state: &mut NextState<LevelStates>,
+) {
+    match room_status {
+        Some(RoomStatus::Hosting(_)) => send_close_room_message(network),
+        Some(RoomStatus::Joining) => { /* empty */ }
+        None => next_state.set(LevelStates::InTitle),
+    }
+}
+
+fn send_close_room_message(network: &Network) {
+    let packet = Packet::TryCancelGame;
+    network.send(&packet).unwrap();
+}
@@ -0,0 +1,332 @@
+// Import necessary Bevy modules.
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
+use protocol::LIVE_MATCH_LIST_SIZE;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(LevelStates::InitWatch), (debug_label, setup_watch))
+            .add_systems(OnExit(LevelStates::InitWatch), cleanup_loading_resource)
+            .add_systems(
+                Update,
+                (
+                    update_entity_spawn_progress,
+                    observe_entity_creation,
+                    check_loading_progress,
+                )
+                    .run_if(in_state(LevelStates::InitWatch)),
+            );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: InitWatch");
+}
+
+fn setup_watch(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let mut loading_entities = LoadingEntities::default();
+    setup_watch_interface(&mut commands, &asset_server, &mut loading_entities);
+
+    // --- Resource Insertion ---
+    commands.insert_resource(loading_entities);
+}
+
+fn setup_watch_interface(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    loading_entities: &mut LoadingEntities,
+) {
+    let entity = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            Visibility::Hidden,
+            SpawnRequest,
+            ZIndex(3),
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(60.0),
+                        height: Val::Percent(80.0),
+                        border: UiRect::all(Val::VMin(1.25)),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..Default::default()
+                    },
+                    BorderRadius::all(Val::Percent(10.0)),
+                    BorderColor::all(BORDER_GREEN_COLOR_0),
+                    BackgroundColor(BG_GREEN_COLOR_3),
+                    Visibility::Inherited,
+                    UiAnimationTarget,
+                    SpawnRequest,
+                ))
+                .with_children(|parent| {
+                    // --- Title ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(10.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            let entity = parent
+                                .spawn((
+                                    Node::default(),
+                                    Text::new("Live Matches"),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("live_matches".into()),
+                                    ResizableFont::vertical(1280.0, 42.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(2.0));
+
+                    // --- Match List ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(66.0),
+                                flex_direction: FlexDirection::Column,
+                                justify_content: JustifyContent::FlexStart,
+                                align_items: AlignItems::Center,
+                                row_gap: Val::Percent(1.0),
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            for index in 0..LIVE_MATCH_LIST_SIZE {
+                                add_match_entry(loading_entities, parent, asset_server, index);
+                            }
+
+                            let entity = parent
+                                .spawn((
+                                    Node {
+                                        display: Display::None,
+                                        margin: UiRect::top(Val::Percent(20.0)),
+                                        ..Default::default()
+                                    },
+                                    Text::new("There are no matches in progress."),
+                                    TextFont::from(asset_server.load(FONT_PATH)),
+                                    TextLayout::new_with_justify(Justify::Center),
+                                    TranslatableText("no_live_matches".into()),
+                                    ResizableFont::vertical(1280.0, 28.0),
+                                    TextColor::BLACK,
+                                    Visibility::Inherited,
+                                    WatchEmptyText,
+                                    SpawnRequest,
+                                ))
+                                .id();
+                            loading_entities.insert(entity);
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+
+                    add_vertical_space(loading_entities, parent, Val::Percent(4.0));
+
+                    // --- Buttons ---
+                    let entity = parent
+                        .spawn((
+                            Node {
+                                width: Val::Percent(90.0),
+                                height: Val::Percent(10.0),
+                                flex_direction: FlexDirection::Row,
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                            Visibility::Inherited,
+                            SpawnRequest,
+                        ))
+                        .with_children(|parent| {
+                            add_watch_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                WatchButton::Refresh,
+                                "Refresh",
+                                "refresh",
+                            );
+
+                            add_horizontal_space(loading_entities, parent, Val::Percent(4.0));
+
+                            add_watch_button(
+                                loading_entities,
+                                parent,
+                                asset_server,
+                                WatchButton::Close,
+                                "Cancel",
+                                "cancel",
+                            );
+                        })
+                        .id();
+                    loading_entities.insert(entity);
+                })
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds a row of the live match list. Rows are hidden until a match is listed in them.
+fn add_match_entry(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    index: usize,
+) {
+    let entity = parent
+        .spawn((
+            Node {
+                display: Display::None,
+                width: Val::Percent(100.0),
+                height: Val::Percent(9.0),
+                border: UiRect::all(Val::VMin(0.4)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(20.0)),
+            OriginColor::<BackgroundColor>::new(Color::WHITE),
+            BorderColor::all(Color::BLACK),
+            BackgroundColor(Color::WHITE),
+            Visibility::Inherited,
+            WatchButton::Entry(index),
+            SpawnRequest,
+            Button,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(""),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    ResizableFont::vertical(1280.0, 26.0),
+                    OriginColor::<TextColor>::new(Color::BLACK),
+                    TextColor::BLACK,
+                    Visibility::Inherited,
+                    WatchEntryText(index),
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+/// Adds a button of the watch popup. Closing the popup is red, refreshing is yellow.
+fn add_watch_button(
+    loading_entities: &mut LoadingEntities,
+    parent: &mut RelatedSpawnerCommands<'_, ChildOf>,
+    asset_server: &AssetServer,
+    button: WatchButton,
+    label: &str,
+    key: &str,
+) {
+    let (background_color, border_color, text_color) = match button {
+        WatchButton::Close => (BG_RED_COLOR_0, BORDER_RED_COLOR_0, Color::WHITE),
+        _ => (BG_YELLO_COLOR_0, BORDER_YELLO_COLOR_0, Color::BLACK),
+    };
+
+    let entity = parent
+        .spawn((
+            Node {
+                width: Val::Percent(30.0),
+                height: Val::Percent(100.0),
+                border: UiRect::all(Val::VMin(0.8)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderRadius::all(Val::Percent(30.0)),
+            OriginColor::<BackgroundColor>::new(background_color),
+            BorderColor::all(border_color),
+            BackgroundColor(background_color),
+            Visibility::Inherited,
+            SpawnRequest,
+            button,
+            Button,
+        ))
+        .with_children(|parent| {
+            let entity = parent
+                .spawn((
+                    Node::default(),
+                    Text::new(label),
+                    TextFont::from(asset_server.load(FONT_PATH)),
+                    TextLayout::new_with_justify(Justify::Center),
+                    TranslatableText(key.into()),
+                    ResizableFont::vertical(1280.0, 36.0),
+                    OriginColor::<TextColor>::new(text_color),
+                    TextColor(text_color),
+                    Visibility::Inherited,
+                    SpawnRequest,
+                ))
+                .id();
+            loading_entities.insert(entity);
+        })
+        .id();
+    loading_entities.insert(entity);
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn observe_entity_creation(
+    mut commands: Commands,
+    mut loading_entities: ResMut<LoadingEntities>,
+    query: Query<(Entity, Option<&ChildOf>), Added<SpawnRequest>>,
+) {
+    for (entity, child_of) in query.iter() {
+        loading_entities.remove(entity);
+
+        let mut commands = commands.entity(entity);
+        commands.remove::<SpawnRequest>();
+
+        commands.insert(WatchLevelEntity);
+        if child_of.is_none() {
+            commands.insert(TitleLevelRoot);
+        }
+    }
+}
+
+fn check_loading_progress(
+    loading_entities: Res<LoadingEntities>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if loading_entities.is_empty() {
+        next_state.set(LevelStates::InitEnterGame);
+    }
+}
@@ -0,0 +1,215 @@
+mod init;
+mod switch;
+
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+use protocol::LiveMatch;
+
+use super::*;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(init::InnerPlugin)
+            .add_plugins(switch::InnerPlugin)
+            .add_systems(OnEnter(LevelStates::Watch), debug_label)
+            .add_systems(
+                OnExit(LevelStates::Watch),
+                (cleanup_live_matches, hide_watch_interfaces),
+            )
+            .add_systems(
+                PreUpdate,
+                (handle_keyboard_inputs, handle_watch_button_pressed)
+                    .run_if(in_state(LevelStates::Watch)),
+            )
+            .add_systems(
+                Update,
+                update_match_list
+                    .run_if(resource_exists_and_changed::<LiveMatches>)
+                    .run_if(in_state(LevelStates::Watch)),
+            );
+
+        app.add_systems(
+            Update,
+            handle_received_packets.run_if(in_state(LevelStates::Watch)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: Watch");
+}
+
+// --- CLEANUP SYSTEMS --
+
+fn cleanup_live_matches(mut commands: Commands) {
+    commands.remove_resource::<LiveMatches>();
+}
+
+fn hide_watch_interfaces(
+    mut query: Query<&mut Visibility, (With<WatchLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+}
+
+// --- PREUPDATE SYSTEMS ---
+
+fn handle_keyboard_inputs(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_state.set(LevelStates::InTitle);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn handle_watch_button_pressed(
+    mut commands: Commands,
+    network: Res<Network>,
+    live_matches: Option<Res<LiveMatches>>,
+    asset_server: Res<AssetServer>,
+    system_volume: Res<SystemVolume>,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<(&mut TextColor, &OriginColor<TextColor>)>,
+    mut button_color_query: Query<(&mut BackgroundColor, &OriginColor<BackgroundColor>)>,
+    mut interaction_query: Query<
+        (Entity, &WatchButton, &Interaction),
+        (With<WatchLevelEntity>, Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (entity, &button, interaction) in interaction_query.iter_mut() {
+        update_button_visual(
+            entity,
+            interaction,
+            &children_query,
+            &mut text_color_query,
+            &mut button_color_query,
+        );
+
+        match (button, interaction) {
+            (WatchButton::Entry(index), Interaction::Pressed) => {
+                let live_match = live_matches.as_ref().and_then(|m| m.0.get(index));
+                if let Some(live_match) = live_match {
+                    send_watch_match_message(&network, live_match.id);
+                }
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (WatchButton::Refresh, Interaction::Pressed) => {
+                send_live_match_query(&network);
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            (WatchButton::Close, Interaction::Pressed) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_DOWN);
+                play_effect_sound(&mut commands, &system_volume, source);
+                next_state.set(LevelStates::InTitle);
+            }
+            (_, Interaction::Hovered) => {
+                let source = asset_server.load(SFX_PATH_COMMON_BUTTON_TOUCH);
+                play_effect_sound(&mut commands, &system_volume, source);
+            }
+            _ => { /* empty */ }
+        }
+    }
+}
+
+// --- UPDATE SYSTEMS ---
+
+#[allow(clippy::type_complexity)]
+fn update_match_list(
+    live_matches: Res<LiveMatches>,
+    mut entry_query: Query<(&mut Node, &WatchButton), Without<WatchEmptyText>>,
+    mut text_query: Query<(&mut Text, &WatchEntryText)>,
+    mut empty_query: Query<&mut Node, (With<WatchEmptyText>, Without<WatchButton>)>,
+) {
+    for (mut node, button) in entry_query.iter_mut() {
+        if let WatchButton::Entry(index) = button {
+            node.display = match live_matches.0.get(*index) {
+                Some(_) => Display::Flex,
+                None => Display::None,
+            };
+        }
+    }
+
+    for (mut text, entry) in text_query.iter_mut() {
+        if let Some(live_match) = live_matches.0.get(entry.0) {
+            *text = Text::new(match_label(live_match));
+        }
+    }
+
+    for mut node in empty_query.iter_mut() {
+        node.display = match live_matches.0.is_empty() {
+            true => Display::Flex,
+            false => Display::None,
+        };
+    }
+}
+
+fn handle_received_packets(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<LevelStates>>,
+    network: Res<Network>,
+) {
+    for result in network.try_iter() {
+        match result {
+            Ok(packet) => match packet {
+                Packet::LiveMatchList { matches } => {
+                    commands.insert_resource(LiveMatches(matches));
+                }
+                Packet::WatchStarted {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(SpectateInfo { left, right });
+                    commands.insert_resource(MatchRuleset(*ruleset));
+                    next_state.set(LevelStates::SwitchToLoadGame);
+                }
+                Packet::WatchNotFound => {
+                    commands.insert_resource(ErrorMessage::new(
+                        "watch_not_found",
+                        "The match is already over.",
+                    ));
+                    next_state.set(LevelStates::SwitchToTitleMessage);
+                }
+                Packet::RejoinGame {
+                    left,
+                    right,
+                    ruleset,
+                } => {
+                    commands.insert_resource(RejoinInfo {
+                        left,
+                        right,
+                        ruleset: *ruleset,
+                    });
+                }
+                _ => { /* empty */ }
+            },
+            Err(e) => {
+                commands.insert_resource(ErrorMessage::from(e));
+                next_state.set(LevelStates::Error);
+            }
+        }
+    }
+}
+
+// --- UTILITIES ---
+
+/// Formats a row of the live match list, the left player first.
+fn match_label(live_match: &LiveMatch) -> String {
+    format!(
+        "{} ({})  VS  {} ({})",
+        live_match.left.name, live_match.left.hero, live_match.right.name, live_match.right.hero
+    )
+}
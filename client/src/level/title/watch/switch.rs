@@ -0,0 +1,97 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+
+use super::*;
+
+// --- CONSTANTS ---
+const SCENE_DURATION: f32 = UI_POPUP_DURATION;
+
+// --- PLUGIN ---
+
+pub struct InnerPlugin;
+
+impl Plugin for InnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(LevelStates::SwitchToWatch),
+            (
+                debug_label,
+                setup_scene_timer,
+                show_watch_entities,
+                setup_watch_interfaces,
+                clear_match_list,
+                play_popup_sounds,
+            ),
+        )
+        .add_systems(OnExit(LevelStates::SwitchToWatch), cleanup_scene_timer)
+        .add_systems(
+            Update,
+            update_scene_timer.run_if(in_state(LevelStates::SwitchToWatch)),
+        );
+    }
+}
+
+// --- SETUP SYSTEMS ---
+
+fn debug_label() {
+    info!("Current Level: SwitchToWatch");
+}
+
+fn setup_scene_timer(mut commands: Commands) {
+    commands.insert_resource(SceneTimer::default());
+}
+
+fn show_watch_entities(
+    mut query: Query<&mut Visibility, (With<WatchLevelEntity>, With<TitleLevelRoot>)>,
+) {
+    for mut visibility in query.iter_mut() {
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn setup_watch_interfaces(
+    mut commands: Commands,
+    query: Query<Entity, (With<UiAnimationTarget>, With<WatchLevelEntity>)>,
+) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(UiBackOutScale::new(SCENE_DURATION, Vec2::ZERO, Vec2::ONE));
+    }
+}
+
+/// Hides the list of the last visit until the server sends the current one.
+#[allow(clippy::type_complexity)]
+fn clear_match_list(
+    mut entry_query: Query<(&mut Node, &WatchButton), Without<WatchEmptyText>>,
+    mut empty_query: Query<&mut Node, (With<WatchEmptyText>, Without<WatchButton>)>,
+) {
+    for (mut node, button) in entry_query.iter_mut() {
+        if let WatchButton::Entry(_) = button {
+            node.display = Display::None;
+        }
+    }
+
+    for mut node in empty_query.iter_mut() {
+        node.display = Display::None;
+    }
+}
+
+// --- CLEANUP SYSTEMS ---
+
+fn cleanup_scene_timer(mut commands: Commands) {
+    commands.remove_resource::<SceneTimer>();
+}
+
+// --- UPDATE SYSTEMS ---
+
+fn update_scene_timer(
+    mut next_state: ResMut<NextState<LevelStates>>,
+    mut scene_timer: ResMut<SceneTimer>,
+    time: Res<Time>,
+) {
+    scene_timer.tick(time.delta_secs());
+    if scene_timer.elapsed_sec() >= SCENE_DURATION {
+        next_state.set(LevelStates::Watch);
+    }
+}
@@ -4,6 +4,8 @@ use std::{marker::PhantomData, num::NonZeroUsize};
 use bevy::prelude::*;
 use protocol::Hero;
 
+use super::RematchStatus;
+
 #[derive(Component)]
 pub struct LoadingStateRoot;
 
@@ -37,6 +39,63 @@ pub struct TitleMessageLevelEntity;
 #[derive(Component)]
 pub struct LeaderBoardLevelEntity;
 
+#[derive(Component)]
+pub struct HeroSelectLevelEntity;
+
+#[derive(Component)]
+pub struct ProfileLevelEntity;
+
+#[derive(Component)]
+pub struct RenameLevelEntity;
+
+#[derive(Component)]
+pub struct RenameInputField;
+
+#[derive(Component)]
+pub struct RenameInputText;
+
+#[derive(Component)]
+pub struct RenameHintText;
+
+#[derive(Component)]
+pub struct RoomLevelEntity;
+
+#[derive(Component)]
+pub struct RoomInputField;
+
+#[derive(Component)]
+pub struct RoomInputText;
+
+#[derive(Component)]
+pub struct RoomHintText;
+
+#[derive(Component)]
+pub struct RoomWaitingText;
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum RoomButton {
+    Create,
+    Join,
+    Close,
+}
+
+#[derive(Component)]
+pub struct WatchLevelEntity;
+
+#[derive(Component)]
+pub struct WatchEntryText(pub usize);
+
+#[derive(Component)]
+pub struct WatchEmptyText;
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum WatchButton {
+    /// A row of the live match list.
+    Entry(usize),
+    Refresh,
+    Close,
+}
+
 #[derive(Component)]
 pub struct MatchingLevelEntity;
 
@@ -76,6 +135,33 @@ pub struct GameResultDefeat;
 #[derive(Component)]
 pub struct GameResultDraw;
 
+#[derive(Component)]
+pub struct GameResultRecordText;
+
+#[derive(Component)]
+pub struct RematchButton;
+
+/// A label of the rematch button, shown while the rematch is in the given status.
+#[derive(Component)]
+pub struct RematchText(pub RematchStatus);
+
+#[derive(Component)]
+pub struct StopWatchingButton;
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayButton {
+    Backward,
+    Pause,
+    Forward,
+}
+
+/// The label of the pause button, which reads "Play" while the replay is paused.
+#[derive(Component)]
+pub struct ReplayPauseText;
+
+#[derive(Component)]
+pub struct ReplayTimeText;
+
 #[derive(Component)]
 pub struct OriginColor<T> {
     pub none: Color,
@@ -143,12 +229,21 @@ pub enum VolumeLevelTextId {
 
 #[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
 pub enum TitleButton {
+    Rename,
     GameStart,
     Option,
+    HeroSelect,
     Ranking,
     HowToPlay,
+    Profile,
+    Room,
+    Watch,
+    Practice,
 }
 
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub struct HeroButton(pub Hero);
+
 #[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
 pub enum Character {
     Alice,
@@ -757,10 +852,8 @@ pub struct LeftPlayerHead(pub Entity);
 #[derive(Component)]
 pub struct RightPlayerHead(pub Entity);
 
-#[derive(Default, Component)]
-pub struct Projectile {
-    pub hit: bool,
-}
+#[derive(Component)]
+pub struct Projectile;
 
 #[derive(Component)]
 pub struct BackgroundSound;
@@ -780,9 +873,44 @@ pub enum VoiceChannel {
     Other,
 }
 
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderBoardButton {
+    PrevSeason,
+    NextSeason,
+    PrevHero,
+    NextHero,
+    PrevPage,
+    NextPage,
+    AroundMe,
+}
+
+#[derive(Component)]
+pub struct RankList;
+
+#[derive(Component)]
+pub struct RankRow;
+
+#[derive(Component)]
+pub struct RankPageText;
+
+#[derive(Component)]
+pub struct RankSeasonText;
+
+#[derive(Component)]
+pub struct RankAllHeroesText;
+
+#[derive(Component)]
+pub struct RankHeroText;
+
+#[derive(Component)]
+pub struct RankHeroStatsText;
+
 #[derive(Component)]
 pub struct RankEntry(pub usize);
 
+#[derive(Component)]
+pub struct RankItemRank;
+
 #[derive(Component)]
 pub struct MyRankEntry;
 
@@ -796,10 +924,41 @@ pub struct RankItemUuid;
 pub struct RankItemName;
 
 #[derive(Component)]
-pub struct RankItemWins;
+pub struct RankItemRating;
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum RankItemRecord {
+    Wins,
+    Losses,
+    Draws,
+}
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileStat {
+    Rating,
+    Wins,
+    Losses,
+    Draws,
+}
+
+#[derive(Component)]
+pub struct HistoryList;
 
 #[derive(Component)]
-pub struct RankItemLosses;
+pub struct HistoryRow;
+
+#[derive(Component)]
+pub struct HistoryEntry(pub usize);
+
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryColumn {
+    Result,
+    Hero,
+    Opponent,
+    OpponentHero,
+    Hits,
+    Duration,
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum GuideGestureStatus {
@@ -1,6 +1,7 @@
 mod assets;
 mod collider;
 mod level;
+mod network;
 mod resizable_font;
 mod translatable_text;
 mod web;
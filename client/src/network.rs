@@ -0,0 +1,252 @@
+// Import necessary Bevy modules.
+use bevy::prelude::*;
+pub use protocol::Packet;
+
+#[cfg(target_arch = "wasm32")]
+pub use self::web_socket::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use self::native_socket::*;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum NetError {
+    NotFound,
+    Closed(u16),
+    Error(String),
+}
+
+fn parse_packet(text: &str) -> Option<Packet> {
+    match serde_json::from_str::<Packet>(text) {
+        Ok(message) => {
+            info!("Received packet: {:?}", message);
+            Some(message)
+        }
+        Err(e) => {
+            error!("Failed to parse packet: {:?}, text: {}", e, text);
+            None
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web_socket {
+    use js_sys::{ArrayBuffer, Uint8Array};
+    use wasm_bindgen::prelude::*;
+    use web_sys::{BinaryType, CloseEvent, ErrorEvent, MessageEvent, WebSocket};
+
+    use super::*;
+
+    #[derive(Resource)]
+    pub struct Network {
+        pub socket: WebSocket,
+        pub receiver: flume::Receiver<Result<Packet, NetError>>,
+    }
+
+    impl Network {
+        pub fn new(url: &str) -> Result<Self, NetError> {
+            let socket = match WebSocket::new(url) {
+                Ok(socket) => socket,
+                Err(e) => {
+                    error!("Failed to connect to the game server: {:?}", e);
+                    return Err(NetError::NotFound);
+                }
+            };
+
+            let (sender, receiver) = flume::unbounded::<Result<Packet, NetError>>();
+            let sender_cloned = sender.clone();
+            let on_message_closure = Closure::<dyn FnMut(_)>::new(move |e: MessageEvent| {
+                let data = e.data();
+                let text = if let Ok(string) = data.clone().dyn_into::<js_sys::JsString>() {
+                    string.as_string()
+                } else if let Ok(buffer) = data.dyn_into::<ArrayBuffer>() {
+                    let array = Uint8Array::new(&buffer);
+                    String::from_utf8(array.to_vec()).ok()
+                } else {
+                    None
+                };
+
+                if let Some(message) = text.as_deref().and_then(parse_packet) {
+                    let _ = sender_cloned.send(Ok(message));
+                }
+            });
+
+            let sender_cloned = sender.clone();
+            let on_close_closure = Closure::<dyn FnMut(_)>::new(move |e: CloseEvent| {
+                info!("WebSocket closed: {}:{}", e.code(), e.reason());
+                let _ = sender_cloned.send(Err(NetError::Closed(e.code())));
+            });
+
+            let sender_cloned = sender;
+            let on_error_closure = Closure::<dyn FnMut(_)>::new(move |e: ErrorEvent| {
+                error!("WebSocket error: {}", e.message());
+                let _ = sender_cloned.send(Err(NetError::Error(e.message())));
+            });
+
+            socket.set_binary_type(BinaryType::Arraybuffer);
+            socket.set_onmessage(Some(on_message_closure.as_ref().unchecked_ref()));
+            socket.set_onclose(Some(on_close_closure.as_ref().unchecked_ref()));
+            socket.set_onerror(Some(on_error_closure.as_ref().unchecked_ref()));
+            on_message_closure.forget();
+            on_close_closure.forget();
+            on_error_closure.forget();
+
+            Ok(Self { socket, receiver })
+        }
+
+        pub fn send(&self, message: &Packet) -> Result<(), JsValue> {
+            let text = serde_json::to_string(message).unwrap();
+            self.socket.send_with_str(&text)
+        }
+
+        pub fn try_iter(&self) -> flume::TryIter<'_, Result<Packet, NetError>> {
+            self.receiver.try_iter()
+        }
+    }
+
+    impl Drop for Network {
+        fn drop(&mut self) {
+            let _ = self.socket.close();
+        }
+    }
+
+    unsafe impl Send for Network {}
+    unsafe impl Sync for Network {}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native_socket {
+    use std::{io::ErrorKind, net::TcpStream, thread, time::Duration};
+
+    use flume::{Receiver, Sender, TryRecvError};
+    use tungstenite::{
+        Message, WebSocket, error::Error as WsError, protocol::frame::coding::CloseCode,
+        stream::MaybeTlsStream,
+    };
+
+    use super::*;
+
+    /// How long a blocking read may wait before the thread checks for outgoing packets.
+    const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Dropping the network disconnects the outgoing channel, which tells the thread to close the socket.
+    /// The thread is detached rather than joined, since it can be blocked connecting to an
+    /// unreachable server, so dropping the network never waits for it.
+    #[derive(Resource)]
+    pub struct Network {
+        outgoing: Sender<Packet>,
+        pub receiver: Receiver<Result<Packet, NetError>>,
+    }
+
+    impl Network {
+        pub fn new(url: &str) -> Result<Self, NetError> {
+            let url = url.to_string();
+            let (sender, receiver) = flume::unbounded::<Result<Packet, NetError>>();
+            let (outgoing, outgoing_rx) = flume::unbounded::<Packet>();
+            let result = thread::Builder::new()
+                .name("network".into())
+                .spawn(move || run_socket(&url, sender, outgoing_rx));
+
+            match result {
+                Ok(_) => Ok(Self { outgoing, receiver }),
+                Err(e) => {
+                    error!("Failed to spawn the network thread: {}", e);
+                    Err(NetError::NotFound)
+                }
+            }
+        }
+
+        pub fn send(&self, message: &Packet) -> Result<(), flume::SendError<Packet>> {
+            self.outgoing.send(message.clone())
+        }
+
+        pub fn try_iter(&self) -> flume::TryIter<'_, Result<Packet, NetError>> {
+            self.receiver.try_iter()
+        }
+    }
+
+    fn run_socket(url: &str, sender: Sender<Result<Packet, NetError>>, outgoing: Receiver<Packet>) {
+        let mut socket = match tungstenite::connect(url) {
+            Ok((socket, _)) => socket,
+            Err(e) => {
+                error!("Failed to connect to the game server: {}", e);
+                let _ = sender.send(Err(NetError::NotFound));
+                return;
+            }
+        };
+
+        if let Err(e) = set_read_timeout(&socket, POLL_INTERVAL) {
+            error!("Failed to configure the socket: {}", e);
+            let _ = sender.send(Err(NetError::Error(e.to_string())));
+            return;
+        }
+
+        loop {
+            // 1. Flush every packet queued by the game systems.
+            loop {
+                match outgoing.try_recv() {
+                    Ok(packet) => {
+                        let text = serde_json::to_string(&packet).unwrap();
+                        if let Err(e) = socket.send(Message::text(text)) {
+                            error!("WebSocket error: {}", e);
+                            let _ = sender.send(Err(NetError::Error(e.to_string())));
+                            return;
+                        }
+                    }
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        let _ = socket.close(None);
+                        let _ = socket.flush();
+                        return;
+                    }
+                }
+            }
+
+            // 2. Wait a little for the next message from the server.
+            match socket.read() {
+                Ok(Message::Text(text)) => {
+                    if let Some(message) = parse_packet(&text) {
+                        let _ = sender.send(Ok(message));
+                    }
+                }
+                Ok(Message::Binary(bytes)) => {
+                    if let Some(message) = std::str::from_utf8(&bytes).ok().and_then(parse_packet) {
+                        let _ = sender.send(Ok(message));
+                    }
+                }
+                Ok(Message::Close(frame)) => {
+                    let code = frame.map(|f| f.code).unwrap_or(CloseCode::Status);
+                    info!("WebSocket closed: {}", code);
+                    let _ = sender.send(Err(NetError::Closed(code.into())));
+                    return;
+                }
+                Ok(_) => { /* empty */ }
+                Err(WsError::Io(e))
+                    if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+                {
+                    // No message arrived within the poll interval.
+                }
+                Err(WsError::ConnectionClosed) | Err(WsError::AlreadyClosed) => {
+                    info!("WebSocket closed.");
+                    let _ = sender.send(Err(NetError::Closed(CloseCode::Abnormal.into())));
+                    return;
+                }
+                Err(e) => {
+                    error!("WebSocket error: {}", e);
+                    let _ = sender.send(Err(NetError::Error(e.to_string())));
+                    return;
+                }
+            }
+        }
+    }
+
+    fn set_read_timeout(
+        socket: &WebSocket<MaybeTlsStream<TcpStream>>,
+        duration: Duration,
+    ) -> std::io::Result<()> {
+        match socket.get_ref() {
+            MaybeTlsStream::Plain(stream) => stream.set_read_timeout(Some(duration)),
+            MaybeTlsStream::Rustls(stream) => stream.get_ref().set_read_timeout(Some(duration)),
+            _ => Ok(()),
+        }
+    }
+}
@@ -5,109 +5,14 @@ pub use bevy::{
     prelude::*,
 };
 use flume::{Receiver, Sender};
-use js_sys::{ArrayBuffer, Uint8Array};
-pub use protocol::Packet;
+use js_sys::ArrayBuffer;
 pub use wasm_bindgen::prelude::*;
-pub use web_sys::{
-    AudioBuffer, AudioBufferSourceNode, AudioContext, BinaryType, CloseEvent, ErrorEvent, GainNode,
-    MessageEvent, Storage, WebSocket, window,
-};
+pub use web_sys::{AudioBuffer, AudioBufferSourceNode, AudioContext, GainNode, Storage, window};
 
 pub fn get_local_storage() -> Option<Storage> {
     window()?.local_storage().ok()?
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum NetError {
-    NotFound,
-    Closed(u16),
-    Error(String),
-}
-
-#[derive(Resource)]
-pub struct Network {
-    pub socket: WebSocket,
-    pub receiver: flume::Receiver<Result<Packet, NetError>>,
-}
-
-impl Network {
-    pub fn new(url: &str) -> Result<Self, NetError> {
-        let socket = match WebSocket::new(url) {
-            Ok(socket) => socket,
-            Err(e) => {
-                error!("Failed to connect to the game server: {:?}", e);
-                return Err(NetError::NotFound);
-            }
-        };
-
-        let (sender, receiver) = flume::unbounded::<Result<Packet, NetError>>();
-        let sender_cloned = sender.clone();
-        let on_message_closure = Closure::<dyn FnMut(_)>::new(move |e: MessageEvent| {
-            let data = e.data();
-            let text = if let Ok(string) = data.clone().dyn_into::<js_sys::JsString>() {
-                string.as_string()
-            } else if let Ok(buffer) = data.dyn_into::<ArrayBuffer>() {
-                let array = Uint8Array::new(&buffer);
-                String::from_utf8(array.to_vec()).ok()
-            } else {
-                None
-            };
-
-            if let Some(text) = text {
-                match serde_json::from_str::<Packet>(&text) {
-                    Ok(message) => {
-                        info!("Received packet: {:?}", message);
-                        let _ = sender_cloned.send(Ok(message));
-                    }
-                    Err(e) => {
-                        error!("Failed to parse packet: {:?}, text: {}", e, text);
-                    }
-                }
-            }
-        });
-
-        let sender_cloned = sender.clone();
-        let on_close_closure = Closure::<dyn FnMut(_)>::new(move |e: CloseEvent| {
-            info!("WebSocket closed: {}:{}", e.code(), e.reason());
-            let _ = sender_cloned.send(Err(NetError::Closed(e.code())));
-        });
-
-        let sender_cloned = sender;
-        let on_error_closure = Closure::<dyn FnMut(_)>::new(move |e: ErrorEvent| {
-            error!("WebSocket error: {}", e.message());
-            let _ = sender_cloned.send(Err(NetError::Error(e.message())));
-        });
-
-        socket.set_binary_type(BinaryType::Arraybuffer);
-        socket.set_onmessage(Some(on_message_closure.as_ref().unchecked_ref()));
-        socket.set_onclose(Some(on_close_closure.as_ref().unchecked_ref()));
-        socket.set_onerror(Some(on_error_closure.as_ref().unchecked_ref()));
-        on_message_closure.forget();
-        on_close_closure.forget();
-        on_error_closure.forget();
-
-        Ok(Self { socket, receiver })
-    }
-
-    pub fn send(&self, message: &Packet) -> Result<(), JsValue> {
-        let text = serde_json::to_string(message).unwrap();
-        self.socket.send_with_str(&text)
-    }
-
-    pub fn try_iter(&self) -> flume::TryIter<'_, Result<Packet, NetError>> {
-        self.receiver.try_iter()
-    }
-}
-
-impl Drop for Network {
-    fn drop(&mut self) {
-        let _ = self.socket.close();
-    }
-}
-
-unsafe impl Send for Network {}
-unsafe impl Sync for Network {}
-
 // --- PLUGIN ---
 
 pub struct WebAudioPlugin;
@@ -473,3 +378,11 @@ extern "C" {
 pub fn start_game_tutorial(lang: &str) {
     unsafe { start_tutorial(lang) };
 }
+
+/// Asks the user to enter a text with the browser dialog.
+/// Returns `None` if the dialog is canceled.
+pub fn prompt_text(message: &str, default: &str) -> Option<String> {
+    window()?
+        .prompt_with_message_and_default(message, default)
+        .ok()?
+}
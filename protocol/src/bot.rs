@@ -0,0 +1,125 @@
+use std::{f32::consts::PI, ops::RangeInclusive};
+
+use glam::Vec2;
+
+use super::*;
+
+/// How close the aim of a bot must get to its target before it throws.
+const BIAS: f32 = 50.0;
+const BOT_LERP_RANGE: RangeInclusive<f32> = 0.5..=0.9;
+const BOT_LERP_OFFSET: RangeInclusive<f32> = -0.05..=0.05;
+const BOT_TIME_RANGE: RangeInclusive<f32> = 1.5..=3.0;
+
+/// Skill of a bot. Lower levels miss their aim by more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl BotLevel {
+    fn power_offset<R: Rng + ?Sized>(&self, throw_power: f32, rng: &mut R) -> f32 {
+        let scale = match self {
+            BotLevel::Low => 0.1,
+            BotLevel::Medium => 0.07,
+            BotLevel::High => 0.03,
+        };
+        rng.random_range(-throw_power * scale..=throw_power * scale)
+    }
+
+    fn angle_offset<R: Rng + ?Sized>(&self, rng: &mut R) -> f32 {
+        let range = match self {
+            BotLevel::Low => -PI / 20.0..=PI / 20.0,
+            BotLevel::Medium => -PI / 25.0..=PI / 25.0,
+            BotLevel::High => -PI / 30.0..=PI / 30.0,
+        };
+        rng.random_range(range)
+    }
+}
+
+impl Distribution<BotLevel> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BotLevel {
+        match rng.random_range(0..3) {
+            0 => BotLevel::Low,
+            1 => BotLevel::Medium,
+            _ => BotLevel::High,
+        }
+    }
+}
+
+/// Aim of a bot during one of its turns.
+/// The bot drags its aim from the lowest angle toward a throw that hits the opponent,
+/// and throws once it is close enough.
+#[derive(Debug, Clone, Copy)]
+pub struct BotAim {
+    side: Side,
+    lerp_p: f32,
+    src_vel: Vec2,
+    dst_vel: Vec2,
+    throw_power: f32,
+}
+
+impl BotAim {
+    /// Starts the aim of the bot on `side` at the collider of the opponent.
+    pub fn new<R: Rng + ?Sized>(
+        side: Side,
+        level: BotLevel,
+        target: &Circle,
+        wind_vel: Vec2,
+        ruleset: &Ruleset,
+        rng: &mut R,
+    ) -> Self {
+        let (start, _) = side.angle_range();
+        let target_pos = side.opponent().collider_pos(target);
+        let start_pos = side.throw_pos();
+        Self {
+            side,
+            lerp_p: rng.random_range(BOT_LERP_RANGE),
+            src_vel: Vec2::new(start.cos(), start.sin()) * f32::EPSILON,
+            dst_vel: aim_velocity(side, start_pos, target_pos, wind_vel, ruleset, level, rng),
+            throw_power: ruleset.throw_power,
+        }
+    }
+
+    /// Moves the aim for `delta_time` seconds and returns the angle and power of the throw.
+    pub fn update<R: Rng + ?Sized>(&mut self, delta_time: f32, rng: &mut R) -> (u8, u8) {
+        let s = self.lerp_p + rng.random_range(BOT_LERP_OFFSET);
+        self.src_vel = self.src_vel.lerp(self.dst_vel, s * delta_time);
+        throw_control(self.side, self.src_vel, self.throw_power)
+    }
+
+    /// Returns `true` once the bot is ready to throw.
+    pub fn is_ready(&self) -> bool {
+        self.src_vel.abs_diff_eq(self.dst_vel, BIAS)
+    }
+}
+
+/// Returns the velocity that takes a projectile from `start_pos` to `target_pos` against the wind,
+/// off by an error depending on the level of the bot.
+fn aim_velocity<R: Rng + ?Sized>(
+    side: Side,
+    start_pos: Vec2,
+    target_pos: Vec2,
+    wind_vel: Vec2,
+    ruleset: &Ruleset,
+    lv: BotLevel,
+    rng: &mut R,
+) -> Vec2 {
+    let time_to_hit = rng.random_range(BOT_TIME_RANGE);
+    let dx = target_pos.x - start_pos.x;
+    let dy = target_pos.y - start_pos.y;
+
+    let vx = (dx / time_to_hit) - wind_vel.x;
+    let vy = (dy - (0.5 * ruleset.gravity * time_to_hit * time_to_hit)) / time_to_hit - wind_vel.y;
+    let vel = Vec2::new(vx, vy);
+
+    let length = vel.length();
+    let dir = vel / length; // Maybe safety...
+
+    let (start, end) = side.angle_range();
+    let power = (length + lv.power_offset(ruleset.throw_power, rng)).min(ruleset.throw_power);
+    let angle = (dir.to_angle() + lv.angle_offset(rng)).clamp(start, end);
+
+    Vec2::new(angle.cos(), angle.sin()) * power
+}
@@ -1,10 +1,19 @@
+mod bot;
+mod physics;
+mod replay;
+
 use std::{collections::HashMap, fmt};
 
 use lazy_static::lazy_static;
+pub use bot::*;
+pub use glam;
+pub use physics::*;
 pub use rand;
+pub use replay::*;
 use rand::{
-    Rng,
+    Rng, SeedableRng,
     distr::{Distribution, StandardUniform},
+    rngs::StdRng,
 };
 pub use serde;
 pub use serde_json;
@@ -120,6 +129,10 @@ impl Hero {
             _ => None,
         }
     }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
 }
 
 impl fmt::Display for Hero {
@@ -189,16 +202,51 @@ pub const MAX_PLAY_TIME: i32 = 150_000; // 150 seconds
 pub const MAX_CTRL_TIME: u16 = 10_000; // 10 seconds
 pub const MAX_HEALTH_COUNT: usize = 5;
 
+/// Length limits of a name chosen by a player (characters).
+pub const MIN_NAME_LENGTH: usize = 2;
+pub const MAX_NAME_LENGTH: usize = 12;
+
+/// Query parameter of the server URL used to resume a previous session.
+pub const SESSION_TOKEN_PARAM: &str = "token";
+
+/// Number of leaderboard entries sent in one page.
+pub const RANKING_PAGE_SIZE: u32 = 20;
+
+/// Number of recent games kept in the match history of a player.
+pub const MATCH_HISTORY_SIZE: usize = 20;
+
+/// Number of characters in the invite code of a private room.
+pub const ROOM_CODE_LENGTH: usize = 6;
+
+/// Number of live matches sent in one list.
+pub const LIVE_MATCH_LIST_SIZE: usize = 10;
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum Packet {
     // Server -> Client
     Connection(PlayData),
+    // Server -> Client
+    SessionToken {
+        token: String,
+    },
     // Client -> Server
     EnterGame,
     // Client -> Server
     TryCancelGame,
     // Server -> Client
     CancelSuccess,
+    // Client -> Server
+    CreateRoom,
+    // Server -> Client
+    RoomCreated {
+        code: String,
+    },
+    // Client -> Server
+    JoinRoom {
+        code: String,
+    },
+    // Server -> Client
+    RoomNotFound,
     // Server -> Client
     MatchingStatus {
         millis: u16,
@@ -207,6 +255,13 @@ pub enum Packet {
     MatchingSuccess {
         left: PlayData,
         right: PlayData,
+        ruleset: Box<Ruleset>,
+    },
+    // Server -> Client
+    RejoinGame {
+        left: PlayData,
+        right: PlayData,
+        ruleset: Box<Ruleset>,
     },
     // Client -> Server
     GameLoadSuccess,
@@ -243,6 +298,16 @@ pub enum Packet {
         wind_power: u8,
     },
     // Server -> Client
+    InGameResync {
+        turn: TurnState,
+        total_remaining_millis: i32,
+        remaining_millis: u16,
+        left_health_cnt: u8,
+        right_health_cnt: u8,
+        wind_angle: u8,
+        wind_power: u8,
+    },
+    // Server -> Client
     InGameProjectileThrown {
         total_remaining_millis: i32,
         remaining_millis: u16,
@@ -252,20 +317,149 @@ pub enum Packet {
         projectile_vel: (f32, f32),
     },
     // Server -> Client
+    InGameHit {
+        target: Side,
+        pos: (f32, f32),
+    },
+    // Server -> Client
     GameResult {
         win: u16,
         lose: u16,
+        draw: u16,
         victory: bool,
     },
     // Server -> Client
-    GameResultDraw,
+    GameResultDraw {
+        win: u16,
+        lose: u16,
+        draw: u16,
+    },
     // Client -> Server
-    RankingQuery,
+    RematchOffer,
+    // Client -> Server
+    RematchDecline,
+    // Server -> Client
+    RematchOffered,
+    // Server -> Client
+    RematchDeclined,
+    // Client -> Server
+    RankingQuery {
+        view: RankingView,
+    },
+    // Client -> Server
+    SeasonRankingQuery {
+        season: u32,
+        view: RankingView,
+    },
     // Server -> Client
     RankingResult {
+        season: u32,
+        current_season: u32,
         my_rank: Option<u32>,
-        top_list: Vec<RankItem>,
-    }
+        my_rating: Option<i32>,
+        total: u32,
+        items: Vec<RankItem>,
+    },
+    // Client -> Server
+    HeroStatsQuery,
+    // Server -> Client
+    HeroStatsResult {
+        mine: Vec<HeroStats>,
+        overall: Vec<HeroStats>,
+    },
+    // Client -> Server
+    HeroRankingQuery {
+        hero: Hero,
+        view: RankingView,
+    },
+    // Server -> Client
+    HeroRankingResult {
+        hero: Hero,
+        my_rank: Option<u32>,
+        total: u32,
+        items: Vec<RankItem>,
+    },
+    // Client -> Server
+    ProfileQuery,
+    // Server -> Client
+    ProfileResult {
+        rating: i32,
+        wins: u16,
+        losses: u16,
+        draws: u16,
+        history: Vec<MatchRecord>,
+    },
+    // Client -> Server
+    ChangeHero {
+        hero: Hero,
+    },
+    // Client -> Server
+    ChangeName {
+        name: String,
+    },
+    // Server -> Client
+    NameChanged {
+        name: String,
+    },
+    // Server -> Client
+    NameRejected {
+        reason: NameError,
+    },
+    // Client -> Server
+    LiveMatchQuery,
+    // Server -> Client
+    LiveMatchList {
+        matches: Vec<LiveMatch>,
+    },
+    // Client -> Server
+    WatchMatch {
+        id: u64,
+    },
+    // Server -> Client
+    WatchStarted {
+        left: PlayData,
+        right: PlayData,
+        ruleset: Box<Ruleset>,
+    },
+    // Server -> Client
+    WatchNotFound,
+    // Client -> Server
+    StopWatching,
+    // Server -> Client
+    WatchEnded,
+    // Client -> Server
+    ReplayQuery {
+        id: Uuid,
+    },
+    // Server -> Client
+    ReplayResult {
+        /// `None` if the replay has expired.
+        replay: Option<Box<Replay>>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TurnState {
+    LeftTurn,
+    RightTurn,
+    LeftProjectileThrown,
+    RightProjectileThrown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RankingView {
+    /// Entries of the given page, starting from zero.
+    Page(u32),
+    /// Entries around the rank of the player. The first page if the player has no rank.
+    AroundMe,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum NameError {
+    InvalidLength,
+    InvalidCharacter,
+    Denied,
+    Duplicated,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -275,6 +469,134 @@ pub struct PlayData {
     pub hero: Hero,
     pub win: u16,
     pub lose: u16,
+    pub draw: u16,
+}
+
+/// Rules of a match that the server can change without a new client.
+/// The server sends them with the start of every match, and every field left out keeps its default.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Ruleset {
+    /// Health each player starts with, at most [`MAX_HEALTH_COUNT`].
+    pub health_count: u8,
+    /// Length of a match (milliseconds).
+    pub max_play_time: i32,
+    /// Time a player has to throw in a turn (milliseconds).
+    pub max_turn_time: u16,
+    /// Speed of a throw at full power.
+    pub throw_power: f32,
+    /// Vertical acceleration of a thrown projectile.
+    pub gravity: f32,
+    /// A projectile that goes past these x coordinates is out of the world.
+    pub world_min_x: f32,
+    pub world_max_x: f32,
+    pub wind: WindRule,
+}
+
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self {
+            health_count: MAX_HEALTH_COUNT as u8,
+            max_play_time: MAX_PLAY_TIME,
+            max_turn_time: MAX_CTRL_TIME,
+            throw_power: THROW_POWER,
+            gravity: GRAVITY,
+            world_min_x: WORLD_MIN_X,
+            world_max_x: WORLD_MAX_X,
+            wind: WindRule::default(),
+        }
+    }
+}
+
+impl Ruleset {
+    /// Checks that a match can be played with the ruleset, and returns what is wrong otherwise.
+    pub fn validate(&self) -> Result<(), RulesetError> {
+        if !(1..=MAX_HEALTH_COUNT).contains(&(self.health_count as usize)) {
+            return Err(RulesetError::HealthCount);
+        }
+        if self.max_play_time <= 0 {
+            return Err(RulesetError::PlayTime);
+        }
+        if self.max_turn_time == 0 {
+            return Err(RulesetError::TurnTime);
+        }
+        if !self.throw_power.is_finite() || self.throw_power <= 0.0 {
+            return Err(RulesetError::ThrowPower);
+        }
+        if !self.gravity.is_finite() || self.gravity >= 0.0 {
+            return Err(RulesetError::Gravity);
+        }
+        if !self.world_min_x.is_finite()
+            || !self.world_max_x.is_finite()
+            || self.world_min_x >= self.world_max_x
+        {
+            return Err(RulesetError::WorldBounds);
+        }
+        if !self.wind.is_valid() {
+            return Err(RulesetError::Wind);
+        }
+        Ok(())
+    }
+}
+
+/// The rule of a [`Ruleset`] a match cannot be played with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesetError {
+    HealthCount,
+    PlayTime,
+    TurnTime,
+    ThrowPower,
+    Gravity,
+    WorldBounds,
+    Wind,
+}
+
+impl fmt::Display for RulesetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesetError::HealthCount => {
+                write!(f, "health_count must be between 1 and {MAX_HEALTH_COUNT}")
+            }
+            RulesetError::PlayTime => write!(f, "max_play_time must be positive"),
+            RulesetError::TurnTime => write!(f, "max_turn_time must be positive"),
+            RulesetError::ThrowPower => write!(f, "throw_power must be positive"),
+            RulesetError::Gravity => write!(f, "gravity must be negative"),
+            RulesetError::WorldBounds => write!(f, "world_min_x must be below world_max_x"),
+            RulesetError::Wind => write!(
+                f,
+                "wind must have its minimums below its maximums and a non-negative speed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RulesetError {}
+
+/// Random numbers of a match, all drawn from the seed of the match.
+/// The wind and the bots draw from separate streams,
+/// so the wind of a match does not depend on how the bots played.
+#[derive(Debug, Clone)]
+pub struct MatchRng {
+    pub wind: StdRng,
+    pub bot: StdRng,
+}
+
+impl MatchRng {
+    pub fn new(seed: u64) -> Self {
+        const BOT_STREAM: u64 = 0x9e37_79b9_7f4a_7c15;
+        Self {
+            wind: StdRng::seed_from_u64(seed),
+            bot: StdRng::seed_from_u64(seed ^ BOT_STREAM),
+        }
+    }
+}
+
+/// A match in progress that can be watched.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LiveMatch {
+    pub id: u64,
+    pub left: PlayData,
+    pub right: PlayData,
 }
 
 pub const WORLD_MIN_X: f32 = -1440.0;
@@ -387,6 +709,65 @@ pub struct RankItem {
     pub rank: u32,
     pub uuid: String,
     pub name: String,
+    pub rating: i32,
     pub wins: u32,
     pub losses: u32,
+    pub draws: u32,
+}
+
+/// Games played with a hero.
+/// `hits` counts the throws that hit the opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeroStats {
+    pub hero: Hero,
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub hits: u32,
+}
+
+impl HeroStats {
+    pub fn new(hero: Hero) -> Self {
+        Self {
+            hero,
+            games: 0,
+            wins: 0,
+            losses: 0,
+            hits: 0,
+        }
+    }
+
+    /// Returns the share of won games in percent, or `None` without games.
+    pub fn win_rate(&self) -> Option<f32> {
+        (self.games > 0).then(|| self.wins as f32 / self.games as f32 * 100.0)
+    }
+}
+
+/// Result of a game from the point of view of the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// A finished game in the match history of a player.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    /// Unix time when the game ended (seconds).
+    pub played_at: i64,
+    pub hero: Hero,
+    pub opponent_name: String,
+    pub opponent_hero: Hero,
+    /// `true` if the opponent was a bot at the end of the game.
+    pub opponent_is_bot: bool,
+    pub outcome: MatchOutcome,
+    pub duration_millis: u32,
+    pub hits_dealt: u32,
+    pub hits_taken: u32,
+    /// Replay of the game, kept for a while after it ended.
+    /// `None` if the replay could not be saved.
+    pub replay_id: Option<Uuid>,
+    /// Seed of the random numbers of the game.
+    pub seed: u64,
 }
@@ -0,0 +1,356 @@
+use std::f32::consts::TAU;
+
+use glam::{FloatExt, Vec2};
+
+use super::*;
+
+/// Length of one step of the projectile simulation (seconds).
+/// The simulation only moves in whole steps, so the path is the same at any update rate.
+pub const FIXED_STEP: f32 = 1.0 / 120.0;
+/// Share of its speed a projectile rolling on the ground loses in each step.
+pub const FRICTION: f32 = 0.03125;
+
+/// Side of the stage a player stands on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn opponent(self) -> Self {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    /// Position where the projectile leaves the hand of the player.
+    pub fn throw_pos(self) -> Vec2 {
+        match self {
+            Side::Left => Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y),
+            Side::Right => Vec2::new(RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y),
+        }
+    }
+
+    /// Lowest and highest throw angles (radians).
+    pub fn angle_range(self) -> (f32, f32) {
+        match self {
+            Side::Left => (LEFT_START_ANGLE, LEFT_END_ANGLE),
+            Side::Right => (RIGHT_START_ANGLE, RIGHT_END_ANGLE),
+        }
+    }
+
+    /// World position of the collider of a hero standing on this side.
+    /// Heroes on the right side are mirrored.
+    pub fn collider_pos(self, collider: &Circle) -> Vec2 {
+        match self {
+            Side::Left => {
+                Vec2::new(LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y) + Vec2::from(collider.center)
+            }
+            Side::Right => {
+                Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y)
+                    + Vec2::from(collider.center) * Vec2::new(-1.0, 1.0)
+            }
+        }
+    }
+
+    /// Returns `true` if a projectile at `pos` touches the collider of a hero standing on this side.
+    pub fn is_hit(self, collider: &Circle, pos: Vec2) -> bool {
+        let radius_sum = PROJECTILE_SIZE * 0.5 + collider.radius;
+        (pos - self.collider_pos(collider)).length_squared() <= radius_sum * radius_sum
+    }
+}
+
+/// Returns the velocity of a throw from the angle and power chosen by the player.
+/// A player who never aimed throws nothing.
+pub fn throw_velocity(side: Side, control: Option<(u8, u8)>, throw_power: f32) -> Vec2 {
+    let (start, end) = side.angle_range();
+    control
+        .map(|(angle, power)| {
+            let delta = angle as f32 / 255.0;
+            let radian = start + (end - start) * delta;
+            let direction = Vec2::new(radian.cos(), radian.sin());
+            let power = (power as f32 / 255.0) * throw_power;
+            direction * power
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the angle and power of a throw with the velocity, the inverse of [`throw_velocity`].
+pub fn throw_control(side: Side, velocity: Vec2, throw_power: f32) -> (u8, u8) {
+    let (start, end) = side.angle_range();
+    let length = velocity.length().min(throw_power);
+    let power = (length / throw_power * 255.0) as u8;
+
+    let radian = velocity.to_angle().clamp(start, end);
+    let angle = ((radian - start) / (end - start) * 255.0) as u8;
+
+    (angle, power)
+}
+
+/// How the wind of each turn is picked.
+/// Angles and powers are fractions of 255, so 255 is a full turn or `max_speed`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WindRule {
+    /// Lowest angle of the wind.
+    pub min_angle: u8,
+    /// Angle the wind stays below.
+    pub max_angle: u8,
+    /// Lowest power of the wind.
+    pub min_power: u8,
+    /// Power the wind stays below.
+    pub max_power: u8,
+    /// Speed of the wind at full power.
+    pub max_speed: f32,
+}
+
+impl Default for WindRule {
+    fn default() -> Self {
+        Self {
+            min_angle: 0,
+            max_angle: 255,
+            min_power: 128,
+            max_power: 255,
+            max_speed: WIND_POWER,
+        }
+    }
+}
+
+impl WindRule {
+    /// Returns `true` if the wind can be picked with the rule.
+    pub fn is_valid(&self) -> bool {
+        self.min_angle < self.max_angle
+            && self.min_power < self.max_power
+            && self.max_speed.is_finite()
+            && self.max_speed >= 0.0
+    }
+
+    /// Picks the angle and power of the wind for a new turn.
+    /// A range whose minimum is not below its maximum always gives the minimum.
+    pub fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> (u8, u8) {
+        let wind_angle = pick_below(rng, self.min_angle, self.max_angle);
+        let wind_power = pick_below(rng, self.min_power, self.max_power);
+        (wind_angle, wind_power)
+    }
+
+    pub fn velocity(&self, wind_angle: u8, wind_power: u8) -> Vec2 {
+        let radian = (wind_angle as f32 / 255.0) * TAU;
+        let direction = Vec2::new(radian.cos(), radian.sin());
+        let power = (wind_power as f32 / 255.0) * self.max_speed;
+        direction * power
+    }
+}
+
+fn pick_below<R: Rng + ?Sized>(rng: &mut R, min: u8, max: u8) -> u8 {
+    match min < max {
+        true => rng.random_range(min..max),
+        false => min,
+    }
+}
+
+/// A thrown projectile.
+/// The wind stops pushing it once it touches the ground, where it rolls to a stop.
+#[derive(Debug, Clone, Copy)]
+pub struct Projectile {
+    pub pos: Vec2,
+    pub vel: Vec2,
+    wind_vel: Vec2,
+    gravity: f32,
+    world_min_x: f32,
+    world_max_x: f32,
+    /// Time not yet simulated, shorter than a step.
+    carry_secs: f32,
+}
+
+impl Projectile {
+    pub fn new(side: Side, control: Option<(u8, u8)>, wind_vel: Vec2, ruleset: &Ruleset) -> Self {
+        Self {
+            pos: side.throw_pos(),
+            vel: throw_velocity(side, control, ruleset.throw_power),
+            wind_vel,
+            gravity: ruleset.gravity,
+            world_min_x: ruleset.world_min_x,
+            world_max_x: ruleset.world_max_x,
+            carry_secs: 0.0,
+        }
+    }
+
+    /// Continues the simulation from a position and velocity sent by the server.
+    pub fn from_snapshot(pos: Vec2, vel: Vec2, wind_vel: Vec2, ruleset: &Ruleset) -> Self {
+        let wind_vel = match pos.y <= LEFT_PLAYER_POS_Y {
+            true => Vec2::ZERO,
+            false => wind_vel,
+        };
+
+        Self {
+            pos,
+            vel,
+            wind_vel,
+            gravity: ruleset.gravity,
+            world_min_x: ruleset.world_min_x,
+            world_max_x: ruleset.world_max_x,
+            carry_secs: 0.0,
+        }
+    }
+
+    /// Moves the projectile forward by `delta_time` seconds.
+    /// Returns where it first touched the collider of the hero on the target side on the way.
+    pub fn step(&mut self, delta_time: f32, target: Side, collider: &Circle) -> Option<Vec2> {
+        let mut impact = None;
+        self.carry_secs += delta_time;
+        while self.carry_secs >= FIXED_STEP {
+            self.carry_secs -= FIXED_STEP;
+            self.integrate();
+            if impact.is_none() && target.is_hit(collider, self.pos) {
+                impact = Some(self.pos);
+            }
+        }
+        impact
+    }
+
+    /// Moves the projectile forward by `delta_time` seconds without looking for hits.
+    pub fn advance(&mut self, delta_time: f32) {
+        self.carry_secs += delta_time;
+        while self.carry_secs >= FIXED_STEP {
+            self.carry_secs -= FIXED_STEP;
+            self.integrate();
+        }
+    }
+
+    fn integrate(&mut self) {
+        self.vel.y += self.gravity * FIXED_STEP;
+        self.pos += (self.vel + self.wind_vel) * FIXED_STEP;
+
+        if self.pos.y < LEFT_PLAYER_POS_Y {
+            self.wind_vel = Vec2::ZERO;
+            self.vel.x = self.vel.x.lerp(0.0, FRICTION);
+            self.pos.y = LEFT_PLAYER_POS_Y;
+        }
+    }
+
+    /// Returns `true` once the projectile is on the ground or out of the world.
+    /// The turn ends a while after that.
+    pub fn is_landed(&self) -> bool {
+        self.pos.y <= LEFT_PLAYER_POS_Y
+            || self.pos.x <= self.world_min_x
+            || self.pos.x >= self.world_max_x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thrown() -> Projectile {
+        let wind_vel = WindRule::default().velocity(64, 200);
+        Projectile::new(Side::Left, Some((128, 200)), wind_vel, &Ruleset::default())
+    }
+
+    #[test]
+    fn path_does_not_depend_on_the_update_rate() {
+        let mut slow = thrown();
+        let mut fast = thrown();
+        for _ in 0..240 {
+            slow.advance(FIXED_STEP * 2.0);
+            for _ in 0..8 {
+                fast.advance(FIXED_STEP * 0.25);
+            }
+            assert_eq!(slow.pos, fast.pos);
+            assert_eq!(slow.vel, fast.vel);
+        }
+
+        // Less than a step of time does not move the projectile.
+        let pos = slow.pos;
+        slow.advance(FIXED_STEP * 0.5);
+        assert_eq!(slow.pos, pos);
+    }
+
+    #[test]
+    fn projectile_rolls_to_a_stop_on_the_ground() {
+        let ground = Vec2::new(0.0, LEFT_PLAYER_POS_Y);
+        let wind_vel = Vec2::new(50.0, 0.0);
+        let mut projectile =
+            Projectile::from_snapshot(ground, Vec2::new(100.0, 0.0), wind_vel, &Ruleset::default());
+        assert_eq!(projectile.wind_vel, Vec2::ZERO);
+        assert!(projectile.is_landed());
+
+        projectile.advance(FIXED_STEP);
+        assert_eq!(projectile.pos.y, LEFT_PLAYER_POS_Y);
+        assert_eq!(projectile.vel.x, 100.0 * (1.0 - FRICTION));
+
+        let mut speed = projectile.vel.x;
+        for _ in 0..1_000 {
+            projectile.advance(FIXED_STEP);
+            assert_eq!(projectile.pos.y, LEFT_PLAYER_POS_Y);
+            assert!(projectile.vel.x < speed);
+            speed = projectile.vel.x;
+        }
+        assert!(speed.abs() < 1e-3);
+    }
+
+    #[test]
+    fn projectile_falling_on_the_ground_stops_following_the_wind() {
+        let mut projectile = thrown();
+        while !projectile.is_landed() {
+            projectile.advance(FIXED_STEP);
+        }
+        assert_eq!(projectile.wind_vel, Vec2::ZERO);
+    }
+
+    #[test]
+    fn hit_test_uses_the_collider_of_the_side() {
+        let collider = Circle::new(40.0, (10.0, 160.0));
+        let reach = PROJECTILE_SIZE * 0.5 + collider.radius;
+        for side in [Side::Left, Side::Right] {
+            let center = side.collider_pos(&collider);
+            assert!(side.is_hit(&collider, center));
+            assert!(side.is_hit(&collider, center + Vec2::new(0.0, reach - 0.5)));
+            assert!(!side.is_hit(&collider, center + Vec2::new(0.0, reach + 0.5)));
+            assert!(!side.opponent().is_hit(&collider, center));
+        }
+
+        // Heroes on the right side are mirrored.
+        let left =
+            Side::Left.collider_pos(&collider) - Vec2::new(LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y);
+        let right =
+            Side::Right.collider_pos(&collider) - Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y);
+        assert_eq!(left, Vec2::new(-right.x, right.y));
+    }
+
+    #[test]
+    fn step_reports_where_the_projectile_hit() {
+        let collider = Circle::new(40.0, (0.0, 160.0));
+        let center = Side::Right.collider_pos(&collider);
+        let mut projectile =
+            Projectile::from_snapshot(center, Vec2::ZERO, Vec2::ZERO, &Ruleset::default());
+        let impact = projectile.step(FIXED_STEP, Side::Right, &collider);
+        assert_eq!(impact, Some(projectile.pos));
+
+        // A throw has not reached the opponent after its first step.
+        let mut projectile = thrown();
+        assert_eq!(projectile.step(FIXED_STEP, Side::Right, &collider), None);
+    }
+
+    #[test]
+    fn wind_is_picked_in_any_range() {
+        let mut rng = MatchRng::new(7).wind;
+        let rule = |min: u8, max: u8| WindRule {
+            min_angle: min,
+            max_angle: max,
+            min_power: min,
+            max_power: max,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            assert_eq!(rule(30, 30).pick(&mut rng), (30, 30));
+            assert_eq!(rule(200, 10).pick(&mut rng), (200, 200));
+            assert_eq!(rule(255, 255).pick(&mut rng), (255, 255));
+            assert_eq!(rule(0, 1).pick(&mut rng), (0, 0));
+
+            let (angle, power) = rule(0, 255).pick(&mut rng);
+            assert!(angle < 255 && power < 255);
+        }
+    }
+}
@@ -0,0 +1,557 @@
+use std::mem;
+
+use glam::Vec2;
+use rand::rngs::StdRng;
+
+use super::*;
+
+/// Version of the replay format written by this build.
+/// Bump it whenever the layout or the rules of the match change,
+/// since an older replay would no longer play out the same.
+pub const REPLAY_VERSION: u16 = 1;
+/// First bytes of a replay file.
+const REPLAY_MAGIC: [u8; 4] = *b"ESRP";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The data is not a replay.
+    InvalidMagic,
+    /// The replay was written by another version of the game.
+    UnsupportedVersion(u16),
+    /// The data ends in the middle of the replay.
+    Truncated,
+    /// The replay has a value that no version of the game writes.
+    InvalidData,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::InvalidMagic => write!(f, "Not a replay file"),
+            ReplayError::UnsupportedVersion(version) => {
+                write!(f, "Unsupported replay version: {version}")
+            }
+            ReplayError::Truncated => write!(f, "Replay file is truncated"),
+            ReplayError::InvalidData => write!(f, "Replay file is corrupted"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ReplayAction {
+    /// `UpdateThrowParams` of the player whose turn it is.
+    Aim { angle: u8, power: u8 },
+    /// `ThrowProjectile` of the player whose turn it is.
+    Throw,
+}
+
+/// An input the server applied during a match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReplayInput {
+    /// Tick of the match the input was applied on, counted from 1.
+    pub tick: u32,
+    pub action: ReplayAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReplayPlayer {
+    pub name: String,
+    pub hero: Hero,
+}
+
+/// A recorded match.
+/// Only the seed, the ruleset and the inputs are kept, and the match is simulated again to watch it.
+///
+/// The file format is little-endian:
+/// the magic `ESRP`, the version (u16), the seed (u64), the tick length (u32),
+/// the ruleset as its health count (u8), play time (i32), turn time (u16),
+/// throw power, gravity and world bounds (f32 each),
+/// and its wind as the lowest and highest angle and power (u8 each) and the speed (f32),
+/// the left and right players as a hero index (u8) and a UTF-8 name prefixed by its length (u16),
+/// and the inputs prefixed by their count (u32).
+/// Each input is its tick (u32), its kind (u8, 0 for aiming and 1 for throwing),
+/// and the angle and power of the aim (u8 each, zero when throwing).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Replay {
+    pub seed: u64,
+    /// Length of a tick of the match (milliseconds).
+    pub tick_millis: u32,
+    pub ruleset: Ruleset,
+    pub left: ReplayPlayer,
+    pub right: ReplayPlayer,
+    pub inputs: Vec<ReplayInput>,
+}
+
+impl Replay {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64 + self.inputs.len() * 7);
+        bytes.extend_from_slice(&REPLAY_MAGIC);
+        bytes.extend_from_slice(&REPLAY_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.seed.to_le_bytes());
+        bytes.extend_from_slice(&self.tick_millis.to_le_bytes());
+        let ruleset = &self.ruleset;
+        bytes.push(ruleset.health_count);
+        bytes.extend_from_slice(&ruleset.max_play_time.to_le_bytes());
+        bytes.extend_from_slice(&ruleset.max_turn_time.to_le_bytes());
+        for value in [
+            ruleset.throw_power,
+            ruleset.gravity,
+            ruleset.world_min_x,
+            ruleset.world_max_x,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        let wind = &ruleset.wind;
+        bytes.extend_from_slice(&[
+            wind.min_angle,
+            wind.max_angle,
+            wind.min_power,
+            wind.max_power,
+        ]);
+        bytes.extend_from_slice(&wind.max_speed.to_le_bytes());
+        for player in [&self.left, &self.right] {
+            let name = player.name.as_bytes();
+            let len = name.len().min(u16::MAX as usize);
+            bytes.push(player.hero.index() as u8);
+            bytes.extend_from_slice(&(len as u16).to_le_bytes());
+            bytes.extend_from_slice(&name[..len]);
+        }
+        bytes.extend_from_slice(&(self.inputs.len() as u32).to_le_bytes());
+        for input in self.inputs.iter() {
+            let (kind, angle, power) = match input.action {
+                ReplayAction::Aim { angle, power } => (0, angle, power),
+                ReplayAction::Throw => (1, 0, 0),
+            };
+            bytes.extend_from_slice(&input.tick.to_le_bytes());
+            bytes.extend_from_slice(&[kind, angle, power]);
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut reader = Reader(bytes);
+        if reader.take::<4>()? != REPLAY_MAGIC {
+            return Err(ReplayError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes(reader.take()?);
+        if version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+
+        let seed = u64::from_le_bytes(reader.take()?);
+        let tick_millis = u32::from_le_bytes(reader.take()?);
+        let [health_count] = reader.take()?;
+        let max_play_time = i32::from_le_bytes(reader.take()?);
+        let max_turn_time = u16::from_le_bytes(reader.take()?);
+        let throw_power = f32::from_le_bytes(reader.take()?);
+        let gravity = f32::from_le_bytes(reader.take()?);
+        let world_min_x = f32::from_le_bytes(reader.take()?);
+        let world_max_x = f32::from_le_bytes(reader.take()?);
+        let [min_angle, max_angle, min_power, max_power] = reader.take()?;
+        let max_speed = f32::from_le_bytes(reader.take()?);
+        let ruleset = Ruleset {
+            health_count,
+            max_play_time,
+            max_turn_time,
+            throw_power,
+            gravity,
+            world_min_x,
+            world_max_x,
+            wind: WindRule {
+                min_angle,
+                max_angle,
+                min_power,
+                max_power,
+                max_speed,
+            },
+        };
+        let left = reader.player()?;
+        let right = reader.player()?;
+
+        let count = u32::from_le_bytes(reader.take()?) as usize;
+        // Checked before allocating, so that a corrupted count cannot exhaust the memory.
+        if reader.0.len() < count.saturating_mul(7) {
+            return Err(ReplayError::Truncated);
+        }
+        let mut inputs = Vec::with_capacity(count);
+        for _ in 0..count {
+            let tick = u32::from_le_bytes(reader.take()?);
+            let action = match reader.take()? {
+                [0, angle, power] => ReplayAction::Aim { angle, power },
+                [1, _, _] => ReplayAction::Throw,
+                _ => return Err(ReplayError::InvalidData),
+            };
+            inputs.push(ReplayInput { tick, action });
+        }
+
+        if tick_millis == 0 || ruleset.validate().is_err() || !reader.0.is_empty() {
+            return Err(ReplayError::InvalidData);
+        }
+
+        Ok(Self {
+            seed,
+            tick_millis,
+            ruleset,
+            left,
+            right,
+            inputs,
+        })
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], ReplayError> {
+        let (head, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or(ReplayError::Truncated)?;
+        self.0 = rest;
+        Ok(*head)
+    }
+
+    fn player(&mut self) -> Result<ReplayPlayer, ReplayError> {
+        let [index] = self.take()?;
+        let hero = Hero::new(index as usize).ok_or(ReplayError::InvalidData)?;
+        let len = u16::from_le_bytes(self.take()?) as usize;
+        if self.0.len() < len {
+            return Err(ReplayError::Truncated);
+        }
+        let (name, rest) = self.0.split_at(len);
+        self.0 = rest;
+        let name = String::from_utf8(name.to_vec()).map_err(|_| ReplayError::InvalidData)?;
+        Ok(ReplayPlayer { name, hero })
+    }
+}
+
+/// Plays a recorded match again, one tick at a time, following the rules of the server.
+/// Each tick returns the packets the server sent on it.
+#[derive(Debug, Clone)]
+pub struct ReplaySim {
+    replay: Replay,
+    rng: StdRng,
+    tick: u32,
+    next_input: usize,
+    turn: TurnState,
+    hit: bool,
+    finished: bool,
+    control: Option<(u8, u8)>,
+    wind: (u8, u8),
+    wind_vel: Vec2,
+    projectile: Projectile,
+    left_collider: Circle,
+    right_collider: Circle,
+    left_health: usize,
+    right_health: usize,
+    total_remaining_millis: i32,
+    remaining_millis: u16,
+}
+
+impl ReplaySim {
+    pub fn new(replay: Replay) -> Self {
+        let mut rng = MatchRng::new(replay.seed).wind;
+        let wind = replay.ruleset.wind.pick(&mut rng);
+        let wind_vel = replay.ruleset.wind.velocity(wind.0, wind.1);
+        Self {
+            rng,
+            tick: 0,
+            next_input: 0,
+            turn: TurnState::LeftTurn,
+            hit: false,
+            finished: false,
+            control: None,
+            wind,
+            wind_vel,
+            projectile: Projectile::new(Side::Left, None, wind_vel, &replay.ruleset),
+            left_collider: *COLLIDER_DATA.get(&replay.left.hero).unwrap(),
+            right_collider: *COLLIDER_DATA.get(&replay.right.hero).unwrap(),
+            left_health: replay.ruleset.health_count as usize,
+            right_health: replay.ruleset.health_count as usize,
+            total_remaining_millis: replay.ruleset.max_play_time,
+            remaining_millis: replay.ruleset.max_turn_time,
+            replay,
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    /// Returns `true` once the match is over.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Time played so far (milliseconds).
+    pub fn elapsed_millis(&self) -> u32 {
+        self.tick.saturating_mul(self.replay.tick_millis)
+    }
+
+    /// Remaining health of the left and right players.
+    pub fn health(&self) -> (usize, usize) {
+        (self.left_health, self.right_health)
+    }
+
+    /// Returns the packet that brings a client to the current state of the match.
+    pub fn resync(&self) -> Packet {
+        Packet::InGameResync {
+            turn: self.turn,
+            total_remaining_millis: self.total_remaining_millis,
+            remaining_millis: self.remaining_millis,
+            left_health_cnt: self.left_health as u8,
+            right_health_cnt: self.right_health as u8,
+            wind_angle: self.wind.0,
+            wind_power: self.wind.1,
+        }
+    }
+
+    /// Plays the next tick of the match.
+    pub fn update(&mut self) -> Vec<Packet> {
+        let mut packets = Vec::new();
+        if self.finished {
+            return packets;
+        }
+
+        let elapsed = self.replay.tick_millis.min(u16::MAX as u32) as u16;
+        let delta_time = elapsed as f32 / 1000.0;
+        self.tick += 1;
+        self.total_remaining_millis -= self.replay.tick_millis.min(i32::MAX as u32) as i32;
+
+        while let Some(input) = self.replay.inputs.get(self.next_input)
+            && input.tick <= self.tick
+        {
+            self.next_input += 1;
+            let side = match self.turn {
+                TurnState::LeftTurn => Side::Left,
+                TurnState::RightTurn => Side::Right,
+                _ => continue,
+            };
+            match input.action {
+                ReplayAction::Aim { angle, power } => self.control = Some((angle, power)),
+                ReplayAction::Throw => {
+                    self.projectile =
+                        Projectile::new(side, self.control, self.wind_vel, &self.replay.ruleset);
+                    self.turn = match side {
+                        Side::Left => TurnState::LeftProjectileThrown,
+                        Side::Right => TurnState::RightProjectileThrown,
+                    };
+                    self.hit = false;
+                    self.remaining_millis = THROW_END_TIME;
+                }
+            }
+        }
+
+        match self.turn {
+            TurnState::LeftTurn | TurnState::RightTurn => {
+                self.remaining_millis = self.remaining_millis.saturating_sub(elapsed);
+                let total_remaining_millis = self.total_remaining_millis;
+                let remaining_millis = self.remaining_millis;
+                let left_health_cnt = self.left_health as u8;
+                let right_health_cnt = self.right_health as u8;
+                let control = self.control;
+                packets.push(match self.turn {
+                    TurnState::LeftTurn => Packet::InGameLeftTurn {
+                        total_remaining_millis,
+                        remaining_millis,
+                        left_health_cnt,
+                        right_health_cnt,
+                        control,
+                    },
+                    _ => Packet::InGameRightTurn {
+                        total_remaining_millis,
+                        remaining_millis,
+                        left_health_cnt,
+                        right_health_cnt,
+                        control,
+                    },
+                });
+
+                if self.remaining_millis == 0 {
+                    let next = match self.turn {
+                        TurnState::LeftTurn => TurnState::RightTurn,
+                        _ => TurnState::LeftTurn,
+                    };
+                    self.setup_turn(next, &mut packets);
+                }
+            }
+            TurnState::LeftProjectileThrown | TurnState::RightProjectileThrown => {
+                let (target, collider, health, next) = match self.turn {
+                    TurnState::LeftProjectileThrown => (
+                        Side::Right,
+                        &self.right_collider,
+                        &mut self.right_health,
+                        TurnState::RightTurn,
+                    ),
+                    _ => (
+                        Side::Left,
+                        &self.left_collider,
+                        &mut self.left_health,
+                        TurnState::LeftTurn,
+                    ),
+                };
+                if let Some(pos) = self.projectile.step(delta_time, target, collider)
+                    && !mem::replace(&mut self.hit, true)
+                {
+                    *health -= 1;
+                    packets.push(Packet::InGameHit {
+                        target,
+                        pos: pos.into(),
+                    });
+                }
+                let knocked_out = *health == 0;
+
+                if self.projectile.is_landed() {
+                    self.remaining_millis = self.remaining_millis.saturating_sub(elapsed);
+                }
+                packets.push(Packet::InGameProjectileThrown {
+                    total_remaining_millis: self.total_remaining_millis,
+                    remaining_millis: self.remaining_millis,
+                    left_health_cnt: self.left_health as u8,
+                    right_health_cnt: self.right_health as u8,
+                    projectile_pos: self.projectile.pos.into(),
+                    projectile_vel: self.projectile.vel.into(),
+                });
+
+                if self.remaining_millis == 0 {
+                    match knocked_out {
+                        true => self.finished = true,
+                        false => self.setup_turn(next, &mut packets),
+                    }
+                }
+            }
+        }
+
+        let thrown = matches!(
+            self.turn,
+            TurnState::LeftProjectileThrown | TurnState::RightProjectileThrown
+        );
+        if self.total_remaining_millis <= 0 && !thrown {
+            self.finished = true;
+        }
+        packets
+    }
+
+    fn setup_turn(&mut self, turn: TurnState, packets: &mut Vec<Packet>) {
+        self.wind = self.replay.ruleset.wind.pick(&mut self.rng);
+        self.wind_vel = self.replay.ruleset.wind.velocity(self.wind.0, self.wind.1);
+        packets.push(Packet::InGameTurnSetup {
+            wind_angle: self.wind.0,
+            wind_power: self.wind.1,
+        });
+
+        self.turn = turn;
+        self.remaining_millis = self.replay.ruleset.max_turn_time;
+        self.control = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay() -> Replay {
+        Replay {
+            seed: 0x1234_5678_9abc_def0,
+            tick_millis: 66,
+            ruleset: Ruleset {
+                health_count: 3,
+                max_play_time: 90_000,
+                max_turn_time: 5_000,
+                throw_power: 1_200.0,
+                gravity: -600.0,
+                world_min_x: -1_200.0,
+                world_max_x: 1_200.0,
+                wind: WindRule {
+                    min_angle: 32,
+                    max_angle: 96,
+                    min_power: 64,
+                    max_power: 200,
+                    max_speed: 250.0,
+                },
+            },
+            left: ReplayPlayer {
+                name: "에르핀".into(),
+                hero: Hero::Erpin,
+            },
+            right: ReplayPlayer {
+                name: "Bot".into(),
+                hero: Hero::Kommy,
+            },
+            inputs: vec![
+                ReplayInput {
+                    tick: 3,
+                    action: ReplayAction::Aim {
+                        angle: 120,
+                        power: 200,
+                    },
+                },
+                ReplayInput {
+                    tick: 10,
+                    action: ReplayAction::Throw,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn replay_file_keeps_the_match() {
+        let bytes = replay().encode();
+        assert_eq!(Replay::decode(&bytes), Ok(replay()));
+    }
+
+    #[test]
+    fn file_of_another_kind_is_rejected() {
+        assert_eq!(
+            Replay::decode(b"not a replay"),
+            Err(ReplayError::InvalidMagic)
+        );
+
+        let mut bytes = replay().encode();
+        bytes[..4].copy_from_slice(b"ESRQ");
+        assert_eq!(Replay::decode(&bytes), Err(ReplayError::InvalidMagic));
+    }
+
+    #[test]
+    fn file_of_another_version_is_rejected() {
+        for version in [REPLAY_VERSION - 1, REPLAY_VERSION + 1, u16::MAX] {
+            let mut bytes = replay().encode();
+            bytes[4..6].copy_from_slice(&version.to_le_bytes());
+            assert_eq!(
+                Replay::decode(&bytes),
+                Err(ReplayError::UnsupportedVersion(version))
+            );
+        }
+    }
+
+    #[test]
+    fn truncated_file_is_rejected() {
+        let bytes = replay().encode();
+        for len in 0..bytes.len() {
+            assert_eq!(
+                Replay::decode(&bytes[..len]),
+                Err(ReplayError::Truncated),
+                "length {len}"
+            );
+        }
+
+        let mut longer = bytes.clone();
+        longer.push(0);
+        assert_eq!(Replay::decode(&longer), Err(ReplayError::InvalidData));
+    }
+
+    #[test]
+    fn oversized_input_count_is_rejected() {
+        let replay = replay();
+        let mut bytes = replay.encode();
+        let count_at = bytes.len() - replay.inputs.len() * 7 - 4;
+        bytes[count_at..count_at + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(Replay::decode(&bytes), Err(ReplayError::Truncated));
+
+        let count = replay.inputs.len() as u32 + 1;
+        bytes[count_at..count_at + 4].copy_from_slice(&count.to_le_bytes());
+        assert_eq!(Replay::decode(&bytes), Err(ReplayError::Truncated));
+    }
+}
@@ -0,0 +1,372 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use protocol::Ruleset;
+use serde::Deserialize;
+use tokio::time::Duration;
+
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreKind {
+    /// Persists players and the leaderboard in Redis.
+    #[default]
+    Redis,
+    /// Keeps everything in memory. Nothing survives a restart.
+    Memory,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatchmakingKind {
+    /// Pairs players with close ratings.
+    #[default]
+    Rating,
+    /// Pairs players with close win rates.
+    WinRate,
+    /// Pairs players in the order they entered the queue.
+    Fifo,
+}
+
+/// What the server executable does after loading the configuration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// Runs the game server.
+    #[default]
+    Serve,
+    /// Removes leaderboard entries of expired users once and exits.
+    PruneLeaderboard,
+}
+
+/// Returns the server configuration.
+///
+/// # Panics
+/// Panics if `init_config` was never called.
+pub fn get_config() -> &'static Config {
+    CONFIG
+        .get()
+        .expect("Server configuration is not initialized.")
+}
+
+/// Sets the server configuration. Must be called before any handler is spawned.
+///
+/// # Panics
+/// Panics if the configuration is already initialized.
+pub fn init_config(config: Config) {
+    if CONFIG.set(config).is_err() {
+        panic!("Server configuration is already initialized.");
+    }
+}
+
+/// Reads the rules of the matches from a TOML file.
+/// Rules left out of the file keep their default values.
+pub fn load_ruleset(path: &Path) -> Result<Ruleset, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read ruleset file ({}): {e}", path.display()))?;
+    let ruleset: Ruleset = toml::from_str(&text)
+        .map_err(|e| format!("Failed to parse ruleset file ({}): {e}", path.display()))?;
+    ruleset
+        .validate()
+        .map_err(|e| format!("Invalid ruleset file ({}): {e}", path.display()))?;
+    Ok(ruleset)
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Storage backend for players and the leaderboard.
+    pub store: StoreKind,
+    /// Redis connection URL. Only used by the Redis store.
+    pub redis_url: String,
+    /// Address the WebSocket server listens on.
+    pub bind_addr: String,
+    /// Update period of the matching, sync, prepare and in-game loops (milliseconds).
+    pub tick_millis: u64,
+    /// How long a player waits in the matching queue before playing against a bot (milliseconds).
+    pub max_matching_time: u16,
+    /// How the players in the matching queue are paired.
+    pub matchmaking: MatchmakingKind,
+    /// Rating gap allowed when a player starts waiting for a match.
+    pub matching_rating_gap: u32,
+    /// How much the allowed rating gap widens per second of waiting.
+    pub matching_rating_gap_per_second: u32,
+    /// Win rate gap allowed when a player starts waiting for a match (percentage points).
+    pub matching_win_rate_gap: u32,
+    /// How much the allowed win rate gap widens per second of waiting (percentage points).
+    pub matching_win_rate_gap_per_second: u32,
+    /// How long the server waits for both clients to load the game (milliseconds).
+    pub max_sync_wait_time: u32,
+    /// How long the server waits before starting the game (milliseconds).
+    pub max_prepare_wait_time: u32,
+    /// TOML file with the rules of the matches. Replaces the `[ruleset]` table when set.
+    pub ruleset_file: Option<PathBuf>,
+    /// Rules of the matches, sent to the clients when a match starts.
+    pub ruleset: Ruleset,
+    /// How long both players have to accept a rematch after a game (milliseconds).
+    /// Zero disables rematches.
+    pub rematch_wait_time: u32,
+    /// How long a bot keeps the seat of a disconnected player so they can reconnect (milliseconds).
+    /// Zero disables reconnecting.
+    pub reconnect_grace_time: u32,
+    /// Lifetime of a user record after playing a game (seconds).
+    pub expire_seconds: i64,
+    /// Lifetime of a newly created user record (seconds).
+    pub initial_expire_seconds: i64,
+    /// Lifetime of the replay of a game (seconds).
+    pub replay_expire_seconds: i64,
+    /// Words that cannot be used in a name chosen by a player.
+    /// Case and spaces are ignored when matching.
+    pub denied_names: Vec<String>,
+    /// Rating of a player who has not played yet. Bots always play at this rating.
+    pub initial_rating: i32,
+    /// Largest rating change of a game between two players.
+    pub k_factor: u32,
+    /// Largest rating change of a game against a bot.
+    pub bot_k_factor: u32,
+    /// Start of the first season (unix seconds).
+    pub season_start: i64,
+    /// Length of a season (days). Each season has its own leaderboard.
+    pub season_length_days: u32,
+    /// How often entries of expired users are removed from the leaderboard (seconds).
+    /// Zero disables the pruning while the server runs.
+    pub leaderboard_prune_interval: u64,
+}
+
+impl Config {
+    /// Loads the configuration in order of priority: CLI arguments, environment variables,
+    /// the configuration file, and finally the default values.
+    /// Also returns the command given on the command line.
+    pub fn load() -> Result<(Self, Command), String> {
+        let args = Args::parse();
+        let command = args.command.unwrap_or_default();
+        Ok((Self::load_from(args)?, command))
+    }
+
+    fn load_from(args: Args) -> Result<Self, String> {
+        let mut config = match &args.config {
+            Some(path) => Self::from_file(path)?,
+            None => {
+                let path = PathBuf::from(DEFAULT_CONFIG_PATH);
+                match path.exists() {
+                    true => Self::from_file(&path)?,
+                    false => Self::default(),
+                }
+            }
+        };
+
+        if let Some(v) = args.store {
+            config.store = v;
+        }
+        if let Some(v) = args.redis_url {
+            config.redis_url = v;
+        }
+        if let Some(v) = args.bind_addr {
+            config.bind_addr = v;
+        }
+        if let Some(v) = args.tick_millis {
+            config.tick_millis = v;
+        }
+        if let Some(v) = args.max_matching_time {
+            config.max_matching_time = v;
+        }
+        if let Some(v) = args.matchmaking {
+            config.matchmaking = v;
+        }
+        if let Some(v) = args.matching_rating_gap {
+            config.matching_rating_gap = v;
+        }
+        if let Some(v) = args.matching_rating_gap_per_second {
+            config.matching_rating_gap_per_second = v;
+        }
+        if let Some(v) = args.matching_win_rate_gap {
+            config.matching_win_rate_gap = v;
+        }
+        if let Some(v) = args.matching_win_rate_gap_per_second {
+            config.matching_win_rate_gap_per_second = v;
+        }
+        if let Some(v) = args.max_sync_wait_time {
+            config.max_sync_wait_time = v;
+        }
+        if let Some(v) = args.max_prepare_wait_time {
+            config.max_prepare_wait_time = v;
+        }
+        if let Some(v) = args.ruleset_file {
+            config.ruleset_file = Some(v);
+        }
+        if let Some(v) = args.rematch_wait_time {
+            config.rematch_wait_time = v;
+        }
+        if let Some(v) = args.reconnect_grace_time {
+            config.reconnect_grace_time = v;
+        }
+        if let Some(v) = args.expire_seconds {
+            config.expire_seconds = v;
+        }
+        if let Some(v) = args.initial_expire_seconds {
+            config.initial_expire_seconds = v;
+        }
+        if let Some(v) = args.replay_expire_seconds {
+            config.replay_expire_seconds = v;
+        }
+        if let Some(v) = args.denied_names {
+            config.denied_names = v;
+        }
+        if let Some(v) = args.initial_rating {
+            config.initial_rating = v;
+        }
+        if let Some(v) = args.k_factor {
+            config.k_factor = v;
+        }
+        if let Some(v) = args.bot_k_factor {
+            config.bot_k_factor = v;
+        }
+        if let Some(v) = args.season_start {
+            config.season_start = v;
+        }
+        if let Some(v) = args.season_length_days {
+            config.season_length_days = v;
+        }
+        if let Some(v) = args.leaderboard_prune_interval {
+            config.leaderboard_prune_interval = v;
+        }
+
+        if let Some(path) = &config.ruleset_file {
+            config.ruleset = load_ruleset(path)?;
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn from_file(path: &PathBuf) -> Result<Self, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read config file ({}): {e}", path.display()))?;
+        toml::from_str(&text)
+            .map_err(|e| format!("Failed to parse config file ({}): {e}", path.display()))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.tick_millis == 0 {
+            return Err("tick_millis must be greater than zero".into());
+        }
+        self.ruleset
+            .validate()
+            .map_err(|e| format!("Invalid ruleset: {e}"))?;
+        if self.expire_seconds <= 0
+            || self.initial_expire_seconds <= 0
+            || self.replay_expire_seconds <= 0
+        {
+            return Err("expire seconds must be greater than zero".into());
+        }
+        if self.season_length_days == 0 {
+            return Err("season_length_days must be greater than zero".into());
+        }
+        Ok(())
+    }
+
+    pub fn period(&self) -> Duration {
+        Duration::from_millis(self.tick_millis)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            store: StoreKind::Redis,
+            redis_url: "redis://127.0.0.1/".into(),
+            bind_addr: "127.0.0.1:8889".into(),
+            tick_millis: 1_000 / 15,
+            max_matching_time: 10_000, // 10 seconds
+            matchmaking: MatchmakingKind::Rating,
+            matching_rating_gap: 100,
+            matching_rating_gap_per_second: 30,
+            matching_win_rate_gap: 10,
+            matching_win_rate_gap_per_second: 3,
+            max_sync_wait_time: 15_000,   // 15 seconds
+            max_prepare_wait_time: 5_000, // 5 seconds
+            ruleset_file: None,
+            ruleset: Ruleset::default(),
+            rematch_wait_time: 15_000,      // 15 seconds
+            reconnect_grace_time: 30_000,   // 30 seconds
+            expire_seconds: 15_552_000,     // 180 days
+            initial_expire_seconds: 86_400, // 24 hours
+            replay_expire_seconds: 604_800, // 7 days
+            denied_names: Vec::new(),
+            initial_rating: 1_000,
+            k_factor: 32,
+            bot_k_factor: 8,
+            season_start: 1_767_225_600, // 2026-01-01 00:00:00 UTC
+            season_length_days: 28,
+            leaderboard_prune_interval: 3_600, // 1 hour
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Path to the TOML configuration file.
+    #[arg(short, long, env = "SERVER_CONFIG")]
+    config: Option<PathBuf>,
+    #[arg(long, env = "SERVER_STORE", value_enum)]
+    store: Option<StoreKind>,
+    #[arg(long, env = "SERVER_REDIS_URL")]
+    redis_url: Option<String>,
+    #[arg(long, env = "SERVER_BIND_ADDR")]
+    bind_addr: Option<String>,
+    #[arg(long, env = "SERVER_TICK_MILLIS")]
+    tick_millis: Option<u64>,
+    #[arg(long, env = "SERVER_MAX_MATCHING_TIME")]
+    max_matching_time: Option<u16>,
+    #[arg(long, env = "SERVER_MATCHMAKING", value_enum)]
+    matchmaking: Option<MatchmakingKind>,
+    #[arg(long, env = "SERVER_MATCHING_RATING_GAP")]
+    matching_rating_gap: Option<u32>,
+    #[arg(long, env = "SERVER_MATCHING_RATING_GAP_PER_SECOND")]
+    matching_rating_gap_per_second: Option<u32>,
+    #[arg(long, env = "SERVER_MATCHING_WIN_RATE_GAP")]
+    matching_win_rate_gap: Option<u32>,
+    #[arg(long, env = "SERVER_MATCHING_WIN_RATE_GAP_PER_SECOND")]
+    matching_win_rate_gap_per_second: Option<u32>,
+    #[arg(long, env = "SERVER_MAX_SYNC_WAIT_TIME")]
+    max_sync_wait_time: Option<u32>,
+    #[arg(long, env = "SERVER_MAX_PREPARE_WAIT_TIME")]
+    max_prepare_wait_time: Option<u32>,
+    /// Path to the TOML ruleset file of the matches.
+    #[arg(long, env = "SERVER_RULESET_FILE")]
+    ruleset_file: Option<PathBuf>,
+    #[arg(long, env = "SERVER_REMATCH_WAIT_TIME")]
+    rematch_wait_time: Option<u32>,
+    #[arg(long, env = "SERVER_RECONNECT_GRACE_TIME")]
+    reconnect_grace_time: Option<u32>,
+    #[arg(long, env = "SERVER_EXPIRE_SECONDS")]
+    expire_seconds: Option<i64>,
+    #[arg(long, env = "SERVER_INITIAL_EXPIRE_SECONDS")]
+    initial_expire_seconds: Option<i64>,
+    #[arg(long, env = "SERVER_REPLAY_EXPIRE_SECONDS")]
+    replay_expire_seconds: Option<i64>,
+    /// Comma-separated list of words that cannot be used in names.
+    #[arg(long, env = "SERVER_DENIED_NAMES", value_delimiter = ',')]
+    denied_names: Option<Vec<String>>,
+    #[arg(long, env = "SERVER_INITIAL_RATING")]
+    initial_rating: Option<i32>,
+    #[arg(long, env = "SERVER_K_FACTOR")]
+    k_factor: Option<u32>,
+    #[arg(long, env = "SERVER_BOT_K_FACTOR")]
+    bot_k_factor: Option<u32>,
+    #[arg(long, env = "SERVER_SEASON_START")]
+    season_start: Option<i64>,
+    #[arg(long, env = "SERVER_SEASON_LENGTH_DAYS")]
+    season_length_days: Option<u32>,
+    #[arg(long, env = "SERVER_LEADERBOARD_PRUNE_INTERVAL")]
+    leaderboard_prune_interval: Option<u64>,
+}
@@ -1,52 +1,6 @@
 use super::*;
 
 const MAX_LOOP: usize = 100;
-const TICK: u64 = 1_000 / 15;
-const PERIOD: Duration = Duration::from_millis(TICK);
-const SUB_STEP: i32 = 8;
-const FRICTION: f32 = 0.25;
-
-const BIAS: f32 = 50.0;
-const BOT_LERP_RANGE: RangeInclusive<f32> = 0.5..=0.9;
-const BOT_LERP_OFFSET: RangeInclusive<f32> = -0.05..=0.05;
-const BOT_TIME_RANGE: RangeInclusive<f32> = 1.5..=3.0;
-
-#[derive(Clone, Copy)]
-enum BotLevel {
-    Low,
-    Medium,
-    High,
-}
-
-impl BotLevel {
-    pub fn new() -> Self {
-        match rand::random_range(0..3) {
-            0 => BotLevel::Low,
-            1 => BotLevel::Medium,
-            2 => BotLevel::High,
-            _ => unreachable!(),
-        }
-    }
-
-    pub fn power_offset(&self) -> f32 {
-        let range = match self {
-            BotLevel::Low => -THROW_POWER * 0.1..=THROW_POWER * 0.1,
-            BotLevel::Medium => -THROW_POWER * 0.07..=THROW_POWER * 0.07,
-            BotLevel::High => -THROW_POWER * 0.03..=THROW_POWER * 0.03,
-        };
-        rand::random_range(range)
-    }
-
-    pub fn angle_offset(&self) -> f32 {
-        let range = match self {
-            BotLevel::Low => -PI / 20.0..=PI / 20.0,
-            BotLevel::Medium => -PI / 25.0..=PI / 25.0,
-            BotLevel::High => -PI / 30.0..=PI / 30.0,
-        };
-        rand::random_range(range)
-    }
-}
-
 #[derive(Default, Clone, Copy)]
 enum GameState {
     #[default]
@@ -67,44 +21,68 @@ impl GameState {
             GameState::LeftProjectileThrown { .. } | GameState::RightProjectileThrown { .. }
         )
     }
+
+    pub fn turn_state(&self) -> TurnState {
+        match self {
+            GameState::LeftTurn => TurnState::LeftTurn,
+            GameState::RightTurn => TurnState::RightTurn,
+            GameState::LeftProjectileThrown { .. } => TurnState::LeftProjectileThrown,
+            GameState::RightProjectileThrown { .. } => TurnState::RightProjectileThrown,
+        }
+    }
 }
 
 pub async fn play(
     mut left: Box<dyn Session>,
     mut right: Box<dyn Session>,
     mut num_player: usize,
-    mut redis_conn: MultiplexedConnection,
+    store: SharedStore,
 ) {
-    let mut left_health = MAX_HEALTH_COUNT;
-    let mut right_health = MAX_HEALTH_COUNT;
+    let ruleset = get_config().ruleset.clone();
+    let mut left_health = ruleset.health_count as usize;
+    let mut right_health = ruleset.health_count as usize;
     let left_collider = COLLIDER_DATA.get(&left.hero()).unwrap();
     let right_collider = COLLIDER_DATA.get(&right.hero()).unwrap();
     let mut control = None;
-    let (mut wind_angle, mut wind_power, mut wind_vel) = update_wind_parameter();
-    let mut projectile_vel = Vec2::ZERO;
-    let mut projectile_pos = Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y);
+    let seed: u64 = rand::random();
+    let mut rng = MatchRng::new(seed);
+    let (mut wind_angle, mut wind_power, mut wind_vel) =
+        update_wind_parameter(&mut rng.wind, &ruleset.wind);
+    let mut projectile = Projectile::new(Side::Left, None, wind_vel, &ruleset);
     let mut game_state = GameState::default();
-    let mut remaining_millis = MAX_CTRL_TIME;
-    let mut total_remaining_millis = MAX_PLAY_TIME;
-    let mut interval = time::interval(PERIOD);
-    let mut previous_instant = Instant::now();
+    let mut left_rejoin = None;
+    let mut right_rejoin = None;
+    let mut remaining_millis = ruleset.max_turn_time;
+    let mut total_remaining_millis = ruleset.max_play_time;
+    let mut interval = time::interval(get_config().period());
+    let started = Instant::now();
     interval.set_missed_tick_behavior(time::MissedTickBehavior::Burst);
+    interval.tick().await; // The first tick completes immediately.
+    let mut tick: u32 = 0;
+    let mut inputs = Vec::new();
 
-    let level = BotLevel::new();
-    let mut lerp_p = rand::random_range(BOT_LERP_RANGE);
-    let mut bot_src_vel = Vec2::new(LEFT_START_ANGLE.cos(), LEFT_START_ANGLE.sin()) * f32::EPSILON;
-    let mut bot_dst_vel = update_left_bot_parameter(
-        Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y),
-        Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y)
-            + Vec2::from(right_collider.center) * Vec2::new(-1.0, 1.0),
-        wind_vel,
+    let level: BotLevel = rng.bot.random();
+    let mut bot_aim = BotAim::new(
+        Side::Left,
         level,
+        right_collider,
+        wind_vel,
+        &ruleset,
+        &mut rng.bot,
+    );
+
+    let mut broadcast = spectate::Broadcast::open(
+        play_data(&mut left),
+        play_data(&mut right),
+        ruleset.clone(),
+        store.clone(),
     );
 
     let message = Packet::InGameTurnSetup {
         wind_angle,
         wind_power,
     };
+    broadcast.send(&message);
     left = send_message(left, &message, &mut num_player);
     right = send_message(right, &message, &mut num_player);
     if num_player == 0 {
@@ -114,16 +92,72 @@ pub async fn play(
     }
 
     while total_remaining_millis > 0 || game_state.is_projectile_thrown() {
+        // Every tick plays the same length of the match, so that the inputs replay it exactly.
+        // Ticks missed by a busy server are played in a burst to catch up.
         let instant = interval.tick().await;
-        let elapsed = instant
-            .saturating_duration_since(previous_instant)
-            .as_millis();
-        let elapsed_u16 = elapsed.min(u16::MAX as u128) as u16;
-        let elapsed_i32 = elapsed.min(i32::MAX as u128) as i32;
-        previous_instant = instant;
+        let elapsed = get_config().tick_millis;
+        let elapsed_u16 = elapsed.min(u16::MAX as u64) as u16;
+        let elapsed_i32 = elapsed.min(i32::MAX as u64) as i32;
+        tick += 1;
 
         total_remaining_millis -= elapsed_i32;
 
+        // --- Players reconnecting to their seats ---
+        let (left_arrived, right_arrived);
+        (left, left_arrived) = take_reconnected(left, &mut left_rejoin, &mut num_player, instant);
+        (right, right_arrived) =
+            take_reconnected(right, &mut right_rejoin, &mut num_player, instant);
+        if left_arrived || right_arrived {
+            let message = Packet::RejoinGame {
+                left: play_data(&mut left),
+                right: play_data(&mut right),
+                ruleset: Box::new(ruleset.clone()),
+            };
+            for (arrived, rejoin) in [
+                (left_arrived, &mut left_rejoin),
+                (right_arrived, &mut right_rejoin),
+            ] {
+                if arrived
+                    && let Some(player) = rejoin
+                    && player.tx.send(message.clone()).is_err()
+                {
+                    println!("WebSocket disconnected ({:?})", player);
+                    *rejoin = None;
+                }
+            }
+        }
+
+        let left_loaded = poll_rejoin(&mut left_rejoin);
+        let right_loaded = poll_rejoin(&mut right_rejoin);
+        if left_loaded.is_some() || right_loaded.is_some() {
+            let message = Packet::InGameResync {
+                turn: game_state.turn_state(),
+                total_remaining_millis,
+                remaining_millis,
+                left_health_cnt: left_health as u8,
+                right_health_cnt: right_health as u8,
+                wind_angle,
+                wind_power,
+            };
+            if let Some(player) = left_loaded {
+                left = send_message(return_seat(left, player), &message, &mut num_player);
+            }
+            if let Some(player) = right_loaded {
+                right = send_message(return_seat(right, player), &message, &mut num_player);
+            }
+        }
+
+        // --- Spectators ---
+        broadcast.update(&Packet::InGameResync {
+            turn: game_state.turn_state(),
+            total_remaining_millis,
+            remaining_millis,
+            left_health_cnt: left_health as u8,
+            right_health_cnt: right_health as u8,
+            wind_angle,
+            wind_power,
+        });
+
         match left.reader() {
             Some(stream) => {
                 let mut cnt = MAX_LOOP;
@@ -140,21 +174,22 @@ pub async fn play(
                                         Packet::UpdateThrowParams { angle, power },
                                     ) => {
                                         control = Some((angle, power));
+                                        inputs.push(ReplayInput {
+                                            tick,
+                                            action: ReplayAction::Aim { angle, power },
+                                        });
                                     }
                                     (GameState::LeftTurn, Packet::ThrowProjectile) => {
-                                        projectile_pos =
-                                            Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y);
-                                        projectile_vel = control
-                                            .map(|(angle, power)| {
-                                                let delta = angle as f32 / 255.0;
-                                                let radian = LEFT_START_ANGLE
-                                                    + (LEFT_END_ANGLE - LEFT_START_ANGLE) * delta;
-                                                let direction =
-                                                    Vec2::new(radian.cos(), radian.sin());
-                                                let power = (power as f32 / 255.0) * THROW_POWER;
-                                                direction * power
-                                            })
-                                            .unwrap_or_default();
+                                        inputs.push(ReplayInput {
+                                            tick,
+                                            action: ReplayAction::Throw,
+                                        });
+                                        projectile = Projectile::new(
+                                            Side::Left,
+                                            control,
+                                            wind_vel,
+                                            &ruleset,
+                                        );
                                         game_state = GameState::LeftProjectileThrown { hit: false };
                                         remaining_millis = THROW_END_TIME;
                                     }
@@ -168,8 +203,7 @@ pub async fn play(
                             #[cfg(not(feature = "no-debugging-log"))]
                             println!("Left player({:?}) replaced by Bot", left);
 
-                            left = Box::new(Bot::from(left));
-                            num_player -= 1;
+                            left = replace_with_bot(left, &mut num_player);
                             break 'update; // Handle disconnection.
                         }
                         StreamPollResult::Closed => {
@@ -178,8 +212,7 @@ pub async fn play(
                             #[cfg(not(feature = "no-debugging-log"))]
                             println!("Left player({:?}) replaced by Bot", left);
 
-                            left = Box::new(Bot::from(left));
-                            num_player -= 1;
+                            left = replace_with_bot(left, &mut num_player);
                             break 'update; // Handle closure.
                         }
                     }
@@ -190,33 +223,21 @@ pub async fn play(
                 match game_state {
                     GameState::LeftTurn => {
                         let delta_time = elapsed_i32 as f32 / 1000.0;
-                        let s = lerp_p + rand::random_range(BOT_LERP_OFFSET);
-                        bot_src_vel = bot_src_vel.lerp(bot_dst_vel, s * delta_time);
-
-                        let length = bot_src_vel.length().min(THROW_POWER);
-                        let power = (length / THROW_POWER * 255.0) as u8;
-
-                        let radian = bot_src_vel
-                            .to_angle()
-                            .clamp(LEFT_START_ANGLE, LEFT_END_ANGLE);
-                        let angle = ((radian - LEFT_START_ANGLE)
-                            / (LEFT_END_ANGLE - LEFT_START_ANGLE)
-                            * 255.0) as u8;
-
+                        let (angle, power) = bot_aim.update(delta_time, &mut rng.bot);
+                        if control != Some((angle, power)) {
+                            inputs.push(ReplayInput {
+                                tick,
+                                action: ReplayAction::Aim { angle, power },
+                            });
+                        }
                         control = Some((angle, power));
 
-                        if bot_src_vel.abs_diff_eq(bot_dst_vel, BIAS) {
-                            projectile_pos = Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y);
-                            projectile_vel = control
-                                .map(|(angle, power)| {
-                                    let delta = angle as f32 / 255.0;
-                                    let radian = LEFT_START_ANGLE
-                                        + (LEFT_END_ANGLE - LEFT_START_ANGLE) * delta;
-                                    let direction = Vec2::new(radian.cos(), radian.sin());
-                                    let power = (power as f32 / 255.0) * THROW_POWER;
-                                    direction * power
-                                })
-                                .unwrap_or_default();
+                        if bot_aim.is_ready() {
+                            inputs.push(ReplayInput {
+                                tick,
+                                action: ReplayAction::Throw,
+                            });
+                            projectile = Projectile::new(Side::Left, control, wind_vel, &ruleset);
                             game_state = GameState::LeftProjectileThrown { hit: false };
                             remaining_millis = THROW_END_TIME;
                         }
@@ -242,21 +263,22 @@ pub async fn play(
                                         Packet::UpdateThrowParams { angle, power },
                                     ) => {
                                         control = Some((angle, power));
+                                        inputs.push(ReplayInput {
+                                            tick,
+                                            action: ReplayAction::Aim { angle, power },
+                                        });
                                     }
                                     (GameState::RightTurn, Packet::ThrowProjectile) => {
-                                        projectile_pos =
-                                            Vec2::new(RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y);
-                                        projectile_vel = control
-                                            .map(|(angle, power)| {
-                                                let delta = angle as f32 / 255.0;
-                                                let radian = RIGHT_START_ANGLE
-                                                    + (RIGHT_END_ANGLE - RIGHT_START_ANGLE) * delta;
-                                                let direction =
-                                                    Vec2::new(radian.cos(), radian.sin());
-                                                let power = (power as f32 / 255.0) * THROW_POWER;
-                                                direction * power
-                                            })
-                                            .unwrap_or_default();
+                                        inputs.push(ReplayInput {
+                                            tick,
+                                            action: ReplayAction::Throw,
+                                        });
+                                        projectile = Projectile::new(
+                                            Side::Right,
+                                            control,
+                                            wind_vel,
+                                            &ruleset,
+                                        );
                                         game_state =
                                             GameState::RightProjectileThrown { hit: false };
                                         remaining_millis = THROW_END_TIME;
@@ -269,20 +291,18 @@ pub async fn play(
                             println!("WebSocket disconnected ({:?}): {e}", right);
 
                             #[cfg(not(feature = "no-debugging-log"))]
-                            println!("Right player({:?}) replaced by Bot", left);
+                            println!("Right player({:?}) replaced by Bot", right);
 
-                            right = Box::new(Bot::from(right));
-                            num_player -= 1;
+                            right = replace_with_bot(right, &mut num_player);
                             break 'update; // Handle disconnection.
                         }
                         StreamPollResult::Closed => {
                             println!("WebSocket disconnected ({:?})", right);
 
                             #[cfg(not(feature = "no-debugging-log"))]
-                            println!("Right player({:?}) replaced by Bot", left);
+                            println!("Right player({:?}) replaced by Bot", right);
 
-                            right = Box::new(Bot::from(right));
-                            num_player -= 1;
+                            right = replace_with_bot(right, &mut num_player);
                             break 'update; // Handle closure.
                         }
                     }
@@ -293,33 +313,21 @@ pub async fn play(
                 match game_state {
                     GameState::RightTurn => {
                         let delta_time = elapsed_i32 as f32 / 1000.0;
-                        let s = lerp_p + rand::random_range(BOT_LERP_OFFSET);
-                        bot_src_vel = bot_src_vel.lerp(bot_dst_vel, s * delta_time);
-
-                        let length = bot_src_vel.length().min(THROW_POWER);
-                        let power = (length / THROW_POWER * 255.0) as u8;
-
-                        let radian = bot_src_vel
-                            .to_angle()
-                            .clamp(RIGHT_START_ANGLE, RIGHT_END_ANGLE);
-                        let angle = ((radian - RIGHT_START_ANGLE)
-                            / (RIGHT_END_ANGLE - RIGHT_START_ANGLE)
-                            * 255.0) as u8;
-
+                        let (angle, power) = bot_aim.update(delta_time, &mut rng.bot);
+                        if control != Some((angle, power)) {
+                            inputs.push(ReplayInput {
+                                tick,
+                                action: ReplayAction::Aim { angle, power },
+                            });
+                        }
                         control = Some((angle, power));
 
-                        if bot_src_vel.abs_diff_eq(bot_dst_vel, BIAS) {
-                            projectile_pos = Vec2::new(RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y);
-                            projectile_vel = control
-                                .map(|(angle, power)| {
-                                    let delta = angle as f32 / 255.0;
-                                    let radian = RIGHT_START_ANGLE
-                                        + (RIGHT_END_ANGLE - RIGHT_START_ANGLE) * delta;
-                                    let direction = Vec2::new(radian.cos(), radian.sin());
-                                    let power = (power as f32 / 255.0) * THROW_POWER;
-                                    direction * power
-                                })
-                                .unwrap_or_default();
+                        if bot_aim.is_ready() {
+                            inputs.push(ReplayInput {
+                                tick,
+                                action: ReplayAction::Throw,
+                            });
+                            projectile = Projectile::new(Side::Right, control, wind_vel, &ruleset);
                             game_state = GameState::RightProjectileThrown { hit: false };
                             remaining_millis = THROW_END_TIME;
                         }
@@ -346,6 +354,7 @@ pub async fn play(
                     right_health_cnt: right_health as u8,
                     control,
                 };
+                broadcast.send(&message);
                 left = send_message(left, &message, &mut num_player);
                 right = send_message(right, &message, &mut num_player);
                 if num_player == 0 {
@@ -358,11 +367,13 @@ pub async fn play(
                     #[cfg(not(feature = "no-debugging-log"))]
                     println!("Left turn ended.");
 
-                    (wind_angle, wind_power, wind_vel) = update_wind_parameter();
+                    (wind_angle, wind_power, wind_vel) =
+                        update_wind_parameter(&mut rng.wind, &ruleset.wind);
                     let message = Packet::InGameTurnSetup {
                         wind_angle,
                         wind_power,
                     };
+                    broadcast.send(&message);
                     left = send_message(left, &message, &mut num_player);
                     right = send_message(right, &message, &mut num_player);
                     if num_player == 0 {
@@ -371,19 +382,17 @@ pub async fn play(
                         return;
                     }
 
-                    lerp_p = rand::random_range(BOT_LERP_RANGE);
-                    bot_src_vel =
-                        Vec2::new(RIGHT_START_ANGLE.cos(), RIGHT_START_ANGLE.sin()) * f32::EPSILON;
-                    bot_dst_vel = update_right_bot_parameter(
-                        Vec2::new(RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y),
-                        Vec2::new(LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y)
-                            + Vec2::from(left_collider.center),
-                        wind_vel,
+                    bot_aim = BotAim::new(
+                        Side::Right,
                         level,
+                        left_collider,
+                        wind_vel,
+                        &ruleset,
+                        &mut rng.bot,
                     );
 
                     game_state = GameState::RightTurn;
-                    remaining_millis = MAX_CTRL_TIME;
+                    remaining_millis = ruleset.max_turn_time;
                     control = None;
                 }
             }
@@ -397,6 +406,7 @@ pub async fn play(
                     right_health_cnt: right_health as u8,
                     control,
                 };
+                broadcast.send(&message);
                 left = send_message(left, &message, &mut num_player);
                 right = send_message(right, &message, &mut num_player);
                 if num_player == 0 {
@@ -409,11 +419,13 @@ pub async fn play(
                     #[cfg(not(feature = "no-debugging-log"))]
                     println!("Right turn ended.");
 
-                    (wind_angle, wind_power, wind_vel) = update_wind_parameter();
+                    (wind_angle, wind_power, wind_vel) =
+                        update_wind_parameter(&mut rng.wind, &ruleset.wind);
                     let message = Packet::InGameTurnSetup {
                         wind_angle,
                         wind_power,
                     };
+                    broadcast.send(&message);
                     left = send_message(left, &message, &mut num_player);
                     right = send_message(right, &message, &mut num_player);
                     if num_player == 0 {
@@ -422,53 +434,39 @@ pub async fn play(
                         return;
                     }
 
-                    lerp_p = rand::random_range(BOT_LERP_RANGE);
-                    bot_src_vel =
-                        Vec2::new(LEFT_START_ANGLE.cos(), LEFT_START_ANGLE.sin()) * f32::EPSILON;
-                    bot_dst_vel = update_left_bot_parameter(
-                        Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y),
-                        Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y)
-                            + Vec2::from(right_collider.center) * Vec2::new(-1.0, 1.0),
-                        wind_vel,
+                    bot_aim = BotAim::new(
+                        Side::Left,
                         level,
+                        right_collider,
+                        wind_vel,
+                        &ruleset,
+                        &mut rng.bot,
                     );
 
                     game_state = GameState::LeftTurn;
-                    remaining_millis = MAX_CTRL_TIME;
+                    remaining_millis = ruleset.max_turn_time;
                     control = None;
                 }
             }
             GameState::LeftProjectileThrown { mut hit } => {
                 let delta_time = elapsed_u16 as f32 / 1000.0;
-                let sub_seconds = delta_time / SUB_STEP as f32;
-
-                let radius_sum = PROJECTILE_SIZE * 0.5 + right_collider.radius;
-                let mut collider_pos = Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y);
-                collider_pos += Vec2::from(right_collider.center) * Vec2::new(-1.0, 1.0);
-
-                for _ in 0..SUB_STEP {
-                    projectile_vel.y += GRAVITY * sub_seconds;
-                    projectile_pos += (projectile_vel + wind_vel) * sub_seconds;
-
-                    if projectile_pos.y < LEFT_PLAYER_POS_Y {
-                        wind_vel = Vec2::ZERO;
-                        projectile_vel.x = projectile_vel.x.lerp(0.0, FRICTION / SUB_STEP as f32);
-                        projectile_pos.y = LEFT_PLAYER_POS_Y;
-                    }
-
-                    let distance_squared = (projectile_pos - collider_pos).length_squared();
+                if let Some(pos) = projectile.step(delta_time, Side::Right, right_collider)
+                    && !hit
+                {
+                    hit = true;
+                    right_health -= 1;
 
-                    if !hit && distance_squared <= radius_sum * radius_sum {
-                        hit = true;
-                        right_health -= 1;
-                    }
+                    let message = Packet::InGameHit {
+                        target: Side::Right,
+                        pos: pos.into(),
+                    };
+                    broadcast.send(&message);
+                    left = send_message(left, &message, &mut num_player);
+                    right = send_message(right, &message, &mut num_player);
                 }
                 game_state = GameState::LeftProjectileThrown { hit };
 
-                if projectile_pos.y <= LEFT_PLAYER_POS_Y
-                    || projectile_pos.x <= WORLD_MIN_X
-                    || projectile_pos.x >= WORLD_MAX_X
-                {
+                if projectile.is_landed() {
                     remaining_millis = remaining_millis.saturating_sub(elapsed_u16);
                 }
 
@@ -477,9 +475,10 @@ pub async fn play(
                     remaining_millis,
                     left_health_cnt: left_health as u8,
                     right_health_cnt: right_health as u8,
-                    projectile_pos: projectile_pos.into(),
-                    projectile_vel: projectile_vel.into(),
+                    projectile_pos: projectile.pos.into(),
+                    projectile_vel: projectile.vel.into(),
                 };
+                broadcast.send(&message);
                 left = send_message(left, &message, &mut num_player);
                 right = send_message(right, &message, &mut num_player);
                 if num_player == 0 {
@@ -496,11 +495,13 @@ pub async fn play(
                         break;
                     }
 
-                    (wind_angle, wind_power, wind_vel) = update_wind_parameter();
+                    (wind_angle, wind_power, wind_vel) =
+                        update_wind_parameter(&mut rng.wind, &ruleset.wind);
                     let message = Packet::InGameTurnSetup {
                         wind_angle,
                         wind_power,
                     };
+                    broadcast.send(&message);
                     left = send_message(left, &message, &mut num_player);
                     right = send_message(right, &message, &mut num_player);
                     if num_player == 0 {
@@ -509,52 +510,39 @@ pub async fn play(
                         return;
                     }
 
-                    lerp_p = rand::random_range(BOT_LERP_RANGE);
-                    bot_src_vel =
-                        Vec2::new(RIGHT_START_ANGLE.cos(), RIGHT_START_ANGLE.sin()) * f32::EPSILON;
-                    bot_dst_vel = update_right_bot_parameter(
-                        Vec2::new(RIGHT_THROW_POS_X, RIGHT_THROW_POS_Y),
-                        Vec2::new(LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y)
-                            + Vec2::from(left_collider.center),
-                        wind_vel,
+                    bot_aim = BotAim::new(
+                        Side::Right,
                         level,
+                        left_collider,
+                        wind_vel,
+                        &ruleset,
+                        &mut rng.bot,
                     );
 
                     game_state = GameState::RightTurn;
-                    remaining_millis = MAX_CTRL_TIME;
+                    remaining_millis = ruleset.max_turn_time;
                     control = None;
                 }
             }
             GameState::RightProjectileThrown { mut hit } => {
                 let delta_time = elapsed_u16 as f32 / 1000.0;
-                let sub_seconds = delta_time / SUB_STEP as f32;
-
-                let radius_sum = PROJECTILE_SIZE * 0.5 + left_collider.radius;
-                let mut collider_pos = Vec2::new(LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y);
-                collider_pos += Vec2::from(left_collider.center);
-
-                for _ in 0..SUB_STEP {
-                    projectile_vel.y += GRAVITY * sub_seconds;
-                    projectile_pos += (projectile_vel + wind_vel) * sub_seconds;
-
-                    if projectile_pos.y < LEFT_PLAYER_POS_Y {
-                        wind_vel = Vec2::ZERO;
-                        projectile_vel.x = projectile_vel.x.lerp(0.0, FRICTION / SUB_STEP as f32);
-                        projectile_pos.y = LEFT_PLAYER_POS_Y;
-                    }
+                if let Some(pos) = projectile.step(delta_time, Side::Left, left_collider)
+                    && !hit
+                {
+                    hit = true;
+                    left_health -= 1;
 
-                    let distance_squared = (projectile_pos - collider_pos).length_squared();
-                    if !hit && distance_squared <= radius_sum * radius_sum {
-                        hit = true;
-                        left_health -= 1;
-                    }
+                    let message = Packet::InGameHit {
+                        target: Side::Left,
+                        pos: pos.into(),
+                    };
+                    broadcast.send(&message);
+                    left = send_message(left, &message, &mut num_player);
+                    right = send_message(right, &message, &mut num_player);
                 }
                 game_state = GameState::RightProjectileThrown { hit };
 
-                if projectile_pos.y <= LEFT_PLAYER_POS_Y
-                    || projectile_pos.x <= WORLD_MIN_X
-                    || projectile_pos.x >= WORLD_MAX_X
-                {
+                if projectile.is_landed() {
                     remaining_millis = remaining_millis.saturating_sub(elapsed_u16);
                 }
 
@@ -563,9 +551,10 @@ pub async fn play(
                     remaining_millis,
                     left_health_cnt: left_health as u8,
                     right_health_cnt: right_health as u8,
-                    projectile_pos: projectile_pos.into(),
-                    projectile_vel: projectile_vel.into(),
+                    projectile_pos: projectile.pos.into(),
+                    projectile_vel: projectile.vel.into(),
                 };
+                broadcast.send(&message);
                 left = send_message(left, &message, &mut num_player);
                 right = send_message(right, &message, &mut num_player);
                 if num_player == 0 {
@@ -582,11 +571,13 @@ pub async fn play(
                         break;
                     }
 
-                    (wind_angle, wind_power, wind_vel) = update_wind_parameter();
+                    (wind_angle, wind_power, wind_vel) =
+                        update_wind_parameter(&mut rng.wind, &ruleset.wind);
                     let message = Packet::InGameTurnSetup {
                         wind_angle,
                         wind_power,
                     };
+                    broadcast.send(&message);
                     left = send_message(left, &message, &mut num_player);
                     right = send_message(right, &message, &mut num_player);
                     if num_player == 0 {
@@ -595,19 +586,17 @@ pub async fn play(
                         return;
                     }
 
-                    lerp_p = rand::random_range(BOT_LERP_RANGE);
-                    bot_src_vel =
-                        Vec2::new(LEFT_START_ANGLE.cos(), LEFT_START_ANGLE.sin()) * f32::EPSILON;
-                    bot_dst_vel = update_left_bot_parameter(
-                        Vec2::new(LEFT_THROW_POS_X, LEFT_THROW_POS_Y),
-                        Vec2::new(RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y)
-                            + Vec2::from(right_collider.center) * Vec2::new(-1.0, 1.0),
-                        wind_vel,
+                    bot_aim = BotAim::new(
+                        Side::Left,
                         level,
+                        right_collider,
+                        wind_vel,
+                        &ruleset,
+                        &mut rng.bot,
                     );
 
                     game_state = GameState::LeftTurn;
-                    remaining_millis = MAX_CTRL_TIME;
+                    remaining_millis = ruleset.max_turn_time;
                     control = None;
                 }
             }
@@ -617,244 +606,291 @@ pub async fn play(
     #[cfg(not(feature = "no-debugging-log"))]
     println!("Game ended.");
 
-    match left_health.cmp(&right_health) {
+    // Spectators go back to the title once the match is over.
+    drop(broadcast);
+
+    // Players who are still loading the game go back to the title with the result recorded.
+    for (session, rejoin) in [
+        (&mut left, &mut left_rejoin),
+        (&mut right, &mut right_rejoin),
+    ] {
+        if let Some(player) = rejoin.take() {
+            let _ = player.tx.send(Packet::GameLoadTimeout);
+            *session = player;
+        }
+    }
+
+    let left_outcome = match left_health.cmp(&right_health) {
         std::cmp::Ordering::Less => {
             #[cfg(not(feature = "no-debugging-log"))]
             println!("Right player won!");
-
-            left.increase_lose();
-            let message = Packet::GameResult {
-                win: left.win(),
-                lose: left.lose(),
-                victory: false,
-            };
-            left = send_message(left, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = left.into_any().downcast();
-            if let Ok(player) = result {
-                let mut redis_conn_cloned = redis_conn.clone();
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn_cloned.hincr(&key, LOSSES_KEY, 1).await;
-                let result =
-                    record_game_result(&mut redis_conn_cloned, &player.uuid, 0, 1, 0).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn_cloned);
-            }
-
-            right.increase_win();
-            let message = Packet::GameResult {
-                win: right.win(),
-                lose: right.lose(),
-                victory: true,
-            };
-            right = send_message(right, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = right.into_any().downcast();
-            if let Ok(player) = result {
-                let mut redis_conn_cloned = redis_conn.clone();
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn_cloned.hincr(key, WINS_KEY, 1).await;
-                let result =
-                    record_game_result(&mut redis_conn_cloned, &player.uuid, 1, 0, 0).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn_cloned);
-            }
+            GameOutcome::Loss
         }
         std::cmp::Ordering::Equal => {
             #[cfg(not(feature = "no-debugging-log"))]
             println!("Draw!");
-
-            let message = Packet::GameResultDraw;
-            left.increase_draw();
-            left = send_message(left, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = left.into_any().downcast();
-            if let Ok(player) = result {
-                let mut redis_conn_cloned = redis_conn.clone();
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn_cloned.hincr(key, DRAWS_KEY, 1).await;
-                let result =
-                    record_game_result(&mut redis_conn_cloned, &player.uuid, 0, 0, 1).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn_cloned);
-            }
-
-            right.increase_draw();
-            right = send_message(right, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = right.into_any().downcast();
-            if let Ok(player) = result {
-                let mut redis_conn_cloned = redis_conn.clone();
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn_cloned.hincr(key, DRAWS_KEY, 1).await;
-                let result =
-                    record_game_result(&mut redis_conn_cloned, &player.uuid, 0, 0, 1).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn_cloned);
-            }
+            GameOutcome::Draw
         }
         std::cmp::Ordering::Greater => {
             #[cfg(not(feature = "no-debugging-log"))]
             println!("Left player won!");
+            GameOutcome::Win
+        }
+    };
 
-            left.increase_win();
-            let message = Packet::GameResult {
-                win: left.win(),
-                lose: left.lose(),
-                victory: true,
-            };
-            left = send_message(left, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = left.into_any().downcast();
-            if let Ok(player) = result {
-                let mut redis_conn_cloned = redis_conn.clone();
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn_cloned.hincr(key, WINS_KEY, 1).await;
-                let result =
-                    record_game_result(&mut redis_conn_cloned, &player.uuid, 1, 0, 0).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn_cloned);
-            }
+    left = send_game_result(left, left_outcome, &mut num_player);
+    right = send_game_result(right, left_outcome.opposite(), &mut num_player);
+
+    let duration_millis = started.elapsed().as_millis().min(u32::MAX as u128) as u32;
+    let left_info = (left.name().to_string(), left.hero());
+    let right_info = (right.name().to_string(), right.hero());
+    let replay = Replay {
+        seed,
+        tick_millis: get_config().tick_millis.min(u32::MAX as u64) as u32,
+        ruleset,
+        left: ReplayPlayer {
+            name: left_info.0.clone(),
+            hero: left_info.1,
+        },
+        right: ReplayPlayer {
+            name: right_info.0.clone(),
+            hero: right_info.1,
+        },
+        inputs,
+    };
 
-            right.increase_lose();
-            let message = Packet::GameResult {
-                win: right.win(),
-                lose: right.lose(),
-                victory: false,
-            };
-            right = send_message(right, &message, &mut num_player);
-            let result: Result<Box<Player>, Box<dyn Any + Send>> = right.into_any().downcast();
-            if let Ok(player) = result {
-                // let key = format!("user:{}", player.uuid);
-                // let result = redis_conn.hincr(key, LOSSES_KEY, 1).await;
-                let result = record_game_result(&mut redis_conn, &player.uuid, 0, 1, 0).await;
-                if let Err(e) = result {
-                    eprintln!("Redis Error: {e}");
-                    return;
-                }
-                next_state(State::Title, player, redis_conn);
-            }
+    // Only a game between two players moves both ratings.
+    // A player whose opponent is a bot at the end of the game is rated against the bot.
+    let mut left: Option<Box<Player>> = left.into_any().downcast().ok();
+    let mut right: Option<Box<Player>> = right.into_any().downcast().ok();
+    let season = current_season();
+    let health_count = replay.ruleset.health_count as usize;
+    let left_hits = (health_count - right_health) as u32;
+    let right_hits = (health_count - left_health) as u32;
+    let result = match (&mut left, &mut right) {
+        (Some(l), Some(r)) => {
+            let left_side = l.game_side(left_hits);
+            let right_side = r.game_side(right_hits);
+            store
+                .record_match_result(season, &left_side, &right_side, left_outcome)
+                .await
+                .map(|(left_rating, right_rating)| {
+                    l.rating = left_rating;
+                    r.rating = right_rating;
+                })
+        }
+        (Some(p), None) => store
+            .record_bot_game_result(season, &p.game_side(left_hits), left_outcome)
+            .await
+            .map(|rating| p.rating = rating),
+        (None, Some(p)) => {
+            let outcome = left_outcome.opposite();
+            store
+                .record_bot_game_result(season, &p.game_side(right_hits), outcome)
+                .await
+                .map(|rating| p.rating = rating)
         }
+        (None, None) => Ok(()),
+    };
+    if let Err(e) = result {
+        eprintln!("{e}");
+        return;
     }
-}
 
-fn update_wind_parameter() -> (u8, u8, Vec2) {
-    let wind_angle = rand::random_range(0..255);
-    let wind_power = rand::random_range(128..255);
+    // The replay is only kept for the players who can find it in their match history.
+    let mut replay_id = None;
+    if left.is_some() || right.is_some() {
+        let id = Uuid::new_v4();
+        match store.add_replay(&id, &replay.encode()).await {
+            Ok(()) => replay_id = Some(id),
+            Err(e) => eprintln!("{e}"),
+        }
+    }
+
+    let played_at = season::now();
+    let records = [
+        (
+            &left,
+            left_outcome,
+            left_hits,
+            right_hits,
+            &right_info,
+            right.is_none(),
+        ),
+        (
+            &right,
+            left_outcome.opposite(),
+            right_hits,
+            left_hits,
+            &left_info,
+            left.is_none(),
+        ),
+    ];
+    for (player, outcome, hits_dealt, hits_taken, opponent, opponent_is_bot) in records {
+        let Some(player) = player else { continue };
+        let record = MatchRecord {
+            played_at,
+            hero: player.hero,
+            opponent_name: opponent.0.clone(),
+            opponent_hero: opponent.1,
+            opponent_is_bot,
+            outcome: outcome.into(),
+            duration_millis,
+            hits_dealt,
+            hits_taken,
+            replay_id,
+            seed,
+        };
+        if let Err(e) = store.add_match_record(&player.uuid, &record).await {
+            eprintln!("{e}");
+            return;
+        }
+    }
 
-    let radian = (wind_angle as f32 / 255.0) * TAU;
-    let direction = Vec2::new(radian.cos(), radian.sin());
-    let power = (wind_power as f32 / 255.0) * WIND_POWER;
-    let wind_vel = direction * power;
+    match (left, right) {
+        (Some(left), Some(right)) if get_config().rematch_wait_time > 0 => {
+            rematch::regist(left, right);
+        }
+        (left, right) => {
+            for player in [left, right].into_iter().flatten() {
+                next_state(State::Title, player, store.clone());
+            }
+        }
+    }
+}
 
-    (wind_angle, wind_power, wind_vel)
+fn send_game_result(
+    mut session: Box<dyn Session>,
+    outcome: GameOutcome,
+    num_player: &mut usize,
+) -> Box<dyn Session> {
+    let message = match outcome {
+        GameOutcome::Win => {
+            session.increase_win();
+            Packet::GameResult {
+                win: session.win(),
+                lose: session.lose(),
+                draw: session.draw(),
+                victory: true,
+            }
+        }
+        GameOutcome::Loss => {
+            session.increase_lose();
+            Packet::GameResult {
+                win: session.win(),
+                lose: session.lose(),
+                draw: session.draw(),
+                victory: false,
+            }
+        }
+        GameOutcome::Draw => {
+            session.increase_draw();
+            Packet::GameResultDraw {
+                win: session.win(),
+                lose: session.lose(),
+                draw: session.draw(),
+            }
+        }
+    };
+    send_message(session, &message, num_player)
 }
 
-fn update_left_bot_parameter(
-    start_pos: Vec2,
-    target_pos: Vec2,
-    wind_vel: Vec2,
-    lv: BotLevel,
-) -> Vec2 {
-    let time_to_hit = rand::random_range(BOT_TIME_RANGE);
-    let dx = target_pos.x - start_pos.x;
-    let dy = target_pos.y - start_pos.y;
+/// Moves a player who reconnected to their seat into `rejoin`,
+/// or releases the seat once the grace time is over.
+/// Returns `true` if a player has just reconnected.
+fn take_reconnected(
+    mut session: Box<dyn Session>,
+    rejoin: &mut Option<Box<Player>>,
+    num_player: &mut usize,
+    instant: Instant,
+) -> (Box<dyn Session>, bool) {
+    let Some(seat) = session.seat() else {
+        return (session, false);
+    };
 
-    let vx = (dx / time_to_hit) - wind_vel.x;
-    let vy = (dy - (0.5 * GRAVITY * time_to_hit * time_to_hit)) / time_to_hit - wind_vel.y;
-    let vel = Vec2::new(vx, vy);
+    if let Some(player) = seat.try_take() {
+        #[cfg(not(feature = "no-debugging-log"))]
+        println!("{:?} reconnected to the seat of {:?}", player, session);
 
-    let length = vel.length();
-    let dir = vel / length; // Maybe safety...
+        if session.reader().is_some() {
+            // The new connection replaces the previous one.
+            session = replace_with_bot(session, num_player);
+        }
+        *rejoin = Some(player);
+        return (session, true);
+    }
 
-    let power = (length + lv.power_offset()).min(THROW_POWER);
-    let angle = (dir.to_angle() + lv.angle_offset()).clamp(LEFT_START_ANGLE, LEFT_END_ANGLE);
+    if rejoin.is_none() && seat.is_expired(instant) {
+        #[cfg(not(feature = "no-debugging-log"))]
+        println!("Seat of {:?} expired", session);
 
-    Vec2::new(angle.cos(), angle.sin()) * power
+        *num_player -= 1;
+        return (Box::new(Bot::from(session.as_ref())), false);
+    }
+    (session, false)
 }
 
-fn update_right_bot_parameter(
-    start_pos: Vec2,
-    target_pos: Vec2,
-    wind_vel: Vec2,
-    lv: BotLevel,
-) -> Vec2 {
-    let time_to_hit = rand::random_range(BOT_TIME_RANGE);
-    let dx = target_pos.x - start_pos.x;
-    let dy = target_pos.y - start_pos.y;
-
-    let vx = (dx / time_to_hit) - wind_vel.x;
-    let vy = (dy - (0.5 * GRAVITY * time_to_hit * time_to_hit)) / time_to_hit - wind_vel.y;
-    let vel = Vec2::new(vx, vy);
-
-    let length = vel.length();
-    let dir = vel / length; // Maybe safety...
+/// Puts a reconnected player back in place of the bot keeping their seat.
+fn return_seat(mut bot: Box<dyn Session>, mut player: Box<Player>) -> Box<dyn Session> {
+    player.seat = bot.take_seat();
+    if let Some(seat) = player.seat.as_mut() {
+        seat.occupy();
+    }
+    player
+}
 
-    let power = (length + lv.power_offset()).min(THROW_POWER);
-    let angle = (dir.to_angle() + lv.angle_offset()).clamp(RIGHT_START_ANGLE, RIGHT_END_ANGLE);
+/// Waits for a reconnected player to load the game.
+/// Returns the player once the game is loaded.
+fn poll_rejoin(rejoin: &mut Option<Box<Player>>) -> Option<Box<Player>> {
+    let player = rejoin.as_mut()?;
+    let mut cnt = MAX_LOOP;
+    while cnt > 0 {
+        match poll_stream_nonblocking(&mut player.read) {
+            StreamPollResult::Pending => break,
+            StreamPollResult::Item(message) => {
+                if let Message::Text(s) = message
+                    && let Ok(Packet::GameLoadSuccess) = serde_json::from_str::<Packet>(&s)
+                {
+                    #[cfg(not(feature = "no-debugging-log"))]
+                    println!("{:?} returned to the game", player);
 
-    Vec2::new(angle.cos(), angle.sin()) * power
+                    return rejoin.take();
+                }
+            }
+            StreamPollResult::Error(e) => {
+                println!("WebSocket disconnected ({:?}): {e}", player);
+                *rejoin = None;
+                break;
+            }
+            StreamPollResult::Closed => {
+                println!("WebSocket disconnected ({:?})", player);
+                *rejoin = None;
+                break;
+            }
+        }
+        cnt -= 1;
+    }
+    None
 }
 
-pub async fn record_game_result(
-    redis_conn: &mut MultiplexedConnection,
-    uuid: &Uuid,
-    win_inc: i32,
-    loss_inc: i32,
-    draw_inc: i32,
-) -> redis::RedisResult<()> {
-    let user_key = format!("user:{uuid}");
-
-    // Lua Script
-    // ARGV[1]: win_inc, ARGV[2]: loss_inc, ARGV[3]: draw_inc, ARGV[4]: expire_seconds
-    // KEYS[1]: user_key, KEYS[2]: leader_board_key
-    let script = Script::new(
-        r#"
-        --- Update Hash data (HINCRBY) ---
-        local win = redis.call('HINCRBY', KEYS[1], 'wins', ARGV[1])
-        local loss = redis.call('HINCRBY', KEYS[1], 'losses', ARGV[2])
-        local draw = redis.call('HINCRBY', KEYS[1], 'draws', ARGV[3])
-
-        --- Update expiration to long-term ---
-        redis.call('EXPIRE', KEYS[1], ARGV[4])
-
-        --- Calculate ranking score ---
-        local w = win
-        if win > 9999 then w = 9999 end
-        local l = loss
-        if loss > 9999 then l = 9999 end
-        local d = draw
-        if draw > 9999 then d = 9999 end
-
-        local inverted_loss = 9999 - l
-        local score = (w * 10000000000) + (inverted_loss * 100000) + d
-
-        --- Update Leader Board ---
-        redis.call('ZADD', KEYS[2], score, KEYS[1])
-
-        return score
-    "#,
-    );
+fn play_data(session: &mut Box<dyn Session>) -> PlayData {
+    PlayData {
+        uuid: session
+            .uuid()
+            .or_else(|| session.seat().map(|seat| seat.uuid())),
+        name: session.name().to_string(),
+        hero: session.hero(),
+        win: session.win(),
+        lose: session.lose(),
+        draw: session.draw(),
+    }
+}
 
-    let _: f64 = script
-        .key(&user_key)
-        .key(LEADER_BOARD_KEY)
-        .arg(win_inc)
-        .arg(loss_inc)
-        .arg(draw_inc)
-        .arg(EXPIRE_SECONDS)
-        .invoke_async(redis_conn)
-        .await?;
-
-    Ok(())
+fn update_wind_parameter(rng: &mut StdRng, rule: &WindRule) -> (u8, u8, Vec2) {
+    let (wind_angle, wind_power) = rule.pick(rng);
+    (
+        wind_angle,
+        wind_power,
+        rule.velocity(wind_angle, wind_power),
+    )
 }
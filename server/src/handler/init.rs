@@ -2,84 +2,119 @@ use super::*;
 
 const MAX_UUID_RETRIES: u32 = 10;
 
+/// Length of the random secret in a session token (bytes).
+const SECRET_LENGTH: usize = 32;
+
 pub async fn setup(
     addr: SocketAddr,
     ws_stream: WebSocketStream<TcpStream>,
-    mut redis_conn: MultiplexedConnection,
+    token: Option<String>,
+    store: SharedStore,
 ) {
     #[cfg(not(feature = "no-debugging-log"))]
     println!("Addr:{addr} - Current State: Init");
 
+    // --- Try to resume the previous session ---
+    let resumed = match token.as_deref().and_then(parse_token) {
+        Some((uuid, secret)) => match store.load_player(&uuid, secret).await {
+            Ok(record) => record.map(|record| (uuid, record, secret.to_string())),
+            Err(e) => {
+                eprintln!("{e}");
+                return;
+            }
+        },
+        None => None,
+    };
+
+    let (uuid, record, secret) = match resumed {
+        Some(resumed) => {
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!("Addr:{addr} - Session resumed (UUID:{})", resumed.0);
+            resumed
+        }
+        None => match create_player(addr, &store).await {
+            Some(created) => created,
+            None => return,
+        },
+    };
+
+    let player = Player::new(uuid, record, addr, ws_stream);
+    let token = format!("{uuid}.{secret}");
+    let result = player
+        .tx
+        .send(Packet::SessionToken { token })
+        .and_then(|_| {
+            player.tx.send(Packet::Connection(PlayData {
+                uuid: player.uuid(),
+                name: player.name().to_string(),
+                hero: player.hero(),
+                win: player.win(),
+                lose: player.lose(),
+                draw: player.draw(),
+            }))
+        });
+    if let Err(e) = result {
+        println!("WebSocket disconnected ({:?}): {}", player, e);
+        drop(player);
+        return;
+    }
+
+    // --- Return to the match if a bot is keeping the seat ---
+    match rejoin::return_to_seat(Box::new(player)) {
+        Ok(()) => {
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!("Addr:{addr} - Returning to the game (UUID:{uuid})");
+        }
+        Err(player) => next_state(State::Title, player, store),
+    }
+}
+
+async fn create_player(
+    addr: SocketAddr,
+    store: &SharedStore,
+) -> Option<(Uuid, PlayerRecord, String)> {
     let hero = rand::random();
     let prefix = get_name_table().choose(&mut rand::rng()).unwrap();
     let name = format!("{prefix} {hero}");
+    let record = PlayerRecord::new(name, hero);
+    let secret = generate_secret();
 
-    let mut final_uuid = None;
     // --- Try to create a new user with a unique UUID, with a limited number of retries ---
     for _ in 0..MAX_UUID_RETRIES {
         let uuid = Uuid::new_v4();
-        let key = format!("user:{uuid}");
-        let value = addr.to_string();
-
-        let is_created: bool = match redis_conn.hset_nx(&key, "ip", &value).await {
-            Ok(created) => created,
+        match store.create_player(&uuid, &addr, &record, &secret).await {
+            Ok(true) => {
+                return Some((uuid, record, secret)); // Successfully created.
+            }
+            Ok(false) => {
+                // If not created (collision), the loop continues to the next attempt.
+            }
             Err(e) => {
-                eprintln!("Redis Error on HSETNX: {e}");
+                eprintln!("{e}");
                 // In case of error, stop trying.
-                return;
-            }
-        };
-
-        if is_created {
-            // If the UUID is unique and 'ip' field is set, populate the rest of the user data.
-            let pipe_result: Result<(), redis::RedisError> = redis::pipe()
-                .hset(&key, NAME_KEY, &name)
-                .hset(&key, WINS_KEY, 0)
-                .hset(&key, LOSSES_KEY, 0)
-                .hset(&key, DRAWS_KEY, 0)
-                .expire(&key, INITIAL_EXPIRE_SECONDS)
-                .query_async(&mut redis_conn)
-                .await;
-
-            if let Err(e) = pipe_result {
-                eprintln!("Redis Error on user init pipe: {e}.");
-                // The key was created with hset_nx, but population failed.
-                // The partial record will expire eventually.
-                // We stop here to prevent having a half-initialized player.
-                return;
+                return None;
             }
-            final_uuid = Some(uuid);
-            break; // Successfully created, exit loop.
         }
-        // If not created (collision), the loop continues to the next attempt.
     }
 
-    let uuid = match final_uuid {
-        Some(u) => u,
-        None => {
-            // If we couldn't find a unique UUID after all retries, log it and drop the connection.
-            eprintln!(
-                "Failed to create a unique user for IP {} after {} retries.",
-                addr.ip(),
-                MAX_UUID_RETRIES
-            );
-            return;
-        }
-    };
+    // If we couldn't find a unique UUID after all retries, log it and drop the connection.
+    eprintln!(
+        "Failed to create a unique user for IP {} after {} retries.",
+        addr.ip(),
+        MAX_UUID_RETRIES
+    );
+    None
+}
 
-    let player = Player::new(uuid, hero, name, addr, ws_stream);
-    let result = player.tx.send(Packet::Connection(PlayData {
-        uuid: player.uuid(),
-        name: player.name().to_string(),
-        hero: player.hero(),
-        win: player.win(),
-        lose: player.lose(),
-    }));
-    if let Err(e) = result {
-        println!("WebSocket disconnected ({:?}): {}", player, e);
-        drop(player);
-        return;
-    }
+fn generate_secret() -> String {
+    (0..SECRET_LENGTH)
+        .map(|_| format!("{:02x}", rand::random::<u8>()))
+        .collect()
+}
 
-    next_state(State::Title, Box::new(player), redis_conn);
+/// Splits a session token (`{uuid}.{secret}`) into its parts.
+fn parse_token(token: &str) -> Option<(Uuid, &str)> {
+    let (uuid, secret) = token.split_once('.')?;
+    let uuid = Uuid::parse_str(uuid).ok()?;
+    (!secret.is_empty()).then_some((uuid, secret))
 }
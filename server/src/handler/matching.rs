@@ -1,6 +1,6 @@
 use super::*;
+use crate::matchmaking::{self, Candidate};
 
-const MAX_MATCHING_TIME: u16 = 10000; // 10 seconds
 const MAX_LOOP: usize = 100;
 
 static NEW: SegQueue<Node> = SegQueue::new();
@@ -16,22 +16,31 @@ impl Node {
         Self {
             player,
             previous_instant: Instant::now(),
-            millis: MAX_MATCHING_TIME,
+            millis: get_config().max_matching_time,
+        }
+    }
+
+    pub fn candidate(&self) -> Candidate {
+        Candidate {
+            uuid: self.player.uuid,
+            rating: self.player.rating,
+            wins: self.player.win,
+            losses: self.player.lose,
+            waited_millis: (get_config().max_matching_time - self.millis) as u32,
         }
     }
 }
 
-pub async fn update(redis_conn: MultiplexedConnection) {
+pub async fn update(store: SharedStore) {
     static COUNTER: AtomicUsize = AtomicUsize::new(0);
     let n = COUNTER.fetch_add(1, MemOrdering::AcqRel);
     assert!(n < 1, "This function must be called only once!");
-    update_internal(redis_conn).await;
+    update_internal(store).await;
 }
 
-async fn update_internal(redis_conn: MultiplexedConnection) {
-    const TICK: u64 = 1000 / 15;
-    const PERIOD: Duration = Duration::from_millis(TICK);
-    let mut interval = time::interval(PERIOD);
+async fn update_internal(store: SharedStore) {
+    let matchmaker = matchmaking::from_config(get_config());
+    let mut interval = time::interval(get_config().period());
     let mut nodes = VecDeque::new();
     let mut temp = VecDeque::new();
     loop {
@@ -61,9 +70,9 @@ async fn update_internal(redis_conn: MultiplexedConnection) {
                         {
                             match packet {
                                 Packet::TryCancelGame => {
-                                    let redis_conn_cloned = redis_conn.clone();
+                                    let store_cloned = store.clone();
                                     node.player.tx.send(Packet::CancelSuccess).unwrap();
-                                    next_state(State::Title, node.player, redis_conn_cloned);
+                                    next_state(State::Title, node.player, store_cloned);
                                     continue 'update; // Session is removed from matching.
                                 }
                                 _ => { /* empty */ }
@@ -90,16 +99,22 @@ async fn update_internal(redis_conn: MultiplexedConnection) {
         mem::swap(&mut nodes, &mut temp);
 
         // 3. Try to match sessions who are still in the queue.
-        while nodes.len() >= 2 {
-            let left = nodes.pop_front().unwrap().player;
-            let right = nodes.pop_front().unwrap().player;
+        let candidates: Vec<_> = nodes.iter().map(Node::candidate).collect();
+        let pairs = matchmaking::find_pairs(matchmaker.as_ref(), &candidates);
+        let mut waiting: Vec<_> = nodes.drain(..).map(Some).collect();
+        for (i, j) in pairs {
+            let mut left = waiting[i].take().unwrap().player;
+            let mut right = waiting[j].take().unwrap().player;
+            left.reserve_seat();
+            right.reserve_seat();
 
             #[cfg(not(feature = "no-debugging-log"))]
-            println!("[{:?} VS {:?}] - Queue Size: {}", left, right, nodes.len());
+            println!("[{:?} VS {:?}]", left, right);
 
-            let redis_conn_cloned = redis_conn.clone();
-            tokio::spawn(sync::wait(left, right, 2, redis_conn_cloned));
+            let store_cloned = store.clone();
+            tokio::spawn(sync::wait(left, right, 2, store_cloned));
         }
+        nodes.extend(waiting.into_iter().flatten());
 
         // 4. Update status for the remaining sessions.
         while let Some(mut node) = nodes.pop_front() {
@@ -110,6 +125,7 @@ async fn update_internal(redis_conn: MultiplexedConnection) {
             node.millis = node.millis.saturating_sub(elapsed as u16);
 
             if node.millis == 0 {
+                node.player.reserve_seat();
                 let (left, right): (Box<dyn Session>, Box<dyn Session>) = match rand::random() {
                     true => (Box::new(Bot::new()), node.player),
                     false => (node.player, Box::new(Bot::new())),
@@ -118,8 +134,8 @@ async fn update_internal(redis_conn: MultiplexedConnection) {
                 #[cfg(not(feature = "no-debugging-log"))]
                 println!("[{:?} VS {:?}] - Queue Size: {}", left, right, nodes.len());
 
-                let redis_conn_cloned = redis_conn.clone();
-                tokio::spawn(sync::wait(left, right, 1, redis_conn_cloned));
+                let store_cloned = store.clone();
+                tokio::spawn(sync::wait(left, right, 1, store_cloned));
                 continue;
             }
 
@@ -1,54 +1,61 @@
 pub mod in_game;
 pub mod init;
 pub mod matching;
+pub mod online;
 pub mod prepare;
+pub mod rejoin;
+pub mod rematch;
+pub mod room;
+pub mod spectate;
 pub mod sync;
 pub mod title;
 
 use std::{
     any::Any,
     collections::VecDeque,
-    f32::consts::{PI, TAU},
     fmt, mem,
     net::SocketAddr,
-    ops::RangeInclusive,
     sync::atomic::{AtomicUsize, Ordering as MemOrdering},
 };
 
 use crossbeam_queue::SegQueue;
 use futures_util::{
     SinkExt, StreamExt,
-    stream::{SplitSink, SplitStream},
+    stream::{Abortable, SplitSink, SplitStream},
 };
-use glam::{FloatExt, Vec2};
+use glam::Vec2;
 use protocol::{
-    COLLIDER_DATA, GRAVITY, Hero, LEFT_END_ANGLE, LEFT_PLAYER_POS_X, LEFT_PLAYER_POS_Y,
-    LEFT_START_ANGLE, LEFT_THROW_POS_X, LEFT_THROW_POS_Y, MAX_CTRL_TIME, MAX_HEALTH_COUNT,
-    MAX_PLAY_TIME, MAX_POINT, PROJECTILE_SIZE, Packet, PlayData, RIGHT_END_ANGLE,
-    RIGHT_PLAYER_POS_X, RIGHT_PLAYER_POS_Y, RIGHT_START_ANGLE, RIGHT_THROW_POS_X,
-    RIGHT_THROW_POS_Y, RankItem, THROW_END_TIME, THROW_POWER, WIND_POWER, WORLD_MAX_X, WORLD_MIN_X,
-    rand, serde_json, uuid::Uuid,
+    BotAim, BotLevel, COLLIDER_DATA, Hero, MAX_POINT, MatchRecord, MatchRng, Packet, PlayData,
+    Projectile, Replay, ReplayAction, ReplayInput, ReplayPlayer, Ruleset, Side, THROW_END_TIME,
+    TurnState, WindRule, rand, serde_json, uuid::Uuid,
 };
-use rand::seq::IndexedRandom;
-use redis::{AsyncTypedCommands, Script, aio::MultiplexedConnection};
+use rand::{Rng, rngs::StdRng, seq::IndexedRandom};
 use tokio::{
     net::TcpStream,
     sync::mpsc::{UnboundedSender, unbounded_channel},
     task::JoinHandle,
-    time::{self, Duration, Instant},
+    time::{self, Instant},
 };
 use tokio_tungstenite::{WebSocketStream, tungstenite::Message};
 
 use crate::{
-    DRAWS_KEY, EXPIRE_SECONDS, INITIAL_EXPIRE_SECONDS, LEADER_BOARD_KEY, LOSSES_KEY, NAME_KEY,
-    WINS_KEY, get_name_table,
+    config::get_config,
+    get_name_table,
+    season::{self, current_season},
+    store::{GameOutcome, GameSide, PlayerRecord, SharedStore, StoreResult},
     stream::{StreamPollResult, poll_stream_nonblocking},
 };
 
+use self::{online::Connection, rejoin::Seat};
+
+/// Messages from the WebSocket of a player, which end once a newer connection of the user opens.
+pub type Reader = Abortable<SplitStream<WebSocketStream<TcpStream>>>;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum State {
     Title,
     Matching,
+    Room,
 }
 
 pub trait Session: fmt::Debug + Send + Sync {
@@ -57,11 +64,14 @@ pub trait Session: fmt::Debug + Send + Sync {
     fn hero(&self) -> Hero;
     fn win(&self) -> u16;
     fn lose(&self) -> u16;
+    fn draw(&self) -> u16;
     fn increase_win(&mut self);
     fn increase_lose(&mut self);
     fn increase_draw(&mut self);
-    fn reader(&mut self) -> Option<&mut SplitStream<WebSocketStream<TcpStream>>>;
+    fn reader(&mut self) -> Option<&mut Reader>;
     fn sender(&self) -> Option<&UnboundedSender<Packet>>;
+    fn seat(&mut self) -> Option<&mut Seat>;
+    fn take_seat(&mut self) -> Option<Seat>;
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
 }
 
@@ -69,25 +79,28 @@ pub struct Player {
     uuid: Uuid,
     name: String,
     hero: Hero,
+    rating: i32,
     win: u16,
     lose: u16,
     draw: u16,
     addr: SocketAddr,
-    read: SplitStream<WebSocketStream<TcpStream>>,
+    read: Reader,
     tx: UnboundedSender<Packet>,
     write_task: JoinHandle<SplitSink<WebSocketStream<TcpStream>, Message>>,
+    seat: Option<Seat>,
+    _connection: Connection,
 }
 
 impl Player {
     pub fn new(
         uuid: Uuid,
-        hero: Hero,
-        name: String,
+        record: PlayerRecord,
         addr: SocketAddr,
         ws_stream: WebSocketStream<TcpStream>,
     ) -> Self {
         let (tx, mut rx) = unbounded_channel::<Packet>();
         let (mut write, read) = ws_stream.split();
+        let (connection, registration) = Connection::open(uuid);
         let write_task = tokio::spawn(async move {
             while let Some(s) = rx.recv().await {
                 let s = serde_json::to_string(&s).unwrap();
@@ -102,15 +115,34 @@ impl Player {
 
         Self {
             uuid,
-            name,
-            hero,
-            win: 0,
-            lose: 0,
-            draw: 0,
+            name: record.name,
+            hero: record.hero,
+            rating: record.rating,
+            win: record.wins,
+            lose: record.losses,
+            draw: record.draws,
             addr,
-            read,
+            read: Abortable::new(read, registration),
             tx,
             write_task,
+            seat: None,
+            _connection: connection,
+        }
+    }
+
+    /// Reserves the seat of a player entering a match, so that they can reconnect to it.
+    pub fn reserve_seat(&mut self) {
+        if get_config().reconnect_grace_time > 0 {
+            self.seat = Some(Seat::reserve(self.uuid));
+        }
+    }
+
+    /// Describes the side of the player in a finished game.
+    pub fn game_side(&self, hits: u32) -> GameSide {
+        GameSide {
+            uuid: self.uuid,
+            hero: self.hero,
+            hits,
         }
     }
 }
@@ -148,6 +180,10 @@ impl Session for Player {
         self.lose
     }
 
+    fn draw(&self) -> u16 {
+        self.draw
+    }
+
     fn increase_win(&mut self) {
         self.win = (self.win + 1).min(MAX_POINT);
     }
@@ -160,7 +196,7 @@ impl Session for Player {
         self.draw = (self.draw + 1).min(MAX_POINT);
     }
 
-    fn reader(&mut self) -> Option<&mut SplitStream<WebSocketStream<TcpStream>>> {
+    fn reader(&mut self) -> Option<&mut Reader> {
         Some(&mut self.read)
     }
 
@@ -168,6 +204,14 @@ impl Session for Player {
         Some(&self.tx)
     }
 
+    fn seat(&mut self) -> Option<&mut Seat> {
+        self.seat.as_mut()
+    }
+
+    fn take_seat(&mut self) -> Option<Seat> {
+        self.seat.take()
+    }
+
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
         self
     }
@@ -178,6 +222,8 @@ pub struct Bot {
     hero: Hero,
     win: u16,
     lose: u16,
+    draw: u16,
+    seat: Option<Seat>,
 }
 
 impl Bot {
@@ -190,18 +236,14 @@ impl Bot {
             hero,
             win: rand::random_range(0..=3),
             lose: rand::random_range(0..=3),
+            draw: rand::random_range(0..=1),
+            seat: None,
         }
     }
-}
 
-impl From<Box<dyn Session>> for Bot {
-    fn from(value: Box<dyn Session>) -> Self {
-        Self {
-            name: value.name().to_string(),
-            hero: value.hero(),
-            win: value.win(),
-            lose: value.lose(),
-        }
+    pub fn with_seat(mut self, seat: Seat) -> Self {
+        self.seat = Some(seat);
+        self
     }
 }
 
@@ -212,6 +254,8 @@ impl From<&dyn Session> for Bot {
             hero: value.hero(),
             win: value.win(),
             lose: value.lose(),
+            draw: value.draw(),
+            seat: None,
         }
     }
 }
@@ -243,6 +287,10 @@ impl Session for Bot {
         self.lose
     }
 
+    fn draw(&self) -> u16 {
+        self.draw
+    }
+
     fn increase_win(&mut self) {
         /* empty */
     }
@@ -255,7 +303,7 @@ impl Session for Bot {
         /* empty */
     }
 
-    fn reader(&mut self) -> Option<&mut SplitStream<WebSocketStream<TcpStream>>> {
+    fn reader(&mut self) -> Option<&mut Reader> {
         None
     }
 
@@ -263,18 +311,44 @@ impl Session for Bot {
         None
     }
 
+    fn seat(&mut self) -> Option<&mut Seat> {
+        self.seat.as_mut()
+    }
+
+    fn take_seat(&mut self) -> Option<Seat> {
+        self.seat.take()
+    }
+
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> {
         self
     }
 }
 
-fn next_state(state: State, player: Box<Player>, redis_conn: MultiplexedConnection) {
+fn next_state(state: State, mut player: Box<Player>, store: SharedStore) {
+    player.seat = None; // The player has left the match.
     match state {
-        State::Title => tokio::spawn(title::update(player, redis_conn)),
+        State::Title => tokio::spawn(title::update(player, store)),
         State::Matching => tokio::spawn(matching::regist(player)),
+        State::Room => tokio::spawn(room::regist(player)),
     };
 }
 
+/// Replaces a disconnected player with a bot.
+/// The bot keeps the player's seat during the reconnect grace time,
+/// so the player is still counted in `num_player` until the seat expires.
+fn replace_with_bot(mut session: Box<dyn Session>, num_player: &mut usize) -> Box<dyn Session> {
+    match session.take_seat() {
+        Some(mut seat) => {
+            seat.vacate();
+            Box::new(Bot::from(session.as_ref()).with_seat(seat))
+        }
+        None => {
+            *num_player -= 1;
+            Box::new(Bot::from(session.as_ref()))
+        }
+    }
+}
+
 fn send_message(
     session: Box<dyn Session>,
     message: &Packet,
@@ -285,8 +359,7 @@ fn send_message(
             Ok(_) => session,
             Err(_) => {
                 println!("WebSocket disconnected ({:?})", session);
-                *num_player -= 1;
-                Box::new(Bot::from(session))
+                replace_with_bot(session, num_player)
             }
         },
         None => session,
@@ -0,0 +1,53 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        LazyLock, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use futures_util::stream::{AbortHandle, AbortRegistration};
+
+use super::*;
+
+/// Connections of the online users, by user UUID.
+static CONNECTIONS: LazyLock<Mutex<HashMap<Uuid, (u64, AbortHandle)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Connection of an online user.
+/// A user has at most one connection: connecting again closes the previous one,
+/// and its player leaves the server as if they had disconnected.
+pub struct Connection {
+    uuid: Uuid,
+    id: u64,
+}
+
+impl Connection {
+    /// Registers a new connection of the user and closes the previous one.
+    /// The messages of the connection must be read through the returned registration.
+    pub fn open(uuid: Uuid) -> (Self, AbortRegistration) {
+        let (handle, registration) = AbortHandle::new_pair();
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let previous = CONNECTIONS.lock().unwrap().insert(uuid, (id, handle));
+        if let Some((_, handle)) = previous {
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!("Closing the previous connection (UUID:{uuid})");
+            handle.abort();
+        }
+        (Self { uuid, id }, registration)
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // Remove the entry only if it still belongs to this connection.
+        let mut connections = CONNECTIONS.lock().unwrap();
+        if connections
+            .get(&self.uuid)
+            .is_some_and(|(id, _)| *id == self.id)
+        {
+            connections.remove(&self.uuid);
+        }
+    }
+}
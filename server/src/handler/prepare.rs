@@ -1,6 +1,5 @@
 use super::*;
 
-const MAX_WAIT_TIME: u32 = 5_000;
 const MAX_LOOP: usize = 100;
 
 struct Node {
@@ -18,7 +17,7 @@ pub async fn wait(
     mut left: Box<dyn Session>,
     mut right: Box<dyn Session>,
     mut num_player: usize,
-    redis_conn: MultiplexedConnection,
+    store: SharedStore,
 ) {
     let message = Packet::PrepareInGame;
     left = send_message(left, &message, &mut num_player);
@@ -32,11 +31,9 @@ pub async fn wait(
     let mut wait_sessions = vec![Node::new(left, true), Node::new(right, false)];
     let mut temp = Vec::new();
 
-    const TICK: u64 = 1_000 / 15;
-    const PERIOD: Duration = Duration::from_millis(TICK);
     let mut previous_instant = Instant::now();
-    let mut interval = time::interval(PERIOD);
-    let mut millis = MAX_WAIT_TIME;
+    let mut interval = time::interval(get_config().period());
+    let mut millis = get_config().max_prepare_wait_time;
     while millis > 0 {
         let instant = interval.tick().await;
         let elapsed = instant
@@ -59,9 +56,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 wait_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
 
                                 continue 'update; // Player is removed due to error.
                             }
@@ -71,9 +67,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 wait_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
 
                                 continue 'update; // Player is removed due to closure.
                             }
@@ -109,6 +104,6 @@ pub async fn wait(
             (n1.session, n0.session)
         };
 
-        tokio::spawn(in_game::play(left, right, num_player, redis_conn));
+        tokio::spawn(in_game::play(left, right, num_player, store));
     }
 }
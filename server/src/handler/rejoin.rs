@@ -0,0 +1,86 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use tokio::sync::mpsc::{UnboundedReceiver, error::TryRecvError};
+
+use super::*;
+
+/// Seats of disconnected players that are still in a match, by user UUID.
+static SEATS: LazyLock<Mutex<HashMap<Uuid, UnboundedSender<Box<Player>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Seat of a player in a match.
+/// A player who reconnects with the same UUID is sent to the seat, replacing the previous
+/// connection. Once the player disconnects, the bot playing in their place keeps the seat
+/// until the reconnect grace time is over.
+pub struct Seat {
+    uuid: Uuid,
+    deadline: Option<Instant>,
+    rx: UnboundedReceiver<Box<Player>>,
+}
+
+impl Seat {
+    pub fn reserve(uuid: Uuid) -> Self {
+        let (tx, rx) = unbounded_channel();
+        SEATS.lock().unwrap().insert(uuid, tx);
+        Self {
+            uuid,
+            deadline: None,
+            rx,
+        }
+    }
+
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    /// Starts the reconnect grace time.
+    pub fn vacate(&mut self) {
+        let grace_time = time::Duration::from_millis(get_config().reconnect_grace_time as u64);
+        self.deadline = Some(Instant::now() + grace_time);
+    }
+
+    /// Stops the reconnect grace time.
+    pub fn occupy(&mut self) {
+        self.deadline = None;
+    }
+
+    pub fn is_expired(&self, instant: Instant) -> bool {
+        self.deadline.is_some_and(|deadline| instant >= deadline)
+    }
+
+    /// Returns the most recently reconnected player, if any.
+    pub fn try_take(&mut self) -> Option<Box<Player>> {
+        let mut player = None;
+        loop {
+            match self.rx.try_recv() {
+                Ok(p) => player = Some(p),
+                Err(TryRecvError::Empty | TryRecvError::Disconnected) => return player,
+            }
+        }
+    }
+}
+
+impl Drop for Seat {
+    fn drop(&mut self) {
+        self.rx.close();
+
+        // Remove the entry only if it still belongs to this seat.
+        let mut seats = SEATS.lock().unwrap();
+        if seats.get(&self.uuid).is_some_and(|tx| tx.is_closed()) {
+            seats.remove(&self.uuid);
+        }
+    }
+}
+
+/// Sends a reconnected player back to their seat.
+/// Returns the player if they are not in a match.
+pub fn return_to_seat(player: Box<Player>) -> Result<(), Box<Player>> {
+    let tx = SEATS.lock().unwrap().get(&player.uuid).cloned();
+    match tx {
+        Some(tx) => tx.send(player).map_err(|e| e.0),
+        None => Err(player),
+    }
+}
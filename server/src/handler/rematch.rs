@@ -0,0 +1,146 @@
+use tokio::time::Duration;
+
+use super::*;
+
+const MAX_LOOP: usize = 100;
+
+static QUEUE: SegQueue<(Box<Player>, Box<Player>)> = SegQueue::new();
+
+/// Players of a finished game who have not answered the rematch offer yet.
+struct Rematch {
+    left: Option<Box<Player>>,
+    right: Option<Box<Player>>,
+    accepted: (bool, bool),
+    deadline: Instant,
+}
+
+pub async fn update(store: SharedStore) {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, MemOrdering::AcqRel);
+    assert!(n < 1, "This function must be called only once!");
+    update_internal(store).await;
+}
+
+async fn update_internal(store: SharedStore) {
+    let mut interval = time::interval(get_config().period());
+    let mut rematches: Vec<Rematch> = Vec::new();
+    loop {
+        let instant = interval.tick().await;
+
+        while let Some((left, right)) = QUEUE.pop() {
+            rematches.push(Rematch {
+                left: Some(left),
+                right: Some(right),
+                accepted: (false, false),
+                deadline: instant + Duration::from_millis(get_config().rematch_wait_time as u64),
+            });
+        }
+
+        for mut r in mem::take(&mut rematches) {
+            let waiting = poll_answer(&mut r.left, &mut r.accepted.0, r.right.as_deref(), &store)
+                && poll_answer(&mut r.right, &mut r.accepted.1, r.left.as_deref(), &store);
+
+            if waiting && r.accepted == (true, true) {
+                let (mut left, mut right) = (r.left.unwrap(), r.right.unwrap());
+                left.reserve_seat();
+                right.reserve_seat();
+
+                // The players swap sides in the rematch.
+                #[cfg(not(feature = "no-debugging-log"))]
+                println!("[{:?} VS {:?}] - Rematch", right, left);
+
+                tokio::spawn(sync::wait(right, left, 2, store.clone()));
+                continue;
+            }
+
+            if waiting && instant < r.deadline {
+                rematches.push(r);
+                continue;
+            }
+
+            // The rematch is off. Whoever is still waiting goes back to the title.
+            for player in [r.left, r.right].into_iter().flatten() {
+                let result = player.tx.send(Packet::RematchDeclined);
+                if let Err(e) = result {
+                    println!("WebSocket disconnected ({:?}): {e}", player);
+                    continue;
+                }
+                next_state(State::Title, player, store.clone());
+            }
+        }
+    }
+}
+
+/// Reads the answer of a player and lets the opponent know about an offer.
+/// Returns `false` if the player has declined or left, which calls off the rematch.
+///
+/// Polling stops once the player leaves, so the packets they send from the title are not lost.
+fn poll_answer(
+    player: &mut Option<Box<Player>>,
+    accepted: &mut bool,
+    opponent: Option<&Player>,
+    store: &SharedStore,
+) -> bool {
+    let Some(p) = player.as_mut() else {
+        return false;
+    };
+
+    let mut cnt = MAX_LOOP;
+    while cnt > 0 {
+        match poll_stream_nonblocking(&mut p.read) {
+            StreamPollResult::Pending => break,
+            StreamPollResult::Item(message) => {
+                if let Message::Text(s) = message
+                    && let Ok(packet) = serde_json::from_str::<Packet>(&s)
+                {
+                    match packet {
+                        Packet::RematchOffer if !*accepted => {
+                            *accepted = true;
+                            // A disconnected opponent is found when their stream is polled.
+                            if let Some(opponent) = opponent
+                                && let Err(e) = opponent.tx.send(Packet::RematchOffered)
+                            {
+                                println!("WebSocket disconnected ({:?}): {e}", opponent);
+                            }
+                        }
+                        Packet::RematchDecline => {
+                            next_state(State::Title, player.take().unwrap(), store.clone());
+                            return false;
+                        }
+                        Packet::RematchOffer => { /* empty */ }
+                        // The player has left the result screen without declining.
+                        packet => {
+                            let player = player.take().unwrap();
+                            tokio::spawn(title::resume(player, packet, store.clone()));
+                            return false;
+                        }
+                    }
+                }
+            }
+            StreamPollResult::Error(e) => {
+                println!("WebSocket disconnected ({:?}): {e}", p);
+                *player = None;
+                return false;
+            }
+            StreamPollResult::Closed => {
+                println!("WebSocket disconnected ({:?})", p);
+                *player = None;
+                return false;
+            }
+        }
+        cnt -= 1;
+    }
+    true
+}
+
+/// Offers a rematch to the players of a finished game.
+pub fn regist(mut left: Box<Player>, mut right: Box<Player>) {
+    // The seats belong to the finished game.
+    left.seat = None;
+    right.seat = None;
+
+    #[cfg(not(feature = "no-debugging-log"))]
+    println!("[{:?} VS {:?}] - Waiting for a rematch", left, right);
+
+    QUEUE.push((left, right));
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use protocol::ROOM_CODE_LENGTH;
+
+use super::*;
+
+const MAX_LOOP: usize = 100;
+
+/// Characters of an invite code. Letters and digits that look alike are left out.
+const CODE_CHARS: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+static NEW: SegQueue<Box<Player>> = SegQueue::new();
+static JOIN: SegQueue<(Box<Player>, String)> = SegQueue::new();
+
+pub async fn update(store: SharedStore) {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let n = COUNTER.fetch_add(1, MemOrdering::AcqRel);
+    assert!(n < 1, "This function must be called only once!");
+    update_internal(store).await;
+}
+
+async fn update_internal(store: SharedStore) {
+    let mut interval = time::interval(get_config().period());
+    let mut rooms: HashMap<String, Box<Player>> = HashMap::new();
+    loop {
+        interval.tick().await;
+
+        // 1. Open a room for each new host.
+        while let Some(host) = NEW.pop() {
+            let code = new_code(&rooms);
+            let result = host.tx.send(Packet::RoomCreated { code: code.clone() });
+            if let Err(e) = result {
+                println!("WebSocket disconnected ({:?}): {e}", host);
+                continue;
+            }
+
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!(
+                "Room Created ({:?}) - Code: {code}, Rooms: {}",
+                host,
+                rooms.len() + 1
+            );
+            rooms.insert(code, host);
+        }
+
+        // 2. Poll events for all hosts before letting guests in.
+        // This ensures that nobody joins a room that has just been closed.
+        'update: for (code, mut host) in mem::take(&mut rooms) {
+            let mut cnt = MAX_LOOP;
+            while cnt > 0 {
+                match poll_stream_nonblocking(&mut host.read) {
+                    StreamPollResult::Pending => break,
+                    StreamPollResult::Item(message) => {
+                        if let Message::Text(s) = message
+                            && let Ok(packet) = serde_json::from_str::<Packet>(&s)
+                        {
+                            match packet {
+                                Packet::TryCancelGame => {
+                                    let result = host.tx.send(Packet::CancelSuccess);
+                                    if let Err(e) = result {
+                                        println!("WebSocket disconnected ({:?}): {e}", host);
+                                        continue 'update; // Room is closed due to error.
+                                    }
+                                    next_state(State::Title, host, store.clone());
+                                    continue 'update; // Room is closed by the host.
+                                }
+                                _ => { /* empty */ }
+                            }
+                        }
+                    }
+                    StreamPollResult::Error(e) => {
+                        println!("WebSocket disconnected ({:?}): {e}", host);
+                        continue 'update; // Room is closed due to error.
+                    }
+                    StreamPollResult::Closed => {
+                        println!("WebSocket disconnected ({:?})", host);
+                        continue 'update; // Room is closed due to closure.
+                    }
+                }
+                cnt -= 1;
+            }
+            rooms.insert(code, host);
+        }
+
+        // 3. Start a match for each guest who knows the code of an open room.
+        // Rooms never fall back to a bot, so the host waits until someone joins.
+        while let Some((mut guest, code)) = JOIN.pop() {
+            let is_open = rooms.get(&code).is_some_and(|host| host.uuid != guest.uuid);
+            if !is_open {
+                let result = guest.tx.send(Packet::RoomNotFound);
+                if let Err(e) = result {
+                    println!("WebSocket disconnected ({:?}): {e}", guest);
+                    continue;
+                }
+                next_state(State::Title, guest, store.clone());
+                continue;
+            }
+
+            let mut host = rooms.remove(&code).unwrap();
+            host.reserve_seat();
+            guest.reserve_seat();
+            let (left, right) = match rand::random() {
+                true => (host, guest),
+                false => (guest, host),
+            };
+
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!("[{:?} VS {:?}] - Room: {code}", left, right);
+
+            tokio::spawn(sync::wait(left, right, 2, store.clone()));
+        }
+    }
+}
+
+/// Returns a code that no open room is using.
+fn new_code(rooms: &HashMap<String, Box<Player>>) -> String {
+    let mut rng = rand::rng();
+    loop {
+        let code: String = (0..ROOM_CODE_LENGTH)
+            .map(|_| *CODE_CHARS.choose(&mut rng).unwrap() as char)
+            .collect();
+        if !rooms.contains_key(&code) {
+            return code;
+        }
+    }
+}
+
+pub async fn regist(player: Box<Player>) {
+    #[cfg(not(feature = "no-debugging-log"))]
+    println!("{:?} - Current State: Room", player);
+    NEW.push(player);
+}
+
+/// Sends the player to the room with the code.
+/// Codes are not case-sensitive.
+pub fn join(player: Box<Player>, code: &str) {
+    #[cfg(not(feature = "no-debugging-log"))]
+    println!("{:?} - Joining Room: {code}", player);
+    JOIN.push((player, code.trim().to_uppercase()));
+}
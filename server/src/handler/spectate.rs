@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    sync::{LazyLock, Mutex, atomic::AtomicU64},
+};
+
+use protocol::{LIVE_MATCH_LIST_SIZE, LiveMatch};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::*;
+
+const MAX_LOOP: usize = 100;
+
+/// A match in progress and the sender that passes the players who asked to watch to it.
+type Entry = (LiveMatch, UnboundedSender<Box<Player>>);
+
+/// Matches in progress, by match id.
+static MATCHES: LazyLock<Mutex<BTreeMap<u64, Entry>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// What a spectator has sent since the last poll.
+enum SpectatorEvent {
+    None,
+    Loaded,
+    Stopped,
+    Disconnected,
+}
+
+/// Spectators of a match in progress.
+/// The match is listed while this is alive. Once dropped, the spectators go back to the title.
+pub struct Broadcast {
+    id: u64,
+    left: PlayData,
+    right: PlayData,
+    ruleset: Ruleset,
+    rx: UnboundedReceiver<Box<Player>>,
+    #[allow(clippy::vec_box)] // Players are passed between states in boxes.
+    loading: Vec<Box<Player>>,
+    #[allow(clippy::vec_box)]
+    spectators: Vec<Box<Player>>,
+    store: SharedStore,
+}
+
+impl Broadcast {
+    pub fn open(left: PlayData, right: PlayData, ruleset: Ruleset, store: SharedStore) -> Self {
+        let id = NEXT_ID.fetch_add(1, MemOrdering::Relaxed);
+        let (tx, rx) = unbounded_channel();
+        let live_match = LiveMatch {
+            id,
+            left: left.clone(),
+            right: right.clone(),
+        };
+        MATCHES.lock().unwrap().insert(id, (live_match, tx));
+
+        Self {
+            id,
+            left,
+            right,
+            ruleset,
+            rx,
+            loading: Vec::new(),
+            spectators: Vec::new(),
+            store,
+        }
+    }
+
+    /// Sends a packet of the match to all spectators who have loaded the game.
+    pub fn send(&mut self, packet: &Packet) {
+        self.spectators
+            .retain(|player| match player.tx.send(packet.clone()) {
+                Ok(_) => true,
+                Err(e) => {
+                    println!("WebSocket disconnected ({:?}): {e}", player);
+                    false
+                }
+            });
+    }
+
+    /// Lets in the players who asked to watch and polls the spectators.
+    /// `resync` describes the current state of the match and is sent once a spectator has
+    /// loaded the game, like to a player returning to their seat.
+    pub fn update(&mut self, resync: &Packet) {
+        while let Ok(player) = self.rx.try_recv() {
+            let message = Packet::WatchStarted {
+                left: self.left.clone(),
+                right: self.right.clone(),
+                ruleset: Box::new(self.ruleset.clone()),
+            };
+            if let Err(e) = player.tx.send(message) {
+                println!("WebSocket disconnected ({:?}): {e}", player);
+                continue;
+            }
+
+            #[cfg(not(feature = "no-debugging-log"))]
+            println!(
+                "{:?} - Current State: Spectate (Match: {})",
+                player, self.id
+            );
+
+            self.loading.push(player);
+        }
+
+        for mut player in mem::take(&mut self.loading) {
+            match poll_spectator(&mut player) {
+                SpectatorEvent::None => self.loading.push(player),
+                SpectatorEvent::Loaded => match player.tx.send(resync.clone()) {
+                    Ok(_) => self.spectators.push(player),
+                    Err(e) => println!("WebSocket disconnected ({:?}): {e}", player),
+                },
+                SpectatorEvent::Stopped => next_state(State::Title, player, self.store.clone()),
+                SpectatorEvent::Disconnected => { /* empty */ }
+            }
+        }
+
+        for mut player in mem::take(&mut self.spectators) {
+            match poll_spectator(&mut player) {
+                SpectatorEvent::None | SpectatorEvent::Loaded => self.spectators.push(player),
+                SpectatorEvent::Stopped => next_state(State::Title, player, self.store.clone()),
+                SpectatorEvent::Disconnected => { /* empty */ }
+            }
+        }
+    }
+}
+
+impl Drop for Broadcast {
+    fn drop(&mut self) {
+        MATCHES.lock().unwrap().remove(&self.id);
+
+        // Players who asked to watch just before the match ended are sent back as well.
+        self.rx.close();
+        while let Ok(player) = self.rx.try_recv() {
+            self.loading.push(player);
+        }
+
+        let loading = mem::take(&mut self.loading);
+        for player in loading.into_iter().chain(self.spectators.drain(..)) {
+            if let Err(e) = player.tx.send(Packet::WatchEnded) {
+                println!("WebSocket disconnected ({:?}): {e}", player);
+                continue;
+            }
+            next_state(State::Title, player, self.store.clone());
+        }
+    }
+}
+
+fn poll_spectator(player: &mut Player) -> SpectatorEvent {
+    let mut event = SpectatorEvent::None;
+    let mut cnt = MAX_LOOP;
+    while cnt > 0 {
+        match poll_stream_nonblocking(&mut player.read) {
+            StreamPollResult::Pending => break,
+            StreamPollResult::Item(message) => {
+                if let Message::Text(s) = message
+                    && let Ok(packet) = serde_json::from_str::<Packet>(&s)
+                {
+                    match packet {
+                        Packet::GameLoadSuccess => event = SpectatorEvent::Loaded,
+                        Packet::StopWatching => return SpectatorEvent::Stopped,
+                        _ => { /* empty */ }
+                    }
+                }
+            }
+            StreamPollResult::Error(e) => {
+                println!("WebSocket disconnected ({:?}): {e}", player);
+                return SpectatorEvent::Disconnected;
+            }
+            StreamPollResult::Closed => {
+                println!("WebSocket disconnected ({:?})", player);
+                return SpectatorEvent::Disconnected;
+            }
+        }
+        cnt -= 1;
+    }
+    event
+}
+
+/// Returns the most recent matches in progress.
+pub fn live_matches() -> Vec<LiveMatch> {
+    MATCHES
+        .lock()
+        .unwrap()
+        .values()
+        .rev()
+        .take(LIVE_MATCH_LIST_SIZE)
+        .map(|(live_match, _)| live_match.clone())
+        .collect()
+}
+
+/// Sends the player to the match with the id.
+/// Returns the player if the match is over.
+pub fn watch(player: Box<Player>, id: u64) -> Result<(), Box<Player>> {
+    let tx = MATCHES.lock().unwrap().get(&id).map(|(_, tx)| tx.clone());
+    match tx {
+        Some(tx) => tx.send(player).map_err(|e| e.0),
+        None => Err(player),
+    }
+}
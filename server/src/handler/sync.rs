@@ -1,6 +1,5 @@
 use super::*;
 
-const MAX_WAIT_TIME: u32 = 15_000;
 const MAX_LOOP: usize = 100;
 
 struct Node {
@@ -18,7 +17,7 @@ pub async fn wait(
     mut left: Box<dyn Session>,
     mut right: Box<dyn Session>,
     mut num_player: usize,
-    redis_conn: MultiplexedConnection,
+    store: SharedStore,
 ) {
     let message = Packet::MatchingSuccess {
         left: PlayData {
@@ -27,6 +26,7 @@ pub async fn wait(
             hero: left.hero(),
             win: left.win(),
             lose: left.lose(),
+            draw: left.draw(),
         },
         right: PlayData {
             uuid: right.uuid(),
@@ -34,7 +34,9 @@ pub async fn wait(
             hero: right.hero(),
             win: right.win(),
             lose: right.lose(),
+            draw: right.draw(),
         },
+        ruleset: Box::new(get_config().ruleset.clone()),
     };
     left = send_message(left, &message, &mut num_player);
     right = send_message(right, &message, &mut num_player);
@@ -48,11 +50,9 @@ pub async fn wait(
     let mut loaded_sessions: Vec<Node> = Vec::new();
     let mut temp = Vec::new();
 
-    const TICK: u64 = 1_000 / 15;
-    const PERIOD: Duration = Duration::from_millis(TICK);
     let mut previous_instant = Instant::now();
-    let mut interval = time::interval(PERIOD);
-    let mut millis = MAX_WAIT_TIME;
+    let mut interval = time::interval(get_config().period());
+    let mut millis = get_config().max_sync_wait_time;
     while millis > 0 {
         let instant = interval.tick().await;
         let elapsed = instant
@@ -87,9 +87,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 wait_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
                                 continue 'update; // Session is removed due to error.
                             }
                             StreamPollResult::Closed => {
@@ -98,9 +97,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 wait_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
                                 continue 'update; // Session is removed due to closure.
                             }
                         }
@@ -136,9 +134,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 loaded_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
                                 continue 'update; // Session is removed due to error.
                             }
                             StreamPollResult::Closed => {
@@ -147,9 +144,8 @@ pub async fn wait(
                                 #[cfg(not(feature = "no-debugging-log"))]
                                 println!("{:?} replaced by Bot", n.session);
 
-                                let bot = Box::new(Bot::from(n.session));
+                                let bot = replace_with_bot(n.session, &mut num_player);
                                 loaded_sessions.push(Node::new(bot, n.left_side));
-                                num_player -= 1;
                                 continue 'update; // Session is removed due to closure.
                             }
                         }
@@ -180,27 +176,28 @@ pub async fn wait(
                 (n1.session, n0.session)
             };
 
-            let redis_conn_cloned = redis_conn.clone();
-            tokio::spawn(prepare::wait(left, right, num_player, redis_conn_cloned));
+            let store_cloned = store.clone();
+            tokio::spawn(prepare::wait(left, right, num_player, store_cloned));
             return;
         }
     }
 
     while let Some(n) = wait_sessions.pop() {
-        let mut session = n.session;
+        let session = n.session;
         let bot = Box::new(Bot::from(session.as_ref()));
         loaded_sessions.push(Node::new(bot, n.left_side));
 
         #[cfg(not(feature = "no-debugging-log"))]
         println!("{:?} replaced by Bot", session);
 
-        let message = Packet::GameLoadTimeout;
-        session = send_message(session, &message, &mut num_player);
         let result: Result<Box<Player>, Box<dyn Any + Send>> = session.into_any().downcast();
         if let Ok(player) = result {
-            let redis_conn_cloned = redis_conn.clone();
-            next_state(State::Title, player, redis_conn_cloned);
+            // The player leaves the match, even if they can no longer be told about it.
             num_player -= 1;
+            match player.tx.send(Packet::GameLoadTimeout) {
+                Ok(_) => next_state(State::Title, player, store.clone()),
+                Err(e) => println!("WebSocket disconnected ({:?}): {e}", player),
+            }
         }
     }
 
@@ -219,6 +216,6 @@ pub async fn wait(
             (n1.session, n0.session)
         };
 
-        tokio::spawn(prepare::wait(left, right, num_player, redis_conn));
+        tokio::spawn(prepare::wait(left, right, num_player, store));
     }
 }
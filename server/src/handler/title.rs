@@ -1,9 +1,26 @@
+use protocol::{NameError, RANKING_PAGE_SIZE, RankingView};
+
 use super::*;
+use crate::name::validate_name;
 
-pub async fn update(mut player: Box<Player>, mut redis_conn: MultiplexedConnection) {
+pub async fn update(player: Box<Player>, store: SharedStore) {
     #[cfg(not(feature = "no-debugging-log"))]
     println!("{:?} - Current State: Title", player);
 
+    listen(player, store).await;
+}
+
+/// Returns the player to the title with a packet they sent before getting there.
+pub async fn resume(player: Box<Player>, packet: Packet, store: SharedStore) {
+    #[cfg(not(feature = "no-debugging-log"))]
+    println!("{:?} - Current State: Title", player);
+
+    if let Some(player) = handle_packet(player, packet, &store).await {
+        listen(player, store).await;
+    }
+}
+
+async fn listen(mut player: Box<Player>, store: SharedStore) {
     while let Some(result) = player.read.next().await {
         let message = match result {
             Ok(message) => message,
@@ -16,72 +33,232 @@ pub async fn update(mut player: Box<Player>, mut redis_conn: MultiplexedConnecti
         if let Message::Text(s) = message
             && let Ok(packet) = serde_json::from_str::<Packet>(&s)
         {
-            match packet {
-                Packet::RankingQuery => {
-                    let result = get_leaderboard_and_my_rank(&mut redis_conn, &player.uuid).await;
+            match handle_packet(player, packet, &store).await {
+                Some(p) => player = p,
+                None => return,
+            }
+        }
+    }
+}
+
+/// Sends the answer to a query of the player.
+/// Returns `None` if the answer could not be read from the store or the connection is lost.
+fn reply(player: &Player, result: StoreResult<Packet>) -> Option<()> {
+    let packet = match result {
+        Ok(packet) => packet,
+        Err(e) => {
+            eprintln!("{e}");
+            return None;
+        }
+    };
+    if let Err(e) = player.tx.send(packet) {
+        eprintln!("WebSocket disconnected ({:?}): {}", player, e);
+        return None;
+    }
+    Some(())
+}
+
+/// Handles a packet of a player on the title.
+/// Returns `None` if the player has left the title or the connection is lost.
+async fn handle_packet(
+    mut player: Box<Player>,
+    packet: Packet,
+    store: &SharedStore,
+) -> Option<Box<Player>> {
+    match packet {
+        Packet::RankingQuery { view } => {
+            let season = current_season();
+            let result = get_leaderboard_and_my_rank(store, &player.uuid, season, view).await;
+            reply(&player, result)?;
+        }
+        Packet::SeasonRankingQuery { season, view } => {
+            // Seasons that have not started yet are shown as the current one.
+            let season = season.clamp(1, current_season());
+            let result = get_leaderboard_and_my_rank(store, &player.uuid, season, view).await;
+            reply(&player, result)?;
+        }
+        Packet::HeroStatsQuery => {
+            let result = get_hero_stats(store, &player.uuid).await;
+            reply(&player, result)?;
+        }
+        Packet::HeroRankingQuery { hero, view } => {
+            let result = get_hero_leaderboard(store, &player.uuid, hero, view).await;
+            reply(&player, result)?;
+        }
+        Packet::ProfileQuery => {
+            let result = get_profile(store, &player).await;
+            reply(&player, result)?;
+        }
+        Packet::ReplayQuery { id } => {
+            let result = get_replay(store, &id).await;
+            reply(&player, result)?;
+        }
+        Packet::ChangeHero { hero } => {
+            if let Err(e) = store.update_hero(&player.uuid, hero).await {
+                eprintln!("{e}");
+                return None;
+            }
+            player.hero = hero;
+        }
+        Packet::ChangeName { name } => {
+            let packet = match validate_name(&name) {
+                Ok(name) => {
+                    let result = store.rename_player(&player.uuid, &player.name, &name).await;
                     match result {
-                        Ok(packet) => {
-                            let result = player.tx.send(packet);
-                            if let Err(e) = result {
-                                eprintln!("WebSocket disconnected ({:?}): {}", &player, e);
-                                return;
-                            }
+                        Ok(true) => {
+                            player.name = name.clone();
+                            Packet::NameChanged { name }
                         }
+                        Ok(false) => Packet::NameRejected {
+                            reason: NameError::Duplicated,
+                        },
                         Err(e) => {
-                            eprintln!("Redis Error: {e}");
-                            return;
+                            eprintln!("{e}");
+                            return None;
                         }
                     }
                 }
-                Packet::EnterGame => {
-                    return next_state(State::Matching, player, redis_conn);
+                Err(reason) => Packet::NameRejected { reason },
+            };
+
+            let result = player.tx.send(packet);
+            if let Err(e) = result {
+                eprintln!("WebSocket disconnected ({:?}): {}", &player, e);
+                return None;
+            }
+        }
+        Packet::EnterGame => {
+            next_state(State::Matching, player, store.clone());
+            return None;
+        }
+        Packet::CreateRoom => {
+            next_state(State::Room, player, store.clone());
+            return None;
+        }
+        Packet::JoinRoom { code } => {
+            room::join(player, &code);
+            return None;
+        }
+        Packet::LiveMatchQuery => {
+            let matches = spectate::live_matches();
+            let result = player.tx.send(Packet::LiveMatchList { matches });
+            if let Err(e) = result {
+                eprintln!("WebSocket disconnected ({:?}): {}", &player, e);
+                return None;
+            }
+        }
+        Packet::WatchMatch { id } => match spectate::watch(player, id) {
+            Ok(_) => return None,
+            Err(p) => {
+                // The match is already over.
+                player = p;
+                let result = player.tx.send(Packet::WatchNotFound);
+                if let Err(e) = result {
+                    eprintln!("WebSocket disconnected ({:?}): {}", &player, e);
+                    return None;
                 }
-                _ => { /* empty */ }
+            }
+        },
+        Packet::RematchOffer => {
+            // The opponent has already left, or was a bot.
+            let result = player.tx.send(Packet::RematchDeclined);
+            if let Err(e) = result {
+                eprintln!("WebSocket disconnected ({:?}): {}", &player, e);
+                return None;
             }
         }
+        _ => { /* empty */ }
     }
+    Some(player)
 }
 
 pub async fn get_leaderboard_and_my_rank(
-    redis_conn: &mut MultiplexedConnection,
+    store: &SharedStore,
     my_uuid: &Uuid,
-) -> redis::RedisResult<Packet> {
-    let my_key = format!("user:{my_uuid}");
-    let (top_keys, my_rank_idx): (Vec<String>, Option<u32>) = redis::pipe()
-        .zrevrange(LEADER_BOARD_KEY, 0, 9)
-        .zrevrank(LEADER_BOARD_KEY, &my_key)
-        .query_async(redis_conn)
+    season: u32,
+    view: RankingView,
+) -> StoreResult<Packet> {
+    let start = match view {
+        RankingView::Page(page) => page_start(page),
+        RankingView::AroundMe => around_rank_start(store.rank(season, my_uuid).await?),
+    };
+
+    let ranking = store
+        .ranking(season, my_uuid, start, RANKING_PAGE_SIZE as usize)
         .await?;
+    Ok(Packet::RankingResult {
+        season,
+        current_season: current_season(),
+        my_rank: ranking.my_rank,
+        my_rating: ranking.my_rating,
+        total: ranking.total,
+        items: ranking.items,
+    })
+}
 
-    let my_rank = my_rank_idx.map(|r| r + 1);
+pub async fn get_hero_stats(store: &SharedStore, my_uuid: &Uuid) -> StoreResult<Packet> {
+    let mine = store.hero_stats(my_uuid).await?;
+    let overall = store.overall_hero_stats().await?;
+    Ok(Packet::HeroStatsResult { mine, overall })
+}
 
-    if top_keys.is_empty() {
-        return Ok(Packet::RankingResult {
-            my_rank,
-            top_list: Vec::new(),
-        });
-    }
+pub async fn get_hero_leaderboard(
+    store: &SharedStore,
+    my_uuid: &Uuid,
+    hero: Hero,
+    view: RankingView,
+) -> StoreResult<Packet> {
+    let start = match view {
+        RankingView::Page(page) => page_start(page),
+        RankingView::AroundMe => around_rank_start(store.hero_rank(hero, my_uuid).await?),
+    };
 
-    let mut pipe = redis::pipe();
-    for key in &top_keys {
-        pipe.hmget(key, &[NAME_KEY, WINS_KEY, LOSSES_KEY]);
-    }
+    let ranking = store
+        .hero_ranking(hero, my_uuid, start, RANKING_PAGE_SIZE as usize)
+        .await?;
+    Ok(Packet::HeroRankingResult {
+        hero,
+        my_rank: ranking.my_rank,
+        total: ranking.total,
+        items: ranking.items,
+    })
+}
+
+pub async fn get_profile(store: &SharedStore, player: &Player) -> StoreResult<Packet> {
+    let history = store.match_history(&player.uuid).await?;
+    Ok(Packet::ProfileResult {
+        rating: player.rating,
+        wins: player.win,
+        losses: player.lose,
+        draws: player.draw,
+        history,
+    })
+}
 
-    let details: Vec<(Option<String>, Option<u32>, Option<u32>)> =
-        pipe.query_async(redis_conn).await?;
-    let mut top_list = Vec::with_capacity(top_keys.len());
-    for (i, ((name, wins, losses), key)) in details.into_iter().zip(top_keys).enumerate() {
-        if let (Some(name), Some(wins), Some(losses)) = (name, wins, losses) {
-            let uuid = key.strip_prefix("user:").unwrap_or(&key).to_string();
-            top_list.push(RankItem {
-                rank: i as u32 + 1,
-                uuid,
-                name,
-                wins,
-                losses,
-            });
+/// Returns the replay of a game.
+/// A replay written by another version of the server is treated as expired.
+async fn get_replay(store: &SharedStore, id: &Uuid) -> StoreResult<Packet> {
+    let data = store.replay(id).await?;
+    let replay = data.and_then(|data| match Replay::decode(&data) {
+        Ok(replay) => Some(Box::new(replay)),
+        Err(e) => {
+            eprintln!("{e}");
+            None
         }
-    }
+    });
+    Ok(Packet::ReplayResult { replay })
+}
 
-    Ok(Packet::RankingResult { my_rank, top_list })
+/// Returns the position of the first item on the page.
+fn page_start(page: u32) -> usize {
+    page as usize * RANKING_PAGE_SIZE as usize
+}
+
+/// Returns the position of the first item on a page that has the player in the middle,
+/// or the first page if the player has no rank.
+fn around_rank_start(rank: Option<u32>) -> usize {
+    match rank {
+        Some(rank) => (rank as usize - 1).saturating_sub(RANKING_PAGE_SIZE as usize / 2),
+        None => 0,
+    }
 }
@@ -0,0 +1,108 @@
+pub mod config;
+mod handler;
+mod maintenance;
+pub mod matchmaking;
+mod name;
+pub mod season;
+pub mod store;
+mod stream;
+
+use std::sync::OnceLock;
+
+use protocol::SESSION_TOKEN_PARAM;
+use tokio::net::TcpListener;
+use tokio_tungstenite::{
+    accept_hdr_async,
+    tungstenite::handshake::server::{Request, Response},
+};
+
+use crate::store::SharedStore;
+
+const NAME_KEY: &str = "name";
+const HERO_KEY: &str = "hero";
+const WINS_KEY: &str = "wins";
+const LOSSES_KEY: &str = "losses";
+const DRAWS_KEY: &str = "draws";
+const RATING_KEY: &str = "rating";
+const TOKEN_KEY: &str = "token";
+// Each season has its own leaderboard under this prefix.
+// The previous global leaderboard is not carried over to the first season.
+const LEADER_BOARD_KEY: &str = "rating_leaderboard";
+// Wins, losses and draws of the users in each season.
+const SEASON_RECORD_KEY: &str = "season_record";
+// Final standings of finished seasons, and the set of archived seasons.
+const SEASON_ARCHIVE_KEY: &str = "season_archive";
+const ARCHIVED_SEASONS_KEY: &str = "archived_seasons";
+const NAME_INDEX_KEY: &str = "names";
+// Statistics of every player by hero, and the players of each hero ordered by wins.
+const HERO_STATS_KEY: &str = "hero_stats";
+const HERO_LEADER_BOARD_KEY: &str = "hero_leaderboard";
+// Recent games of each user, most recent first.
+const MATCH_HISTORY_KEY: &str = "match_history";
+// Replays of finished games in the replay file format.
+const REPLAY_KEY: &str = "replay";
+
+const NAMES: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/name.txt"));
+static NAME_TABLE: OnceLock<Vec<String>> = OnceLock::new();
+
+pub fn get_name_table() -> &'static Vec<String> {
+    NAME_TABLE.get_or_init(|| {
+        let lines = NAMES.lines().map(|s| s.to_string());
+        Vec::from_iter(lines)
+    })
+}
+
+/// Runs the game server on the given listener until it stops accepting connections.
+/// The configuration must be initialized before calling this function,
+/// and it must be called only once per process.
+pub async fn serve(listener: TcpListener, store: SharedStore) {
+    // --- Init name table ---
+    get_name_table();
+
+    // --- Init matching queue ---
+    tokio::spawn(handler::matching::update(store.clone()));
+
+    // --- Init private rooms ---
+    tokio::spawn(handler::room::update(store.clone()));
+
+    // --- Init rematch offers ---
+    tokio::spawn(handler::rematch::update(store.clone()));
+
+    // --- Init leaderboard maintenance ---
+    tokio::spawn(maintenance::update(store.clone()));
+    tokio::spawn(maintenance::update_seasons(store.clone()));
+
+    // --- Accept WebSocket connections ---
+    while let Ok((stream, addr)) = listener.accept().await {
+        let mut token = None;
+        #[allow(clippy::result_large_err)] // Signature required by tungstenite.
+        let callback = |request: &Request, response: Response| {
+            token = find_session_token(request);
+            Ok(response)
+        };
+        let result = accept_hdr_async(stream, callback).await;
+        let ws_stream = match result {
+            Ok(ws_stream) => {
+                println!("New WebSocket connection (Address:{addr})");
+                ws_stream
+            }
+            Err(e) => {
+                eprintln!("Failed to accept WebSocket connection (Address:{addr}): {e}");
+                continue;
+            }
+        };
+
+        tokio::spawn(handler::init::setup(addr, ws_stream, token, store.clone()));
+    }
+}
+
+/// Reads the session token from the query string of the WebSocket request.
+fn find_session_token(request: &Request) -> Option<String> {
+    request
+        .uri()
+        .query()?
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == SESSION_TOKEN_PARAM)
+        .map(|(_, value)| value.to_string())
+}
@@ -0,0 +1,58 @@
+use std::ops::Range;
+
+use tokio::time::{self, Duration, MissedTickBehavior};
+
+use crate::{
+    config::get_config,
+    season::{current_season, now, season_end},
+    store::SharedStore,
+};
+
+/// Removes the leaderboard entries of expired users periodically while the server runs.
+/// User records expire in the store, but their leaderboard entries do not.
+pub async fn update(store: SharedStore) {
+    let interval_seconds = get_config().leaderboard_prune_interval;
+    if interval_seconds == 0 {
+        return;
+    }
+
+    let mut interval = time::interval(Duration::from_secs(interval_seconds));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        match store.prune_leaderboard(current_season()).await {
+            Ok(0) => { /* empty */ }
+            Ok(removed) => println!("Removed {removed} stale leaderboard entries."),
+            Err(e) => eprintln!("Failed to prune the leaderboard: {e}"),
+        }
+    }
+}
+
+/// Archives each season when it ends.
+/// Finished seasons that were not archived yet, e.g. while the server was down,
+/// are archived at startup.
+pub async fn update_seasons(store: SharedStore) {
+    let mut season = current_season();
+    archive_seasons(&store, 1..season).await;
+
+    loop {
+        let remaining = season_end(get_config(), season) - now();
+        time::sleep(Duration::from_secs(remaining.max(1) as u64)).await;
+
+        let current = current_season();
+        if current != season {
+            archive_seasons(&store, season..current).await;
+            season = current;
+        }
+    }
+}
+
+async fn archive_seasons(store: &SharedStore, seasons: Range<u32>) {
+    for season in seasons {
+        match store.archive_season(season).await {
+            Ok(true) => println!("Archived the leaderboard of season {season}."),
+            Ok(false) => { /* empty */ }
+            Err(e) => eprintln!("Failed to archive season {season}: {e}"),
+        }
+    }
+}
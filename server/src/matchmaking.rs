@@ -0,0 +1,120 @@
+use protocol::uuid::Uuid;
+
+use crate::config::{Config, MatchmakingKind};
+
+/// What the matchmaker knows about a player waiting in the matching queue.
+#[derive(Debug, Clone, Copy)]
+pub struct Candidate {
+    pub uuid: Uuid,
+    pub rating: i32,
+    pub wins: u16,
+    pub losses: u16,
+    /// How long the player has been waiting (milliseconds).
+    pub waited_millis: u32,
+}
+
+/// Decides which players in the matching queue can play each other.
+pub trait Matchmaker: Send + Sync {
+    /// Returns how far apart the two players are,
+    /// or `None` if they cannot be matched with each other yet.
+    fn distance(&self, a: &Candidate, b: &Candidate) -> Option<f64>;
+}
+
+/// Matches players in the order they entered the queue.
+pub struct FifoMatchmaker;
+
+impl Matchmaker for FifoMatchmaker {
+    fn distance(&self, _a: &Candidate, _b: &Candidate) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+/// Matches players whose skill is close enough.
+/// The allowed gap of each player widens while they wait,
+/// and both players must allow the gap between them.
+pub struct SkillMatchmaker {
+    skill: fn(&Candidate) -> f64,
+    gap: f64,
+    gap_per_second: f64,
+}
+
+impl SkillMatchmaker {
+    /// Compares players by rating.
+    pub fn rating(gap: u32, gap_per_second: u32) -> Self {
+        Self {
+            skill: |c| c.rating as f64,
+            gap: gap as f64,
+            gap_per_second: gap_per_second as f64,
+        }
+    }
+
+    /// Compares players by win rate in percent.
+    /// Players without games start at 50% so that a few games do not decide their place.
+    pub fn win_rate(gap: u32, gap_per_second: u32) -> Self {
+        Self {
+            skill: |c| {
+                let wins = c.wins as f64;
+                let games = c.wins as f64 + c.losses as f64;
+                (wins + 1.0) / (games + 2.0) * 100.0
+            },
+            gap: gap as f64,
+            gap_per_second: gap_per_second as f64,
+        }
+    }
+
+    fn allowed_gap(&self, candidate: &Candidate) -> f64 {
+        self.gap + self.gap_per_second * candidate.waited_millis as f64 / 1000.0
+    }
+}
+
+impl Matchmaker for SkillMatchmaker {
+    fn distance(&self, a: &Candidate, b: &Candidate) -> Option<f64> {
+        let distance = ((self.skill)(a) - (self.skill)(b)).abs();
+        let allowed_gap = self.allowed_gap(a).min(self.allowed_gap(b));
+        (distance <= allowed_gap).then_some(distance)
+    }
+}
+
+/// Creates the matchmaker selected in the configuration.
+pub fn from_config(config: &Config) -> Box<dyn Matchmaker> {
+    match config.matchmaking {
+        MatchmakingKind::Fifo => Box::new(FifoMatchmaker),
+        MatchmakingKind::Rating => Box::new(SkillMatchmaker::rating(
+            config.matching_rating_gap,
+            config.matching_rating_gap_per_second,
+        )),
+        MatchmakingKind::WinRate => Box::new(SkillMatchmaker::win_rate(
+            config.matching_win_rate_gap,
+            config.matching_win_rate_gap_per_second,
+        )),
+    }
+}
+
+/// Pairs the waiting players, visiting them in the order they entered the queue.
+/// Each player is paired with the closest allowed opponent who is not paired yet;
+/// ties go to the one who has been waiting longer. A user is never paired with themselves.
+/// Returns the indices of the paired candidates.
+pub fn find_pairs(matchmaker: &dyn Matchmaker, candidates: &[Candidate]) -> Vec<(usize, usize)> {
+    let mut paired = vec![false; candidates.len()];
+    let mut pairs = Vec::new();
+    for i in 0..candidates.len() {
+        if paired[i] {
+            continue;
+        }
+
+        let opponent = (i + 1..candidates.len())
+            .filter(|&j| !paired[j] && candidates[j].uuid != candidates[i].uuid)
+            .filter_map(|j| {
+                let distance = matchmaker.distance(&candidates[i], &candidates[j])?;
+                Some((j, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((j, _)) = opponent {
+            paired[i] = true;
+            paired[j] = true;
+            pairs.push((i, j));
+        }
+    }
+    pairs
+}
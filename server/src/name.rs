@@ -0,0 +1,69 @@
+use protocol::{Hero, MAX_NAME_LENGTH, MIN_NAME_LENGTH, NUM_HEROS, NameError};
+
+use crate::{config::get_config, get_name_table};
+
+/// Checks a name requested by a player.
+/// Returns the name without leading and trailing spaces if it can be used.
+pub fn validate_name(name: &str) -> Result<String, NameError> {
+    let name = name.trim();
+
+    let length = name.chars().count();
+    if !(MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&length) {
+        return Err(NameError::InvalidLength);
+    }
+
+    if !name.chars().all(is_allowed_char) || name.contains("  ") {
+        return Err(NameError::InvalidCharacter);
+    }
+
+    let normalized = normalize_name(name);
+    let is_denied = get_config()
+        .denied_names
+        .iter()
+        .map(|word| normalize_name(word))
+        .any(|word| !word.is_empty() && normalized.contains(&word));
+    if is_denied {
+        return Err(NameError::Denied);
+    }
+
+    // Generated names are shared by many users, so they cannot be taken by one.
+    if is_generated_name(&normalized) {
+        return Err(NameError::Duplicated);
+    }
+
+    Ok(name.to_string())
+}
+
+/// Returns the form of a name used to compare names: lowercase without spaces.
+pub fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_allowed_char(c: char) -> bool {
+    matches!(c,
+        ' '
+        | '0'..='9'
+        | 'A'..='Z'
+        | 'a'..='z'
+        | '\u{AC00}'..='\u{D7A3}' // Hangul Syllables
+        | '\u{3041}'..='\u{3096}' // Hiragana
+        | '\u{30A1}'..='\u{30FA}' // Katakana
+        | '\u{30FC}'              // Katakana-Hiragana Prolonged Sound Mark
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+    )
+}
+
+/// Returns `true` if the name has the form of `{prefix} {hero}` given to new users and bots.
+fn is_generated_name(normalized: &str) -> bool {
+    get_name_table()
+        .iter()
+        .filter_map(|prefix| normalized.strip_prefix(normalize_name(prefix).as_str()))
+        .any(|rest| {
+            (0..NUM_HEROS)
+                .filter_map(Hero::new)
+                .any(|hero| normalize_name(&hero.to_string()) == rest)
+        })
+}
@@ -0,0 +1,31 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::{Config, get_config};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Returns the current time in unix seconds.
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Returns the season at the given time (unix seconds).
+/// Seasons are numbered from 1, and the time before the first season belongs to it.
+pub fn season_at(config: &Config, time: i64) -> u32 {
+    let length = config.season_length_days as i64 * SECONDS_PER_DAY;
+    let elapsed = (time - config.season_start).max(0);
+    (elapsed / length) as u32 + 1
+}
+
+/// Returns the time the given season ends (unix seconds).
+pub fn season_end(config: &Config, season: u32) -> i64 {
+    config.season_start + season as i64 * config.season_length_days as i64 * SECONDS_PER_DAY
+}
+
+/// Returns the season in progress.
+pub fn current_season() -> u32 {
+    season_at(get_config(), now())
+}
@@ -0,0 +1,429 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use protocol::{MATCH_HISTORY_SIZE, MAX_POINT};
+
+use super::*;
+use crate::name::normalize_name;
+
+#[derive(Debug, Default, Clone)]
+struct UserRecord {
+    name: String,
+    hero: Option<Hero>,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    secret: String,
+    /// `None` until the first game.
+    rating: Option<i32>,
+    /// Record of each season the user played.
+    /// Users appear on the leaderboard of a season after their first game in it.
+    seasons: HashMap<u32, SeasonRecord>,
+    heroes: HashMap<Hero, HeroStats>,
+    /// Most recent first.
+    history: VecDeque<MatchRecord>,
+}
+
+/// Games of a user in one season.
+#[derive(Debug, Default, Clone)]
+struct SeasonRecord {
+    /// Rating after the last game of the season.
+    rating: i32,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+}
+
+/// Storage backend that keeps everything in process memory.
+/// Records other than replays never expire, and everything is lost when the server stops.
+/// The users are always locked before the archives.
+#[derive(Default)]
+pub struct MemoryStore {
+    users: Mutex<HashMap<Uuid, UserRecord>>,
+    /// Final standings of the archived seasons.
+    archives: Mutex<HashMap<u32, Vec<RankItem>>>,
+    /// Replays with the time they expire at.
+    replays: Mutex<HashMap<Uuid, (Instant, Vec<u8>)>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Returns the leaderboard of the season ordered by rating, highest first.
+/// Ties are ordered by key in reverse, the same as `ZREVRANGE`.
+fn sorted_leaderboard(
+    users: &HashMap<Uuid, UserRecord>,
+    season: u32,
+) -> Vec<(&Uuid, &UserRecord, &SeasonRecord)> {
+    let mut list: Vec<_> = users
+        .iter()
+        .filter_map(|(uuid, record)| Some((uuid, record, record.seasons.get(&season)?)))
+        .collect();
+    list.sort_by(|a, b| b.2.rating.cmp(&a.2.rating).then_with(|| b.0.cmp(a.0)));
+    list
+}
+
+fn rating_of(users: &HashMap<Uuid, UserRecord>, uuid: &Uuid) -> i32 {
+    users
+        .get(uuid)
+        .and_then(|record| record.rating)
+        .unwrap_or(get_config().initial_rating)
+}
+
+fn add_game_result(
+    record: &mut UserRecord,
+    season: u32,
+    side: &GameSide,
+    outcome: GameOutcome,
+    opponent_rating: i32,
+    k_factor: u32,
+) -> i32 {
+    match outcome {
+        GameOutcome::Win => record.wins = record.wins.saturating_add(1),
+        GameOutcome::Loss => record.losses = record.losses.saturating_add(1),
+        GameOutcome::Draw => record.draws = record.draws.saturating_add(1),
+    }
+
+    let stats = record
+        .heroes
+        .entry(side.hero)
+        .or_insert_with(|| HeroStats::new(side.hero));
+    stats.games = stats.games.saturating_add(1);
+    match outcome {
+        GameOutcome::Win => stats.wins = stats.wins.saturating_add(1),
+        GameOutcome::Loss => stats.losses = stats.losses.saturating_add(1),
+        GameOutcome::Draw => { /* empty */ }
+    }
+    stats.hits = stats.hits.saturating_add(side.hits);
+
+    let rating = record.rating.unwrap_or(get_config().initial_rating);
+    let rating = updated_rating(rating, opponent_rating, outcome.score(), k_factor);
+    record.rating = Some(rating);
+
+    let season = record.seasons.entry(season).or_default();
+    season.rating = rating;
+    match outcome {
+        GameOutcome::Win => season.wins = season.wins.saturating_add(1),
+        GameOutcome::Loss => season.losses = season.losses.saturating_add(1),
+        GameOutcome::Draw => season.draws = season.draws.saturating_add(1),
+    }
+    rating
+}
+
+#[async_trait]
+impl PlayerStore for MemoryStore {
+    async fn create_player(
+        &self,
+        uuid: &Uuid,
+        _addr: &SocketAddr,
+        record: &PlayerRecord,
+        secret: &str,
+    ) -> StoreResult<bool> {
+        let mut users = self.users.lock().unwrap();
+        if users.contains_key(uuid) {
+            return Ok(false);
+        }
+
+        users.insert(
+            *uuid,
+            UserRecord {
+                name: record.name.clone(),
+                hero: Some(record.hero),
+                wins: record.wins as u32,
+                losses: record.losses as u32,
+                draws: record.draws as u32,
+                secret: secret.to_string(),
+                ..Default::default()
+            },
+        );
+        Ok(true)
+    }
+
+    async fn load_player(&self, uuid: &Uuid, secret: &str) -> StoreResult<Option<PlayerRecord>> {
+        let users = self.users.lock().unwrap();
+        let record = users
+            .get(uuid)
+            .filter(|record| secret_matches(&record.secret, secret))
+            .and_then(|record| {
+                Some(PlayerRecord {
+                    name: record.name.clone(),
+                    hero: record.hero?,
+                    rating: record.rating.unwrap_or(get_config().initial_rating),
+                    wins: record.wins.min(MAX_POINT as u32) as u16,
+                    losses: record.losses.min(MAX_POINT as u32) as u16,
+                    draws: record.draws.min(MAX_POINT as u32) as u16,
+                })
+            });
+        Ok(record)
+    }
+
+    async fn update_hero(&self, uuid: &Uuid, hero: Hero) -> StoreResult<()> {
+        let mut users = self.users.lock().unwrap();
+        if let Some(record) = users.get_mut(uuid) {
+            record.hero = Some(hero);
+        }
+        Ok(())
+    }
+
+    async fn rename_player(&self, uuid: &Uuid, _old_name: &str, name: &str) -> StoreResult<bool> {
+        let mut users = self.users.lock().unwrap();
+        let key = normalize_name(name);
+        let is_taken = users
+            .iter()
+            .any(|(other, record)| other != uuid && normalize_name(&record.name) == key);
+        if is_taken {
+            return Ok(false);
+        }
+
+        match users.get_mut(uuid) {
+            Some(record) => {
+                record.name = name.to_string();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn record_match_result(
+        &self,
+        season: u32,
+        left: &GameSide,
+        right: &GameSide,
+        left_outcome: GameOutcome,
+    ) -> StoreResult<(i32, i32)> {
+        let mut users = self.users.lock().unwrap();
+        let k_factor = get_config().k_factor;
+        let left_rating = rating_of(&users, &left.uuid);
+        let right_rating = rating_of(&users, &right.uuid);
+
+        let record = users.entry(left.uuid).or_default();
+        let new_left = add_game_result(record, season, left, left_outcome, right_rating, k_factor);
+        let record = users.entry(right.uuid).or_default();
+        let new_right = add_game_result(
+            record,
+            season,
+            right,
+            left_outcome.opposite(),
+            left_rating,
+            k_factor,
+        );
+        Ok((new_left, new_right))
+    }
+
+    async fn record_bot_game_result(
+        &self,
+        season: u32,
+        side: &GameSide,
+        outcome: GameOutcome,
+    ) -> StoreResult<i32> {
+        let mut users = self.users.lock().unwrap();
+        let config = get_config();
+        let record = users.entry(side.uuid).or_default();
+        let rating = add_game_result(
+            record,
+            season,
+            side,
+            outcome,
+            config.initial_rating,
+            config.bot_k_factor,
+        );
+        Ok(rating)
+    }
+
+    async fn add_match_record(&self, uuid: &Uuid, record: &MatchRecord) -> StoreResult<()> {
+        let mut users = self.users.lock().unwrap();
+        let history = &mut users.entry(*uuid).or_default().history;
+        history.push_front(record.clone());
+        history.truncate(MATCH_HISTORY_SIZE);
+        Ok(())
+    }
+
+    async fn match_history(&self, uuid: &Uuid) -> StoreResult<Vec<MatchRecord>> {
+        let users = self.users.lock().unwrap();
+        let history = users
+            .get(uuid)
+            .map(|record| record.history.iter().cloned().collect())
+            .unwrap_or_default();
+        Ok(history)
+    }
+}
+
+/// Returns the leaderboard of the season with the details of each user.
+fn rank_items(users: &HashMap<Uuid, UserRecord>, season: u32) -> Vec<RankItem> {
+    sorted_leaderboard(users, season)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (key, record, season))| RankItem {
+            rank: i as u32 + 1,
+            uuid: key.to_string(),
+            name: record.name.clone(),
+            rating: season.rating,
+            wins: season.wins,
+            losses: season.losses,
+            draws: season.draws,
+        })
+        .collect()
+}
+
+#[async_trait]
+impl LeaderboardStore for MemoryStore {
+    async fn rank(&self, season: u32, uuid: &Uuid) -> StoreResult<Option<u32>> {
+        let ranking = self.ranking(season, uuid, 0, 0).await?;
+        Ok(ranking.my_rank)
+    }
+
+    async fn ranking(
+        &self,
+        season: u32,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking> {
+        let users = self.users.lock().unwrap();
+        let archives = self.archives.lock().unwrap();
+        let list = match archives.get(&season) {
+            Some(items) => items.clone(),
+            None => rank_items(&users, season),
+        };
+
+        let uuid = uuid.to_string();
+        let me = list.iter().find(|item| item.uuid == uuid);
+        let my_rank = me.map(|item| item.rank);
+        let my_rating = me.map(|item| item.rating);
+        let total = list.len() as u32;
+        let items = list.into_iter().skip(start).take(count).collect();
+
+        Ok(Ranking {
+            my_rank,
+            my_rating,
+            total,
+            items,
+        })
+    }
+
+    async fn prune_leaderboard(&self, _season: u32) -> StoreResult<usize> {
+        // Ratings live in the user records, which never expire here.
+        Ok(0)
+    }
+
+    async fn archive_season(&self, season: u32) -> StoreResult<bool> {
+        let users = self.users.lock().unwrap();
+        let mut archives = self.archives.lock().unwrap();
+        if archives.contains_key(&season) {
+            return Ok(false);
+        }
+
+        archives.insert(season, rank_items(&users, season));
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl HeroStatsStore for MemoryStore {
+    async fn hero_stats(&self, uuid: &Uuid) -> StoreResult<Vec<HeroStats>> {
+        let users = self.users.lock().unwrap();
+        let mut list: Vec<_> = users
+            .get(uuid)
+            .map(|record| record.heroes.values().copied().collect())
+            .unwrap_or_default();
+        list.sort_by_key(|stats| stats.hero.index());
+        Ok(list)
+    }
+
+    async fn overall_hero_stats(&self) -> StoreResult<Vec<HeroStats>> {
+        let users = self.users.lock().unwrap();
+        let mut overall: HashMap<Hero, HeroStats> = HashMap::new();
+        for stats in users.values().flat_map(|record| record.heroes.values()) {
+            let total = overall
+                .entry(stats.hero)
+                .or_insert_with(|| HeroStats::new(stats.hero));
+            total.games += stats.games;
+            total.wins += stats.wins;
+            total.losses += stats.losses;
+            total.hits += stats.hits;
+        }
+
+        let mut list: Vec<_> = overall.into_values().collect();
+        list.sort_by_key(|stats| stats.hero.index());
+        Ok(list)
+    }
+
+    async fn hero_rank(&self, hero: Hero, uuid: &Uuid) -> StoreResult<Option<u32>> {
+        let ranking = self.hero_ranking(hero, uuid, 0, 0).await?;
+        Ok(ranking.my_rank)
+    }
+
+    async fn hero_ranking(
+        &self,
+        hero: Hero,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking> {
+        let users = self.users.lock().unwrap();
+
+        // Ordered the same as `ZREVRANGE`, like `sorted_leaderboard`.
+        let mut list: Vec<_> = users
+            .iter()
+            .filter_map(|(key, record)| Some((key, record, record.heroes.get(&hero)?)))
+            .collect();
+        list.sort_by(|a, b| b.2.wins.cmp(&a.2.wins).then_with(|| b.0.cmp(a.0)));
+
+        let my_rank = list
+            .iter()
+            .position(|(key, _, _)| *key == uuid)
+            .map(|i| i as u32 + 1);
+        let my_rating = users.get(uuid).and_then(|record| record.rating);
+        let total = list.len() as u32;
+
+        let items = list
+            .into_iter()
+            .enumerate()
+            .skip(start)
+            .take(count)
+            .map(|(i, (key, record, stats))| RankItem {
+                rank: i as u32 + 1,
+                uuid: key.to_string(),
+                name: record.name.clone(),
+                rating: record.rating.unwrap_or(get_config().initial_rating),
+                wins: stats.wins,
+                losses: stats.losses,
+                draws: stats.games.saturating_sub(stats.wins + stats.losses),
+            })
+            .collect();
+
+        Ok(Ranking {
+            my_rank,
+            my_rating,
+            total,
+            items,
+        })
+    }
+}
+
+#[async_trait]
+impl ReplayStore for MemoryStore {
+    async fn add_replay(&self, id: &Uuid, data: &[u8]) -> StoreResult<()> {
+        let now = Instant::now();
+        let seconds = get_config().replay_expire_seconds as u64;
+        let mut replays = self.replays.lock().unwrap();
+        replays.retain(|_, (expires_at, _)| *expires_at > now);
+        replays.insert(*id, (now + Duration::from_secs(seconds), data.to_vec()));
+        Ok(())
+    }
+
+    async fn replay(&self, id: &Uuid) -> StoreResult<Option<Vec<u8>>> {
+        let replays = self.replays.lock().unwrap();
+        let data = replays
+            .get(id)
+            .filter(|(expires_at, _)| *expires_at > Instant::now())
+            .map(|(_, data)| data.clone());
+        Ok(data)
+    }
+}
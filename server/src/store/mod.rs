@@ -0,0 +1,257 @@
+mod memory_store;
+mod redis_store;
+
+use std::{fmt, net::SocketAddr, sync::Arc};
+
+use async_trait::async_trait;
+use protocol::{Hero, HeroStats, MatchOutcome, MatchRecord, RankItem, uuid::Uuid};
+
+use crate::config::get_config;
+
+pub use self::{memory_store::MemoryStore, redis_store::RedisStore};
+
+pub type StoreResult<T> = Result<T, StoreError>;
+
+/// Shared handle to the storage backend passed between the handlers.
+pub type SharedStore = Arc<dyn Store>;
+
+/// Persistent data of a user.
+#[derive(Debug, Clone)]
+pub struct PlayerRecord {
+    pub name: String,
+    pub hero: Hero,
+    pub rating: i32,
+    pub wins: u16,
+    pub losses: u16,
+    pub draws: u16,
+}
+
+/// Result of a game from the point of view of one side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+impl GameOutcome {
+    /// Returns the result of the same game for the other side.
+    pub fn opposite(self) -> Self {
+        match self {
+            GameOutcome::Win => GameOutcome::Loss,
+            GameOutcome::Loss => GameOutcome::Win,
+            GameOutcome::Draw => GameOutcome::Draw,
+        }
+    }
+
+    /// Returns the score used by the rating: 1 for a win, 0.5 for a draw and 0 for a loss.
+    pub fn score(self) -> f64 {
+        match self {
+            GameOutcome::Win => 1.0,
+            GameOutcome::Loss => 0.0,
+            GameOutcome::Draw => 0.5,
+        }
+    }
+}
+
+impl From<GameOutcome> for MatchOutcome {
+    fn from(outcome: GameOutcome) -> Self {
+        match outcome {
+            GameOutcome::Win => MatchOutcome::Win,
+            GameOutcome::Loss => MatchOutcome::Loss,
+            GameOutcome::Draw => MatchOutcome::Draw,
+        }
+    }
+}
+
+/// One side of a finished game played by a user.
+#[derive(Debug, Clone, Copy)]
+pub struct GameSide {
+    pub uuid: Uuid,
+    pub hero: Hero,
+    /// Number of throws that hit the opponent.
+    pub hits: u32,
+}
+
+/// A part of the leaderboard as seen by one user.
+#[derive(Debug, Default)]
+pub struct Ranking {
+    /// Rank of the user (1-based). `None` until the user plays a game.
+    pub my_rank: Option<u32>,
+    pub my_rating: Option<i32>,
+    /// Number of users on the leaderboard.
+    pub total: u32,
+    pub items: Vec<RankItem>,
+}
+
+impl PlayerRecord {
+    pub fn new(name: String, hero: Hero) -> Self {
+        Self {
+            name,
+            hero,
+            rating: get_config().initial_rating,
+            wins: 0,
+            losses: 0,
+            draws: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Redis(redis::RedisError),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Redis(e) => write!(f, "Redis Error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<redis::RedisError> for StoreError {
+    fn from(e: redis::RedisError) -> Self {
+        StoreError::Redis(e)
+    }
+}
+
+#[async_trait]
+pub trait PlayerStore: Send + Sync {
+    /// Creates a new user record that can be resumed later with `secret`.
+    /// Returns `false` if a user with the same UUID already exists.
+    async fn create_player(
+        &self,
+        uuid: &Uuid,
+        addr: &SocketAddr,
+        record: &PlayerRecord,
+        secret: &str,
+    ) -> StoreResult<bool>;
+
+    /// Loads an existing user record.
+    /// Returns `None` if the user does not exist or `secret` does not match.
+    async fn load_player(&self, uuid: &Uuid, secret: &str) -> StoreResult<Option<PlayerRecord>>;
+
+    /// Changes the hero of the user and refreshes the expiry of the record.
+    /// Does nothing if the record no longer exists.
+    async fn update_hero(&self, uuid: &Uuid, hero: Hero) -> StoreResult<()>;
+
+    /// Changes the name of the user from `old_name` to `name` and refreshes the expiry of the record.
+    /// Returns `false` if another user already has the name or the record no longer exists.
+    async fn rename_player(&self, uuid: &Uuid, old_name: &str, name: &str) -> StoreResult<bool>;
+
+    /// Adds the result of a game between two users to both records
+    /// and updates both ratings at once on the leaderboard of the season.
+    /// Returns the new ratings of the left and right users.
+    async fn record_match_result(
+        &self,
+        season: u32,
+        left: &GameSide,
+        right: &GameSide,
+        left_outcome: GameOutcome,
+    ) -> StoreResult<(i32, i32)>;
+
+    /// Adds the result of a game against a bot to the user record
+    /// and the leaderboard of the season.
+    /// Bots are not stored, so they are rated at the initial rating.
+    /// Returns the new rating of the user.
+    async fn record_bot_game_result(
+        &self,
+        season: u32,
+        side: &GameSide,
+        outcome: GameOutcome,
+    ) -> StoreResult<i32>;
+
+    /// Adds a finished game to the match history of the user.
+    /// Only the last `MATCH_HISTORY_SIZE` games are kept.
+    async fn add_match_record(&self, uuid: &Uuid, record: &MatchRecord) -> StoreResult<()>;
+
+    /// Returns the match history of the user, most recent first.
+    async fn match_history(&self, uuid: &Uuid) -> StoreResult<Vec<MatchRecord>>;
+}
+
+#[async_trait]
+pub trait LeaderboardStore: Send + Sync {
+    /// Returns the rank of the given user (1-based) in the season.
+    async fn rank(&self, season: u32, uuid: &Uuid) -> StoreResult<Option<u32>>;
+
+    /// Returns the rank and rating of the given user in the season
+    /// and `count` users from the 0-based position `start`.
+    /// Archived seasons show their final standings.
+    async fn ranking(
+        &self,
+        season: u32,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking>;
+
+    /// Removes the entries of users whose record no longer exists
+    /// from the leaderboard of the season and the hero leaderboards.
+    /// Returns the number of removed entries.
+    async fn prune_leaderboard(&self, season: u32) -> StoreResult<usize>;
+
+    /// Saves the final standings of a finished season,
+    /// so that they outlive the user records and stop changing.
+    /// Returns `false` if the season was already archived.
+    async fn archive_season(&self, season: u32) -> StoreResult<bool>;
+}
+
+/// Statistics of the games played with each hero.
+/// They are updated by `PlayerStore::record_match_result` and `record_bot_game_result`.
+#[async_trait]
+pub trait HeroStatsStore: Send + Sync {
+    /// Returns the statistics of the heroes the user has played, in hero order.
+    async fn hero_stats(&self, uuid: &Uuid) -> StoreResult<Vec<HeroStats>>;
+
+    /// Returns the statistics of every player for each hero that has been played, in hero order.
+    async fn overall_hero_stats(&self) -> StoreResult<Vec<HeroStats>>;
+
+    /// Returns the rank of the user among the players of the hero (1-based).
+    async fn hero_rank(&self, hero: Hero, uuid: &Uuid) -> StoreResult<Option<u32>>;
+
+    /// Returns the players of the hero ordered by their wins with it.
+    /// The wins and losses of the items are those with the hero.
+    async fn hero_ranking(
+        &self,
+        hero: Hero,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking>;
+}
+
+/// Replays of finished games in the replay file format.
+#[async_trait]
+pub trait ReplayStore: Send + Sync {
+    /// Saves the replay of a game, which expires after `replay_expire_seconds`.
+    async fn add_replay(&self, id: &Uuid, data: &[u8]) -> StoreResult<()>;
+
+    /// Returns the replay of a game, or `None` if it has expired.
+    async fn replay(&self, id: &Uuid) -> StoreResult<Option<Vec<u8>>>;
+}
+
+pub trait Store: PlayerStore + LeaderboardStore + HeroStatsStore + ReplayStore {}
+
+impl<T: PlayerStore + LeaderboardStore + HeroStatsStore + ReplayStore> Store for T {}
+
+/// Compares the secret of a session token with the stored one.
+/// Takes the same time wherever the secrets differ, so that the time does not leak the secret.
+fn secret_matches(stored: &str, secret: &str) -> bool {
+    stored.len() == secret.len()
+        && stored
+            .bytes()
+            .zip(secret.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// Calculates the Elo rating of a player after a game.
+/// The leaderboard is ordered by this rating.
+pub fn updated_rating(rating: i32, opponent_rating: i32, score: f64, k_factor: u32) -> i32 {
+    let expected = 1.0 / (1.0 + 10f64.powf((opponent_rating - rating) as f64 / 400.0));
+    let rating = rating as f64 + k_factor as f64 * (score - expected);
+    (rating + 0.5).floor() as i32
+}
@@ -0,0 +1,811 @@
+use std::collections::{BTreeMap, HashMap};
+
+use protocol::{MATCH_HISTORY_SIZE, MAX_POINT, NUM_HEROS, serde_json};
+use redis::{AsyncTypedCommands, Script, aio::MultiplexedConnection};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::{
+    ARCHIVED_SEASONS_KEY, DRAWS_KEY, HERO_KEY, HERO_LEADER_BOARD_KEY, HERO_STATS_KEY,
+    LEADER_BOARD_KEY, LOSSES_KEY, MATCH_HISTORY_KEY, NAME_INDEX_KEY, NAME_KEY, RATING_KEY,
+    REPLAY_KEY, SEASON_ARCHIVE_KEY, SEASON_RECORD_KEY, TOKEN_KEY, WINS_KEY, config::get_config,
+    name::normalize_name,
+};
+
+/// Number of leaderboard entries handled at once while pruning or archiving.
+const BATCH_SIZE: isize = 500;
+
+/// Details of a user kept in the archive of a season.
+#[derive(Serialize, Deserialize)]
+struct ArchivedUser {
+    name: String,
+    wins: u32,
+    losses: u32,
+    draws: u32,
+    /// Rating at the end of the season.
+    rating: i32,
+}
+
+#[derive(Clone)]
+pub struct RedisStore {
+    conn: MultiplexedConnection,
+}
+
+impl RedisStore {
+    pub async fn connect(url: &str) -> StoreResult<Self> {
+        let client = redis::Client::open(url)?;
+        let conn = client.get_multiplexed_async_connection().await?;
+        Ok(Self { conn })
+    }
+
+    /// Adds the result of a game to one user, or to two users who played each other.
+    /// `score` is the score of the first user.
+    /// Returns the new ratings in the order of `sides`.
+    async fn record_rated_game(
+        &self,
+        season: u32,
+        sides: &[&GameSide],
+        score: f64,
+        k_factor: u32,
+    ) -> StoreResult<Vec<i32>> {
+        let mut conn = self.conn.clone();
+
+        // Lua Script
+        // ARGV[1]: score, ARGV[2]: k_factor, ARGV[3]: initial_rating, ARGV[4]: expire_seconds,
+        // ARGV[5]: hero index, ARGV[6]: hits,
+        // ARGV[7]: opponent hero index (optional), ARGV[8]: opponent hits (optional)
+        // KEYS[1]: leader_board_key, KEYS[2]: hero_stats_key, KEYS[3]: season_record_key,
+        // KEYS[4]: user_key, KEYS[5]: hero_leader_board_key of the user,
+        // KEYS[6]: opponent user_key (optional), KEYS[7]: hero_leader_board_key of the opponent (optional)
+        // Without an opponent key, the opponent is a bot at the initial rating.
+        // The rating must match `updated_rating`.
+        let script = Script::new(
+            r#"
+            local initial = tonumber(ARGV[3])
+
+            local function rating_of(key)
+                return tonumber(redis.call('HGET', key, 'rating')) or initial
+            end
+
+            local function record_hero(key, hero_board_key, hero, hits, score)
+                local won = 0
+                for _, stats_key in ipairs({ key, KEYS[2] }) do
+                    local prefix = hero .. ':'
+                    if stats_key == key then
+                        prefix = 'hero:' .. prefix
+                    end
+
+                    redis.call('HINCRBY', stats_key, prefix .. 'games', 1)
+                    redis.call('HINCRBY', stats_key, prefix .. 'hits', hits)
+                    if score == 1 then
+                        redis.call('HINCRBY', stats_key, prefix .. 'wins', 1)
+                        won = 1
+                    elseif score == 0 then
+                        redis.call('HINCRBY', stats_key, prefix .. 'losses', 1)
+                    end
+                end
+
+                --- Update Hero Leader Board ---
+                redis.call('ZINCRBY', hero_board_key, won, key)
+            end
+
+            local function record(key, score, opponent_rating)
+                --- Update Hash data (HINCRBY) ---
+                local field = 'draws'
+                if score == 1 then
+                    field = 'wins'
+                elseif score == 0 then
+                    field = 'losses'
+                end
+                redis.call('HINCRBY', key, field, 1)
+                redis.call('HINCRBY', KEYS[3], key .. ':' .. field, 1)
+
+                --- Calculate rating ---
+                local rating = rating_of(key)
+                local expected = 1 / (1 + 10 ^ ((opponent_rating - rating) / 400))
+                local new_rating = math.floor(rating + tonumber(ARGV[2]) * (score - expected) + 0.5)
+                redis.call('HSET', key, 'rating', new_rating)
+
+                --- Update expiration to long-term ---
+                redis.call('EXPIRE', key, ARGV[4])
+
+                --- Update Leader Board ---
+                redis.call('ZADD', KEYS[1], new_rating, key)
+
+                return new_rating
+            end
+
+            local score = tonumber(ARGV[1])
+            local rating = rating_of(KEYS[4])
+            record_hero(KEYS[4], KEYS[5], ARGV[5], tonumber(ARGV[6]), score)
+            if KEYS[6] then
+                record_hero(KEYS[6], KEYS[7], ARGV[7], tonumber(ARGV[8]), 1 - score)
+                local opponent_rating = rating_of(KEYS[6])
+                local new_rating = record(KEYS[4], score, opponent_rating)
+                local new_opponent_rating = record(KEYS[6], 1 - score, rating)
+                return { new_rating, new_opponent_rating }
+            end
+
+            return { record(KEYS[4], score, initial) }
+        "#,
+        );
+
+        let mut invocation = script.key(leader_board_key(season));
+        invocation
+            .key(HERO_STATS_KEY)
+            .key(season_record_key(season));
+        for side in sides {
+            invocation
+                .key(user_key(&side.uuid))
+                .key(hero_leader_board_key(side.hero));
+        }
+        invocation
+            .arg(score)
+            .arg(k_factor)
+            .arg(get_config().initial_rating)
+            .arg(get_config().expire_seconds);
+        for side in sides {
+            invocation.arg(side.hero.index()).arg(side.hits);
+        }
+        let ratings: Vec<i32> = invocation.invoke_async(&mut conn).await?;
+
+        Ok(ratings)
+    }
+
+    /// Reads the name and the games in the season of users on the leaderboard of the season,
+    /// given as their keys and scores.
+    /// The name is `None` if the record of the user no longer exists.
+    async fn season_details(
+        &self,
+        season: u32,
+        entries: &[(String, f64)],
+    ) -> StoreResult<Vec<(Option<String>, ArchivedUser)>> {
+        let mut conn = self.conn.clone();
+        let record_key = season_record_key(season);
+        let mut pipe = redis::pipe();
+        for (key, _) in entries {
+            pipe.hget(key, NAME_KEY);
+        }
+        let names: Vec<Option<String>> = pipe.query_async(&mut conn).await?;
+
+        let mut pipe = redis::pipe();
+        for (key, _) in entries {
+            pipe.hmget(&record_key, &season_record_fields(key));
+        }
+        type Games = Vec<(Option<u32>, Option<u32>, Option<u32>)>;
+        let games: Games = pipe.query_async(&mut conn).await?;
+
+        Ok(names
+            .into_iter()
+            .zip(games)
+            .zip(entries)
+            .map(|((name, (wins, losses, draws)), (_, score))| {
+                let user = ArchivedUser {
+                    name: name.clone().unwrap_or_default(),
+                    wins: wins.unwrap_or_default(),
+                    losses: losses.unwrap_or_default(),
+                    draws: draws.unwrap_or_default(),
+                    rating: *score as i32,
+                };
+                (name, user)
+            })
+            .collect())
+    }
+
+    /// Removes the entries of users whose record no longer exists from a sorted set.
+    /// Returns the number of removed entries.
+    async fn prune_board(&self, board_key: &str) -> StoreResult<usize> {
+        let mut conn = self.conn.clone();
+
+        // Lua Script
+        // ARGV: user_keys on the leaderboard
+        // KEYS[1]: leader_board_key
+        // Checking and removing in one script keeps a user who plays meanwhile on the board.
+        let script = Script::new(
+            r#"
+            local removed = 0
+            for _, key in ipairs(ARGV) do
+                if redis.call('EXISTS', key) == 0 then
+                    removed = removed + redis.call('ZREM', KEYS[1], key)
+                end
+            end
+            return removed
+        "#,
+        );
+
+        let mut total_removed = 0;
+        let mut start = 0;
+        loop {
+            let stop = start + BATCH_SIZE - 1;
+            let keys: Vec<String> = conn.zrange(board_key, start, stop).await?;
+            if keys.is_empty() {
+                break;
+            }
+
+            let removed: usize = script
+                .key(board_key)
+                .arg(&keys)
+                .invoke_async(&mut conn)
+                .await?;
+
+            // Removed entries shift the remaining ones toward the front.
+            start += (keys.len() - removed) as isize;
+            total_removed += removed;
+        }
+
+        Ok(total_removed)
+    }
+}
+
+fn user_key(uuid: &Uuid) -> String {
+    format!("user:{uuid}")
+}
+
+fn leader_board_key(season: u32) -> String {
+    format!("{LEADER_BOARD_KEY}:{season}")
+}
+
+/// Maps `{user_key}:wins`, `{user_key}:losses` and `{user_key}:draws` to the games of the users
+/// in the season. Removed once the season is archived.
+fn season_record_key(season: u32) -> String {
+    format!("{SEASON_RECORD_KEY}:{season}")
+}
+
+/// Returns the fields of the season record of a user, in the order wins, losses and draws.
+fn season_record_fields(user_key: &str) -> [String; 3] {
+    [
+        format!("{user_key}:{WINS_KEY}"),
+        format!("{user_key}:{LOSSES_KEY}"),
+        format!("{user_key}:{DRAWS_KEY}"),
+    ]
+}
+
+/// Maps the user keys on the leaderboard of the season to `ArchivedUser` in JSON.
+fn season_archive_key(season: u32) -> String {
+    format!("{SEASON_ARCHIVE_KEY}:{season}")
+}
+
+/// A list of `MatchRecord` in JSON, most recent first.
+/// It expires together with the user record, since both are refreshed after each game.
+fn match_history_key(uuid: &Uuid) -> String {
+    format!("{MATCH_HISTORY_KEY}:{uuid}")
+}
+
+/// The replay of a game in the replay file format.
+fn replay_key(id: &Uuid) -> String {
+    format!("{REPLAY_KEY}:{id}")
+}
+
+/// Orders the players of the hero by their wins with it.
+fn hero_leader_board_key(hero: Hero) -> String {
+    format!("{HERO_LEADER_BOARD_KEY}:{}", hero.index())
+}
+
+/// Name of the field of a hero statistic in a user record.
+fn hero_field(hero: Hero, stat: &str) -> String {
+    format!("hero:{}:{stat}", hero.index())
+}
+
+/// Collects the hero statistics stored as `<prefix><hero index>:<stat>` fields.
+fn parse_hero_stats(fields: HashMap<String, String>, prefix: &str) -> Vec<HeroStats> {
+    let mut list = BTreeMap::new();
+    for (field, value) in fields {
+        let Some((index, stat)) = field
+            .strip_prefix(prefix)
+            .and_then(|rest| rest.split_once(':'))
+        else {
+            continue;
+        };
+        let (Some(hero), Ok(value)) = (index.parse().ok().and_then(Hero::new), value.parse())
+        else {
+            continue;
+        };
+
+        let stats = list
+            .entry(hero.index())
+            .or_insert_with(|| HeroStats::new(hero));
+        match stat {
+            "games" => stats.games = value,
+            "wins" => stats.wins = value,
+            "losses" => stats.losses = value,
+            "hits" => stats.hits = value,
+            _ => { /* empty */ }
+        }
+    }
+    list.into_values().collect()
+}
+
+#[async_trait]
+impl PlayerStore for RedisStore {
+    async fn create_player(
+        &self,
+        uuid: &Uuid,
+        addr: &SocketAddr,
+        record: &PlayerRecord,
+        secret: &str,
+    ) -> StoreResult<bool> {
+        let mut conn = self.conn.clone();
+        let key = user_key(uuid);
+        let value = addr.to_string();
+
+        let is_created: bool = conn.hset_nx(&key, "ip", &value).await?;
+        if !is_created {
+            return Ok(false);
+        }
+
+        // If the UUID is unique and 'ip' field is set, populate the rest of the user data.
+        // If population fails, the partial record will expire eventually.
+        let _: () = redis::pipe()
+            .hset(&key, NAME_KEY, &record.name)
+            .hset(&key, HERO_KEY, record.hero.index())
+            .hset(&key, WINS_KEY, record.wins)
+            .hset(&key, LOSSES_KEY, record.losses)
+            .hset(&key, DRAWS_KEY, record.draws)
+            .hset(&key, TOKEN_KEY, secret)
+            .expire(&key, get_config().initial_expire_seconds)
+            .query_async(&mut conn)
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn load_player(&self, uuid: &Uuid, secret: &str) -> StoreResult<Option<PlayerRecord>> {
+        let mut conn = self.conn.clone();
+        let key = user_key(uuid);
+
+        type Fields = (
+            Option<String>,
+            Option<usize>,
+            Option<i32>,
+            Option<u32>,
+            Option<u32>,
+            Option<u32>,
+            Option<String>,
+        );
+        let (name, hero, rating, wins, losses, draws, token): Fields = redis::cmd("HMGET")
+            .arg(&key)
+            .arg(&[
+                NAME_KEY, HERO_KEY, RATING_KEY, WINS_KEY, LOSSES_KEY, DRAWS_KEY, TOKEN_KEY,
+            ])
+            .query_async(&mut conn)
+            .await?;
+
+        if !token.is_some_and(|token| secret_matches(&token, secret)) {
+            return Ok(None);
+        }
+
+        let (Some(name), Some(hero), Some(wins), Some(losses), Some(draws)) =
+            (name, hero.and_then(Hero::new), wins, losses, draws)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(PlayerRecord {
+            name,
+            hero,
+            // Users have no rating until their first game.
+            rating: rating.unwrap_or(get_config().initial_rating),
+            wins: wins.min(MAX_POINT as u32) as u16,
+            losses: losses.min(MAX_POINT as u32) as u16,
+            draws: draws.min(MAX_POINT as u32) as u16,
+        }))
+    }
+
+    async fn update_hero(&self, uuid: &Uuid, hero: Hero) -> StoreResult<()> {
+        let mut conn = self.conn.clone();
+
+        // Lua Script
+        // ARGV[1]: hero index, ARGV[2]: expire_seconds, ARGV[3]: initial_expire_seconds
+        // KEYS[1]: user_key
+        // An expired record is not recreated. Users who never played keep the initial expiry.
+        let script = Script::new(
+            r#"
+            if redis.call('EXISTS', KEYS[1]) == 0 then
+                return 0
+            end
+
+            redis.call('HSET', KEYS[1], 'hero', ARGV[1])
+            if redis.call('HEXISTS', KEYS[1], 'rating') == 1 then
+                redis.call('EXPIRE', KEYS[1], ARGV[2])
+            else
+                redis.call('EXPIRE', KEYS[1], ARGV[3])
+            end
+
+            return 1
+        "#,
+        );
+
+        let _: bool = script
+            .key(user_key(uuid))
+            .arg(hero.index())
+            .arg(get_config().expire_seconds)
+            .arg(get_config().initial_expire_seconds)
+            .invoke_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn rename_player(&self, uuid: &Uuid, old_name: &str, name: &str) -> StoreResult<bool> {
+        let mut conn = self.conn.clone();
+        let user_key = user_key(uuid);
+
+        // Lua Script
+        // ARGV[1]: name, ARGV[2]: normalized name, ARGV[3]: normalized old name,
+        // ARGV[4]: expire_seconds, ARGV[5]: initial_expire_seconds
+        // KEYS[1]: user_key, KEYS[2]: name_index_key
+        // The name index maps a normalized name to the key of the user who chose it.
+        // A name is free again once its owner changes it or their record expires.
+        // An expired record is not recreated. Users who never played keep the initial expiry.
+        let script = Script::new(
+            r#"
+            if redis.call('EXISTS', KEYS[1]) == 0 then
+                return 0
+            end
+
+            --- Check the owner of the name ---
+            local owner = redis.call('HGET', KEYS[2], ARGV[2])
+            if owner and owner ~= KEYS[1] and redis.call('EXISTS', owner) == 1 then
+                return 0
+            end
+
+            --- Release the previous name ---
+            if redis.call('HGET', KEYS[2], ARGV[3]) == KEYS[1] then
+                redis.call('HDEL', KEYS[2], ARGV[3])
+            end
+
+            --- Take the new name ---
+            redis.call('HSET', KEYS[2], ARGV[2], KEYS[1])
+            redis.call('HSET', KEYS[1], 'name', ARGV[1])
+            if redis.call('HEXISTS', KEYS[1], 'rating') == 1 then
+                redis.call('EXPIRE', KEYS[1], ARGV[4])
+            else
+                redis.call('EXPIRE', KEYS[1], ARGV[5])
+            end
+
+            return 1
+        "#,
+        );
+
+        let is_renamed: bool = script
+            .key(&user_key)
+            .key(NAME_INDEX_KEY)
+            .arg(name)
+            .arg(normalize_name(name))
+            .arg(normalize_name(old_name))
+            .arg(get_config().expire_seconds)
+            .arg(get_config().initial_expire_seconds)
+            .invoke_async(&mut conn)
+            .await?;
+
+        Ok(is_renamed)
+    }
+
+    async fn record_match_result(
+        &self,
+        season: u32,
+        left: &GameSide,
+        right: &GameSide,
+        left_outcome: GameOutcome,
+    ) -> StoreResult<(i32, i32)> {
+        let k_factor = get_config().k_factor;
+        let ratings = self
+            .record_rated_game(season, &[left, right], left_outcome.score(), k_factor)
+            .await?;
+        Ok((ratings[0], ratings[1]))
+    }
+
+    async fn record_bot_game_result(
+        &self,
+        season: u32,
+        side: &GameSide,
+        outcome: GameOutcome,
+    ) -> StoreResult<i32> {
+        let k_factor = get_config().bot_k_factor;
+        let ratings = self
+            .record_rated_game(season, &[side], outcome.score(), k_factor)
+            .await?;
+        Ok(ratings[0])
+    }
+
+    async fn add_match_record(&self, uuid: &Uuid, record: &MatchRecord) -> StoreResult<()> {
+        let mut conn = self.conn.clone();
+        let key = match_history_key(uuid);
+        let value = serde_json::to_string(record).unwrap();
+        let _: () = redis::pipe()
+            .atomic()
+            .lpush(&key, value)
+            .ltrim(&key, 0, MATCH_HISTORY_SIZE as isize - 1)
+            .expire(&key, get_config().expire_seconds)
+            .query_async(&mut conn)
+            .await?;
+        Ok(())
+    }
+
+    async fn match_history(&self, uuid: &Uuid) -> StoreResult<Vec<MatchRecord>> {
+        let mut conn = self.conn.clone();
+        let values = conn
+            .lrange(match_history_key(uuid), 0, MATCH_HISTORY_SIZE as isize - 1)
+            .await?;
+        let history = values
+            .iter()
+            .filter_map(|value| serde_json::from_str(value).ok())
+            .collect();
+        Ok(history)
+    }
+}
+
+#[async_trait]
+impl LeaderboardStore for RedisStore {
+    async fn rank(&self, season: u32, uuid: &Uuid) -> StoreResult<Option<u32>> {
+        let mut conn = self.conn.clone();
+        let rank_idx = conn
+            .zrevrank(leader_board_key(season), user_key(uuid))
+            .await?;
+        Ok(rank_idx.map(|r| r as u32 + 1))
+    }
+
+    async fn ranking(
+        &self,
+        season: u32,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking> {
+        let mut conn = self.conn.clone();
+        let board_key = leader_board_key(season);
+        let my_key = user_key(uuid);
+        let stop = (start + count) as isize - 1;
+        type Fields = (Vec<(String, f64)>, Option<u32>, Option<f64>, u32, bool);
+        let (entries, my_rank_idx, my_score, total, is_archived): Fields = redis::pipe()
+            .zrevrange_withscores(&board_key, start as isize, stop)
+            .zrevrank(&board_key, &my_key)
+            .zscore(&board_key, &my_key)
+            .zcard(&board_key)
+            .sismember(ARCHIVED_SEASONS_KEY, season)
+            .query_async(&mut conn)
+            .await?;
+
+        let my_rank = my_rank_idx.map(|r| r + 1);
+        let my_rating = my_score.map(|score| score as i32);
+
+        if entries.is_empty() {
+            return Ok(Ranking {
+                my_rank,
+                my_rating,
+                total,
+                items: Vec::new(),
+            });
+        }
+
+        // The users of an archived season are read from the archive,
+        // since their records may have changed or expired since then.
+        let details: Vec<Option<ArchivedUser>> = if is_archived {
+            let keys: Vec<&str> = entries.iter().map(|(key, _)| key.as_str()).collect();
+            let values: Vec<Option<String>> = redis::cmd("HMGET")
+                .arg(season_archive_key(season))
+                .arg(&keys)
+                .query_async(&mut conn)
+                .await?;
+            values
+                .into_iter()
+                .map(|value| value.and_then(|v| serde_json::from_str(&v).ok()))
+                .collect()
+        } else {
+            self.season_details(season, &entries)
+                .await?
+                .into_iter()
+                .map(|(name, user)| name.map(|_| user))
+                .collect()
+        };
+
+        let mut items = Vec::with_capacity(entries.len());
+        for (i, (detail, (key, _))) in details.into_iter().zip(entries).enumerate() {
+            if let Some(ArchivedUser {
+                name,
+                wins,
+                losses,
+                draws,
+                rating,
+            }) = detail
+            {
+                let uuid = key.strip_prefix("user:").unwrap_or(&key).to_string();
+                items.push(RankItem {
+                    rank: (start + i) as u32 + 1,
+                    uuid,
+                    name,
+                    rating,
+                    wins,
+                    losses,
+                    draws,
+                });
+            }
+        }
+
+        Ok(Ranking {
+            my_rank,
+            my_rating,
+            total,
+            items,
+        })
+    }
+
+    async fn prune_leaderboard(&self, season: u32) -> StoreResult<usize> {
+        let mut removed = self.prune_board(&leader_board_key(season)).await?;
+        for hero in (0..NUM_HEROS).filter_map(Hero::new) {
+            removed += self.prune_board(&hero_leader_board_key(hero)).await?;
+        }
+        Ok(removed)
+    }
+
+    async fn archive_season(&self, season: u32) -> StoreResult<bool> {
+        let mut conn = self.conn.clone();
+        let is_archived: bool = conn.sismember(ARCHIVED_SEASONS_KEY, season).await?;
+        if is_archived {
+            return Ok(false);
+        }
+
+        // Users who expired before the end of the season have no details to keep.
+        let board_key = leader_board_key(season);
+        self.prune_board(&board_key).await?;
+
+        let archive_key = season_archive_key(season);
+        let mut start = 0;
+        loop {
+            let stop = start + BATCH_SIZE - 1;
+            let entries: Vec<(String, f64)> =
+                conn.zrange_withscores(&board_key, start, stop).await?;
+            if entries.is_empty() {
+                break;
+            }
+
+            let details = self.season_details(season, &entries).await?;
+
+            let mut pipe = redis::pipe();
+            for ((key, _), (_, user)) in entries.iter().zip(details) {
+                let value = serde_json::to_string(&user).unwrap();
+                pipe.hset(&archive_key, key, value);
+            }
+            let _: () = pipe.query_async(&mut conn).await?;
+
+            start += entries.len() as isize;
+        }
+
+        // The games of the season are kept in the archive from now on.
+        let _: () = redis::pipe()
+            .sadd(ARCHIVED_SEASONS_KEY, season)
+            .ignore()
+            .del(season_record_key(season))
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        Ok(true)
+    }
+}
+
+#[async_trait]
+impl HeroStatsStore for RedisStore {
+    async fn hero_stats(&self, uuid: &Uuid) -> StoreResult<Vec<HeroStats>> {
+        let mut conn = self.conn.clone();
+        let fields: HashMap<String, String> = redis::cmd("HGETALL")
+            .arg(user_key(uuid))
+            .query_async(&mut conn)
+            .await?;
+        Ok(parse_hero_stats(fields, "hero:"))
+    }
+
+    async fn overall_hero_stats(&self) -> StoreResult<Vec<HeroStats>> {
+        let mut conn = self.conn.clone();
+        let fields: HashMap<String, String> = redis::cmd("HGETALL")
+            .arg(HERO_STATS_KEY)
+            .query_async(&mut conn)
+            .await?;
+        Ok(parse_hero_stats(fields, ""))
+    }
+
+    async fn hero_rank(&self, hero: Hero, uuid: &Uuid) -> StoreResult<Option<u32>> {
+        let mut conn = self.conn.clone();
+        let rank_idx = conn
+            .zrevrank(hero_leader_board_key(hero), user_key(uuid))
+            .await?;
+        Ok(rank_idx.map(|r| r as u32 + 1))
+    }
+
+    async fn hero_ranking(
+        &self,
+        hero: Hero,
+        uuid: &Uuid,
+        start: usize,
+        count: usize,
+    ) -> StoreResult<Ranking> {
+        let mut conn = self.conn.clone();
+        let board_key = hero_leader_board_key(hero);
+        let my_key = user_key(uuid);
+        let stop = (start + count) as isize - 1;
+        type Fields = (Vec<String>, Option<u32>, Option<i32>, u32);
+        let (keys, my_rank_idx, my_rating, total): Fields = redis::pipe()
+            .zrevrange(&board_key, start as isize, stop)
+            .zrevrank(&board_key, &my_key)
+            .hget(&my_key, RATING_KEY)
+            .zcard(&board_key)
+            .query_async(&mut conn)
+            .await?;
+
+        let my_rank = my_rank_idx.map(|r| r + 1);
+
+        let games_field = hero_field(hero, "games");
+        let (wins_field, losses_field) = (hero_field(hero, "wins"), hero_field(hero, "losses"));
+        let mut pipe = redis::pipe();
+        for key in &keys {
+            pipe.hmget(
+                key,
+                &[
+                    NAME_KEY,
+                    RATING_KEY,
+                    &games_field,
+                    &wins_field,
+                    &losses_field,
+                ],
+            );
+        }
+        type Details = Vec<(
+            Option<String>,
+            Option<i32>,
+            Option<u32>,
+            Option<u32>,
+            Option<u32>,
+        )>;
+        let details: Details = if keys.is_empty() {
+            Vec::new()
+        } else {
+            pipe.query_async(&mut conn).await?
+        };
+
+        let mut items = Vec::with_capacity(keys.len());
+        for (i, (detail, key)) in details.into_iter().zip(keys).enumerate() {
+            let (name, rating, games, wins, losses) = detail;
+            if let Some(name) = name {
+                let (games, wins, losses) = (
+                    games.unwrap_or_default(),
+                    wins.unwrap_or_default(),
+                    losses.unwrap_or_default(),
+                );
+                let uuid = key.strip_prefix("user:").unwrap_or(&key).to_string();
+                items.push(RankItem {
+                    rank: (start + i) as u32 + 1,
+                    uuid,
+                    name,
+                    rating: rating.unwrap_or(get_config().initial_rating),
+                    wins,
+                    losses,
+                    draws: games.saturating_sub(wins + losses),
+                });
+            }
+        }
+
+        Ok(Ranking {
+            my_rank,
+            my_rating,
+            total,
+            items,
+        })
+    }
+}
+
+#[async_trait]
+impl ReplayStore for RedisStore {
+    async fn add_replay(&self, id: &Uuid, data: &[u8]) -> StoreResult<()> {
+        let mut conn = self.conn.clone();
+        let seconds = get_config().replay_expire_seconds as u64;
+        conn.set_ex(replay_key(id), data, seconds).await?;
+        Ok(())
+    }
+
+    async fn replay(&self, id: &Uuid) -> StoreResult<Option<Vec<u8>>> {
+        let mut conn = self.conn.clone();
+        let data = redis::cmd("GET")
+            .arg(replay_key(id))
+            .query_async(&mut conn)
+            .await?;
+        Ok(data)
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use protocol::Packet;
+
+use self::common::*;
+
+#[tokio::test]
+async fn lonely_player_is_matched_with_bot() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    a.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    let millis = a
+        .recv_until(|p| match p {
+            Packet::MatchingStatus { millis } => Some(millis),
+            _ => None,
+        })
+        .await;
+    assert!(millis <= test_config().max_matching_time);
+
+    let (left, right) = a
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    assert!(left.uuid.is_none() != right.uuid.is_none());
+
+    a.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(|p| matches!(p, Packet::PrepareInGame).then_some(()))
+        .await;
+    a.recv_until(game_outcome).await;
+    a.close().await;
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use protocol::{Packet, RankingView};
+
+use self::common::*;
+
+#[tokio::test]
+async fn disconnected_opponent_is_replaced_by_bot() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+        _ => None,
+    };
+    let (left, right) = a.recv_until(matched).await;
+    b.recv_until(matched).await;
+    assert!(left.uuid.is_some() && right.uuid.is_some());
+
+    // The opponent leaves while the game is loading.
+    b.close().await;
+
+    a.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(|p| matches!(p, Packet::PrepareInGame).then_some(()))
+        .await;
+
+    // The game keeps running against a bot until it ends.
+    let outcome = a.recv_until(game_outcome).await;
+    println!("Game against the bot ended: {outcome:?}");
+
+    // The remaining player is back at the title and recorded on the leaderboard.
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    let top_list = a
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                items: top_list, ..
+            } => Some(top_list),
+            _ => None,
+        })
+        .await;
+    let a_uuid = a_data.uuid.unwrap().to_string();
+    assert!(top_list.iter().any(|item| item.uuid == a_uuid));
+    assert_eq!(top_list.len(), 1);
+
+    a.close().await;
+}
@@ -0,0 +1,184 @@
+#![allow(dead_code)]
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Once, OnceLock, mpsc},
+    thread,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use protocol::{
+    Circle, FIXED_STEP, Packet, PlayData, Projectile, Ruleset, SESSION_TOKEN_PARAM, Side,
+    glam::Vec2, serde_json,
+};
+use server::{
+    config::{Config, StoreKind, init_config},
+    serve,
+    store::MemoryStore,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    time::{self, Duration},
+};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungstenite::Message};
+
+/// Upper bound for waiting on a single packet from the server.
+const RECV_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Returns a configuration with short timeouts so that a whole game ends in a few seconds.
+pub fn test_config() -> Config {
+    Config {
+        store: StoreKind::Memory,
+        tick_millis: 20,
+        max_matching_time: 1_000,
+        max_sync_wait_time: 5_000,
+        max_prepare_wait_time: 200,
+        ruleset: Ruleset {
+            max_play_time: 2_000,
+            max_turn_time: 500,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Sets the configuration shared by every test in the same binary.
+/// Only the first call takes effect, so tests that use a store without a server
+/// must pass the same configuration as the server of their binary.
+pub fn setup_config(config: Config) {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| init_config(config));
+}
+
+/// Boots the server with an in-memory store on an ephemeral port and returns its address.
+/// The server runs on its own thread and is shared by every test in the same binary.
+pub fn start_server(config: Config) -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| {
+        setup_config(config);
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async move {
+                let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+                tx.send(listener.local_addr().unwrap()).unwrap();
+                serve(listener, Arc::new(MemoryStore::new())).await;
+            });
+        });
+        rx.recv().unwrap()
+    })
+}
+
+/// WebSocket client that speaks `protocol::Packet` like the game client.
+pub struct TestClient {
+    ws: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl TestClient {
+    pub async fn connect(addr: SocketAddr) -> Self {
+        let (ws, _) = connect_async(format!("ws://{addr}")).await.unwrap();
+        Self { ws }
+    }
+
+    pub async fn connect_with_token(addr: SocketAddr, token: &str) -> Self {
+        let url = format!("ws://{addr}/?{SESSION_TOKEN_PARAM}={token}");
+        let (ws, _) = connect_async(url).await.unwrap();
+        Self { ws }
+    }
+
+    /// Receives the session token and the user data sent right after connecting.
+    pub async fn handshake(&mut self) -> (String, PlayData) {
+        let token = match self.recv().await {
+            Packet::SessionToken { token } => token,
+            packet => panic!("expected SessionToken, got {packet:?}"),
+        };
+        let data = match self.recv().await {
+            Packet::Connection(data) => data,
+            packet => panic!("expected Connection, got {packet:?}"),
+        };
+        assert!(data.uuid.is_some());
+        (token, data)
+    }
+
+    pub async fn send(&mut self, packet: &Packet) {
+        let text = serde_json::to_string(packet).unwrap();
+        self.ws.send(Message::text(text)).await.unwrap();
+    }
+
+    /// Waits for the next packet from the server.
+    pub async fn recv(&mut self) -> Packet {
+        loop {
+            let message = time::timeout(RECV_TIMEOUT, self.ws.next())
+                .await
+                .expect("timed out waiting for a packet")
+                .expect("connection closed by the server")
+                .unwrap();
+            if let Message::Text(s) = message {
+                return serde_json::from_str(&s).unwrap();
+            }
+        }
+    }
+
+    /// Skips packets until `f` returns `Some`.
+    pub async fn recv_until<T>(&mut self, mut f: impl FnMut(Packet) -> Option<T>) -> T {
+        loop {
+            if let Some(value) = f(self.recv().await) {
+                return value;
+            }
+        }
+    }
+
+    /// Waits until the server closes the connection, skipping the packets sent before.
+    pub async fn wait_closed(&mut self) {
+        loop {
+            let message = time::timeout(RECV_TIMEOUT, self.ws.next())
+                .await
+                .expect("timed out waiting for the connection to close");
+            match message {
+                None | Some(Err(_)) | Some(Ok(Message::Close(_))) => return,
+                Some(Ok(_)) => { /* empty */ }
+            }
+        }
+    }
+
+    pub async fn close(mut self) {
+        let _ = self.ws.close(None).await;
+    }
+}
+
+/// Outcome of a finished game seen by a single client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Victory,
+    Defeat,
+    Draw,
+}
+
+pub fn game_outcome(packet: Packet) -> Option<Outcome> {
+    match packet {
+        Packet::GameResult { victory: true, .. } => Some(Outcome::Victory),
+        Packet::GameResult { victory: false, .. } => Some(Outcome::Defeat),
+        Packet::GameResultDraw { .. } => Some(Outcome::Draw),
+        _ => None,
+    }
+}
+
+/// Looks for a throw from the left side that hits the hero on the right side in the wind.
+pub fn find_hitting_throw(collider: &Circle, wind_vel: Vec2, ruleset: &Ruleset) -> (u8, u8) {
+    for power in (0..=255).rev() {
+        for angle in 0..=255 {
+            let mut projectile =
+                Projectile::new(Side::Left, Some((angle, power)), wind_vel, ruleset);
+            while !projectile.is_landed() {
+                if projectile.step(FIXED_STEP, Side::Right, collider).is_some() {
+                    return (angle, power);
+                }
+            }
+        }
+    }
+    panic!("no throw hits the right hero in this wind");
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use protocol::{Packet, RankingView};
+use server::config::get_config;
+
+use self::common::*;
+
+#[tokio::test]
+async fn two_players_play_a_full_game() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    let (_, b_data) = b.handshake().await;
+    assert_ne!(a_data.uuid, b_data.uuid);
+
+    // --- Title: nobody has played yet ---
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    match a.recv().await {
+        Packet::RankingResult {
+            my_rank,
+            items: top_list,
+            ..
+        } => {
+            assert_eq!(my_rank, None);
+            assert!(top_list.is_empty());
+        }
+        packet => panic!("expected RankingResult, got {packet:?}"),
+    }
+
+    // --- Matching ---
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+        _ => None,
+    };
+    let (left, right) = a.recv_until(matched).await;
+    let (b_left, b_right) = b.recv_until(matched).await;
+    assert_eq!(left.uuid, b_left.uuid);
+    assert_eq!(right.uuid, b_right.uuid);
+
+    let mut uuids = [left.uuid, right.uuid];
+    uuids.sort();
+    let mut expected = [a_data.uuid, b_data.uuid];
+    expected.sort();
+    assert_eq!(uuids, expected);
+
+    // --- Sync and prepare ---
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(|p| matches!(p, Packet::PrepareInGame).then_some(()))
+        .await;
+    b.recv_until(|p| matches!(p, Packet::PrepareInGame).then_some(()))
+        .await;
+
+    // --- In game: the left player drops the projectile at their feet once ---
+    let (mut left_client, mut right_client) = match left.uuid == a_data.uuid {
+        true => (a, b),
+        false => (b, a),
+    };
+    left_client
+        .recv_until(|p| matches!(p, Packet::InGameTurnSetup { .. }).then_some(()))
+        .await;
+    left_client
+        .recv_until(|p| matches!(p, Packet::InGameLeftTurn { .. }).then_some(()))
+        .await;
+    left_client
+        .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
+        .await;
+    left_client.send(&Packet::ThrowProjectile).await;
+    left_client
+        .recv_until(|p| matches!(p, Packet::InGameProjectileThrown { .. }).then_some(()))
+        .await;
+    right_client
+        .recv_until(|p| matches!(p, Packet::InGameProjectileThrown { .. }).then_some(()))
+        .await;
+
+    // --- Result: nobody was hit, so the game ends in a draw ---
+    let left_outcome = left_client.recv_until(game_outcome).await;
+    let right_outcome = right_client.recv_until(game_outcome).await;
+    assert_eq!(left_outcome, Outcome::Draw);
+    assert_eq!(right_outcome, Outcome::Draw);
+
+    // --- Back to title: both players are on the leaderboard ---
+    left_client
+        .send(&Packet::RankingQuery {
+            view: RankingView::Page(0),
+        })
+        .await;
+    let (my_rank, top_list) = left_client
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                my_rank,
+                items: top_list,
+                ..
+            } => Some((my_rank, top_list)),
+            _ => None,
+        })
+        .await;
+    assert!(my_rank.is_some());
+    assert_eq!(top_list.len(), 2);
+    for item in &top_list {
+        assert_eq!((item.wins, item.losses), (0, 0));
+        assert_eq!(item.rating, get_config().initial_rating);
+    }
+
+    left_client.close().await;
+    right_client.close().await;
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use protocol::{Hero, NUM_HEROS, Packet, RankingView};
+
+use self::common::*;
+
+#[tokio::test]
+async fn changed_hero_is_saved_and_used_in_matching() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let (token, data) = a.handshake().await;
+
+    let hero = Hero::new((data.hero.index() + 1) % NUM_HEROS).unwrap();
+    a.send(&Packet::ChangeHero { hero }).await;
+
+    // Packets are handled in order, so the hero is saved once the ranking arrives.
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    a.recv_until(|p| matches!(p, Packet::RankingResult { .. }).then_some(()))
+        .await;
+
+    // --- The hero is stored in the user record ---
+    // Resuming the session closes the previous connection.
+    let mut b = TestClient::connect_with_token(addr, &token).await;
+    let (_, resumed) = b.handshake().await;
+    assert_eq!(resumed.uuid, data.uuid);
+    assert_eq!(resumed.hero, hero);
+    a.wait_closed().await;
+
+    // --- The hero is used in the matching data ---
+    b.send(&Packet::EnterGame).await;
+    let (left, right) = b
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    let me = if left.uuid == data.uuid { left } else { right };
+    assert_eq!(me.uuid, data.uuid);
+    assert_eq!(me.hero, hero);
+    b.close().await;
+}
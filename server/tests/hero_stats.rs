@@ -0,0 +1,135 @@
+mod common;
+
+use protocol::{Hero, HeroStats, Packet, RankingView, uuid::Uuid};
+use server::store::{
+    GameOutcome, GameSide, HeroStatsStore, MemoryStore, PlayerRecord, PlayerStore,
+};
+
+use self::common::*;
+
+#[tokio::test]
+async fn hero_games_are_counted_per_user_and_overall() {
+    setup_config(test_config());
+    let store = MemoryStore::new();
+    let addr = "127.0.0.1:0".parse().unwrap();
+    let a = Uuid::new_v4();
+    let b = Uuid::new_v4();
+    for (uuid, name) in [(&a, "Alpha"), (&b, "Beta")] {
+        let record = PlayerRecord::new(name.into(), Hero::Erpin);
+        assert!(store.create_player(uuid, &addr, &record, "").await.unwrap());
+    }
+
+    let erpin = |uuid, hits| GameSide {
+        uuid,
+        hero: Hero::Erpin,
+        hits,
+    };
+    let kommy = |uuid, hits| GameSide {
+        uuid,
+        hero: Hero::Kommy,
+        hits,
+    };
+    store
+        .record_match_result(1, &erpin(a, 5), &kommy(b, 2), GameOutcome::Win)
+        .await
+        .unwrap();
+    store
+        .record_match_result(1, &erpin(a, 3), &erpin(b, 3), GameOutcome::Draw)
+        .await
+        .unwrap();
+    store
+        .record_bot_game_result(1, &erpin(b, 5), GameOutcome::Win)
+        .await
+        .unwrap();
+
+    // --- Statistics of a user ---
+    let mine = store.hero_stats(&b).await.unwrap();
+    assert_eq!(
+        mine,
+        vec![
+            HeroStats {
+                hero: Hero::Erpin,
+                games: 2,
+                wins: 1,
+                losses: 0,
+                hits: 8,
+            },
+            HeroStats {
+                hero: Hero::Kommy,
+                games: 1,
+                wins: 0,
+                losses: 1,
+                hits: 2,
+            },
+        ]
+    );
+
+    // --- Statistics of every player ---
+    let overall = store.overall_hero_stats().await.unwrap();
+    assert_eq!(overall[0].hero, Hero::Erpin);
+    assert_eq!(
+        (overall[0].games, overall[0].wins, overall[0].hits),
+        (4, 2, 16)
+    );
+    assert_eq!(overall[1].win_rate(), Some(0.0));
+
+    // --- Players of a hero are ordered by their wins with it ---
+    let ranking = store.hero_ranking(Hero::Erpin, &b, 0, 10).await.unwrap();
+    assert_eq!(ranking.total, 2);
+    assert_eq!(ranking.items.len(), 2);
+    assert!(ranking.items.iter().all(|item| item.wins == 1));
+    assert!(ranking.items.iter().all(|item| item.draws == 1));
+    let ranking = store.hero_ranking(Hero::Kommy, &a, 0, 10).await.unwrap();
+    assert_eq!(ranking.my_rank, None);
+    assert_eq!(ranking.items[0].uuid, b.to_string());
+    assert_eq!(ranking.items[0].losses, 1);
+}
+
+#[tokio::test]
+async fn hero_stats_are_sent_after_a_game() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let (_, data) = a.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    a.recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    a.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(game_outcome).await;
+
+    a.send(&Packet::HeroStatsQuery).await;
+    let (mine, overall) = a
+        .recv_until(|p| match p {
+            Packet::HeroStatsResult { mine, overall } => Some((mine, overall)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].hero, data.hero);
+    assert_eq!(mine[0].games, 1);
+    assert!(overall.iter().any(|stats| stats.hero == data.hero));
+
+    let view = RankingView::Page(0);
+    a.send(&Packet::HeroRankingQuery {
+        hero: data.hero,
+        view,
+    })
+    .await;
+    let (hero, my_rank, items) = a
+        .recv_until(|p| match p {
+            Packet::HeroRankingResult {
+                hero,
+                my_rank,
+                items,
+                ..
+            } => Some((hero, my_rank, items)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(hero, data.hero);
+    assert!(my_rank.is_some());
+    let my_uuid = data.uuid.unwrap().to_string();
+    assert!(items.iter().any(|item| item.uuid == my_uuid));
+
+    a.close().await;
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use protocol::{COLLIDER_DATA, Packet, Ruleset, Side};
+use server::config::Config;
+
+use self::common::*;
+
+fn hit_config() -> Config {
+    Config {
+        ruleset: Ruleset {
+            max_play_time: 10_000,
+            max_turn_time: 5_000,
+            ..test_config().ruleset
+        },
+        ..test_config()
+    }
+}
+
+#[tokio::test]
+async fn server_reports_where_the_projectile_hit() {
+    let addr = start_server(hit_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess {
+            left,
+            right,
+            ruleset,
+        } => Some((left, right, ruleset)),
+        _ => None,
+    };
+    let (left, right, ruleset) = a.recv_until(matched).await;
+    b.recv_until(matched).await;
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+
+    let (mut left_client, mut right_client) = match left.uuid == a_data.uuid {
+        true => (a, b),
+        false => (b, a),
+    };
+
+    // --- The left player throws at the right hero in the wind of the turn ---
+    let (wind_angle, wind_power) = left_client
+        .recv_until(|p| match p {
+            Packet::InGameTurnSetup {
+                wind_angle,
+                wind_power,
+            } => Some((wind_angle, wind_power)),
+            _ => None,
+        })
+        .await;
+    left_client
+        .recv_until(|p| matches!(p, Packet::InGameLeftTurn { .. }).then_some(()))
+        .await;
+    let collider = COLLIDER_DATA.get(&right.hero).unwrap();
+    let (angle, power) = find_hitting_throw(
+        collider,
+        ruleset.wind.velocity(wind_angle, wind_power),
+        &ruleset,
+    );
+    left_client
+        .send(&Packet::UpdateThrowParams { angle, power })
+        .await;
+    left_client.send(&Packet::ThrowProjectile).await;
+
+    // --- Both players are told where the right hero was hit ---
+    for client in [&mut left_client, &mut right_client] {
+        let (target, pos) = client
+            .recv_until(|p| match p {
+                Packet::InGameHit { target, pos } => Some((target, pos)),
+                _ => None,
+            })
+            .await;
+        assert_eq!(target, Side::Right);
+        assert!(Side::Right.is_hit(collider, pos.into()));
+
+        let right_health_cnt = client
+            .recv_until(|p| match p {
+                Packet::InGameProjectileThrown {
+                    right_health_cnt, ..
+                } => Some(right_health_cnt),
+                _ => None,
+            })
+            .await;
+        assert_eq!(right_health_cnt, ruleset.health_count - 1);
+    }
+
+    left_client.close().await;
+    right_client.close().await;
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use futures_util::future::join_all;
+use protocol::{Packet, RANKING_PAGE_SIZE, RankItem, RankingView};
+use server::season::current_season;
+
+use self::common::*;
+
+const NUM_PLAYERS: usize = RANKING_PAGE_SIZE as usize + 6;
+
+async fn play_game(mut client: TestClient) -> TestClient {
+    client.send(&Packet::EnterGame).await;
+    client
+        .recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    client.send(&Packet::GameLoadSuccess).await;
+    client.recv_until(game_outcome).await;
+    client
+}
+
+async fn query_ranking(
+    client: &mut TestClient,
+    view: RankingView,
+) -> (Option<u32>, u32, Vec<RankItem>) {
+    client.send(&Packet::RankingQuery { view }).await;
+    client
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                my_rank,
+                total,
+                items,
+                ..
+            } => Some((my_rank, total, items)),
+            _ => None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn leaderboard_is_paged() {
+    let addr = start_server(test_config());
+    let mut clients = Vec::new();
+    let mut uuids = Vec::new();
+    for _ in 0..NUM_PLAYERS {
+        let mut client = TestClient::connect(addr).await;
+        let (_, data) = client.handshake().await;
+        uuids.push(data.uuid.unwrap().to_string());
+        clients.push(client);
+    }
+
+    // --- Nobody is ranked yet, so every view is empty ---
+    let (my_rank, total, items) = query_ranking(&mut clients[0], RankingView::AroundMe).await;
+    assert_eq!((my_rank, total), (None, 0));
+    assert!(items.is_empty());
+
+    // --- Everyone plays a game to get on the leaderboard ---
+    let mut clients = join_all(clients.into_iter().map(play_game)).await;
+
+    // --- Pages split the leaderboard without gaps ---
+    let (_, total, first_page) = query_ranking(&mut clients[0], RankingView::Page(0)).await;
+    assert_eq!(total, NUM_PLAYERS as u32);
+    assert_eq!(first_page.len(), RANKING_PAGE_SIZE as usize);
+
+    let (_, _, second_page) = query_ranking(&mut clients[0], RankingView::Page(1)).await;
+    assert_eq!(second_page.len(), NUM_PLAYERS - RANKING_PAGE_SIZE as usize);
+
+    let all: Vec<_> = first_page.iter().chain(&second_page).collect();
+    for (i, item) in all.iter().enumerate() {
+        assert_eq!(item.rank, i as u32 + 1);
+    }
+    for uuid in &uuids {
+        assert!(all.iter().any(|item| &item.uuid == uuid));
+    }
+
+    let (_, _, empty_page) = query_ranking(&mut clients[0], RankingView::Page(2)).await;
+    assert!(empty_page.is_empty());
+
+    // --- The view around a player contains the player ---
+    for (client, uuid) in clients.iter_mut().zip(&uuids) {
+        let (my_rank, _, items) = query_ranking(client, RankingView::AroundMe).await;
+        let my_rank = my_rank.unwrap();
+        let me = items.iter().find(|item| &item.uuid == uuid).unwrap();
+        assert_eq!(me.rank, my_rank);
+        let start = (my_rank - 1).saturating_sub(RANKING_PAGE_SIZE / 2);
+        assert_eq!(items[0].rank, start + 1);
+    }
+
+    // --- Games count only for the current season ---
+    let season = current_season();
+    clients[0]
+        .send(&Packet::RankingQuery {
+            view: RankingView::Page(0),
+        })
+        .await;
+    let shown = clients[0]
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                season,
+                current_season,
+                ..
+            } => Some((season, current_season)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(shown, (season, season));
+
+    if season > 1 {
+        let view = RankingView::Page(0);
+        clients[0]
+            .send(&Packet::SeasonRankingQuery {
+                season: season - 1,
+                view,
+            })
+            .await;
+        let (shown, total) = clients[0]
+            .recv_until(|p| match p {
+                Packet::RankingResult { season, total, .. } => Some((season, total)),
+                _ => None,
+            })
+            .await;
+        assert_eq!((shown, total), (season - 1, 0));
+    }
+
+    for client in clients {
+        client.close().await;
+    }
+}
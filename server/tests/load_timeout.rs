@@ -0,0 +1,58 @@
+mod common;
+
+use protocol::{Packet, RankingView};
+use server::config::Config;
+
+use self::common::*;
+
+fn load_timeout_config() -> Config {
+    Config {
+        max_sync_wait_time: 500,
+        ..test_config()
+    }
+}
+
+#[tokio::test]
+async fn player_who_does_not_load_leaves_the_match() {
+    let addr = start_server(load_timeout_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| matches!(packet, Packet::MatchingSuccess { .. }).then_some(());
+    a.recv_until(matched).await;
+    b.recv_until(matched).await;
+
+    // --- Only one player finishes loading ---
+    a.send(&Packet::GameLoadSuccess).await;
+    b.recv_until(|p| matches!(p, Packet::GameLoadTimeout).then_some(()))
+        .await;
+
+    // --- The player who timed out is back at the title ---
+    b.send(&Packet::ProfileQuery).await;
+    b.recv_until(|p| matches!(p, Packet::ProfileResult { .. }).then_some(()))
+        .await;
+
+    // --- The other player plays against a bot, and only they are recorded ---
+    a.recv_until(game_outcome).await;
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    let top_list = a
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                items: top_list, ..
+            } => Some(top_list),
+            _ => None,
+        })
+        .await;
+    assert_eq!(top_list.len(), 1);
+    assert_eq!(top_list[0].uuid, a_data.uuid.unwrap().to_string());
+
+    a.close().await;
+    b.close().await;
+}
@@ -0,0 +1,87 @@
+use protocol::uuid::Uuid;
+use server::matchmaking::{Candidate, FifoMatchmaker, Matchmaker, SkillMatchmaker, find_pairs};
+
+fn candidate(rating: i32, waited_millis: u32) -> Candidate {
+    Candidate {
+        uuid: Uuid::new_v4(),
+        rating,
+        wins: 0,
+        losses: 0,
+        waited_millis,
+    }
+}
+
+#[test]
+fn rating_gap_widens_while_waiting() {
+    let matchmaker = SkillMatchmaker::rating(100, 50);
+    let newcomer = candidate(1000, 0);
+    let veteran = candidate(1300, 0);
+    assert_eq!(
+        matchmaker.distance(&newcomer, &candidate(1080, 0)),
+        Some(80.0)
+    );
+    assert_eq!(matchmaker.distance(&newcomer, &veteran), None);
+
+    // A veteran who waited long does not get a player who has just arrived.
+    let waited_veteran = candidate(1300, 10_000);
+    assert_eq!(matchmaker.distance(&newcomer, &waited_veteran), None);
+
+    // Once both have waited long enough, the gap is allowed.
+    let waited_newcomer = candidate(1000, 4_000);
+    assert_eq!(
+        matchmaker.distance(&waited_newcomer, &waited_veteran),
+        Some(300.0)
+    );
+}
+
+#[test]
+fn win_rate_starts_at_half_for_new_players() {
+    let matchmaker = SkillMatchmaker::win_rate(10, 0);
+    let new_player = candidate(1000, 0);
+    let winner = Candidate {
+        wins: 30,
+        losses: 0,
+        ..new_player
+    };
+    let average = Candidate {
+        wins: 10,
+        losses: 9,
+        ..new_player
+    };
+    assert!(matchmaker.distance(&new_player, &average).is_some());
+    assert_eq!(matchmaker.distance(&new_player, &winner), None);
+}
+
+#[test]
+fn players_are_paired_with_the_closest_opponent() {
+    let matchmaker = SkillMatchmaker::rating(100, 0);
+    let candidates = [
+        candidate(1000, 0),
+        candidate(1090, 0),
+        candidate(1500, 0),
+        candidate(1020, 0),
+        candidate(1450, 0),
+    ];
+    assert_eq!(find_pairs(&matchmaker, &candidates), vec![(0, 3), (2, 4)]);
+}
+
+#[test]
+fn user_is_not_paired_with_themselves() {
+    let first = candidate(1000, 0);
+    let second = Candidate {
+        waited_millis: 500,
+        ..first
+    };
+    let other = candidate(1000, 0);
+    assert_eq!(find_pairs(&FifoMatchmaker, &[first, second]), vec![]);
+    assert_eq!(
+        find_pairs(&FifoMatchmaker, &[first, second, other]),
+        vec![(0, 2)]
+    );
+}
+
+#[test]
+fn fifo_pairs_in_queue_order() {
+    let candidates = [candidate(1000, 0), candidate(1500, 0), candidate(1000, 0)];
+    assert_eq!(find_pairs(&FifoMatchmaker, &candidates), vec![(0, 1)]);
+}
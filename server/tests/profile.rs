@@ -0,0 +1,129 @@
+mod common;
+
+use protocol::{Hero, MATCH_HISTORY_SIZE, MatchOutcome, MatchRecord, Packet, uuid::Uuid};
+use server::store::{MemoryStore, PlayerRecord, PlayerStore};
+
+use self::common::*;
+
+fn match_record(played_at: i64) -> MatchRecord {
+    MatchRecord {
+        played_at,
+        hero: Hero::Erpin,
+        opponent_name: "Beta".into(),
+        opponent_hero: Hero::Kommy,
+        opponent_is_bot: false,
+        outcome: MatchOutcome::Win,
+        duration_millis: 60_000,
+        hits_dealt: 3,
+        hits_taken: 1,
+        replay_id: None,
+        seed: 0,
+    }
+}
+
+#[tokio::test]
+async fn match_history_keeps_the_most_recent_games() {
+    setup_config(test_config());
+    let store = MemoryStore::new();
+    let uuid = Uuid::new_v4();
+    assert!(store.match_history(&uuid).await.unwrap().is_empty());
+
+    let num_games = MATCH_HISTORY_SIZE as i64 + 5;
+    for played_at in 0..num_games {
+        store
+            .add_match_record(&uuid, &match_record(played_at))
+            .await
+            .unwrap();
+    }
+
+    let history = store.match_history(&uuid).await.unwrap();
+    assert_eq!(history.len(), MATCH_HISTORY_SIZE);
+    assert_eq!(history[0], match_record(num_games - 1));
+    assert!(history.windows(2).all(|w| w[0].played_at > w[1].played_at));
+}
+
+#[tokio::test]
+async fn hero_of_a_missing_user_is_not_saved() {
+    setup_config(test_config());
+    let store = MemoryStore::new();
+    let uuid = Uuid::new_v4();
+    store.update_hero(&uuid, Hero::Kommy).await.unwrap();
+
+    // The record was not recreated, so the UUID is still free.
+    let addr = "127.0.0.1:0".parse().unwrap();
+    let record = PlayerRecord::new("Alpha".into(), Hero::Erpin);
+    assert!(
+        store
+            .create_player(&uuid, &addr, &record, "")
+            .await
+            .unwrap()
+    );
+    let loaded = store.load_player(&uuid, "").await.unwrap().unwrap();
+    assert_eq!(loaded.hero, Hero::Erpin);
+}
+
+#[tokio::test]
+async fn profile_shows_the_last_game() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let (_, data) = a.handshake().await;
+
+    a.send(&Packet::ProfileQuery).await;
+    let history = a
+        .recv_until(|p| match p {
+            Packet::ProfileResult { history, .. } => Some(history),
+            _ => None,
+        })
+        .await;
+    assert!(history.is_empty());
+
+    a.send(&Packet::EnterGame).await;
+    let (opponent, ruleset) = a
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess {
+                left,
+                right,
+                ruleset,
+            } => match left.uuid {
+                Some(_) => Some((right, ruleset)),
+                None => Some((left, ruleset)),
+            },
+            _ => None,
+        })
+        .await;
+    a.send(&Packet::GameLoadSuccess).await;
+    let outcome = a.recv_until(game_outcome).await;
+
+    a.send(&Packet::ProfileQuery).await;
+    let (wins, losses, draws, history) = a
+        .recv_until(|p| match p {
+            Packet::ProfileResult {
+                wins,
+                losses,
+                draws,
+                history,
+                ..
+            } => Some((wins, losses, draws, history)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(wins + losses + draws, 1);
+    assert_eq!(history.len(), 1);
+
+    let record = &history[0];
+    let expected = match outcome {
+        Outcome::Victory => MatchOutcome::Win,
+        Outcome::Defeat => MatchOutcome::Loss,
+        Outcome::Draw => MatchOutcome::Draw,
+    };
+    assert_eq!(record.outcome, expected);
+    assert_eq!(record.hero, data.hero);
+    assert_eq!(record.opponent_name, opponent.name);
+    assert_eq!(record.opponent_hero, opponent.hero);
+    assert!(record.opponent_is_bot);
+    assert!(record.hits_dealt <= ruleset.health_count as u32);
+    assert!(record.hits_taken <= ruleset.health_count as u32);
+    assert!(record.duration_millis > 0);
+
+    a.close().await;
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use protocol::{Packet, RankItem, RankingView};
+use server::{
+    config::get_config,
+    store::{GameOutcome, updated_rating},
+};
+
+use self::common::*;
+
+fn game_outcome_of(outcome: Outcome) -> GameOutcome {
+    match outcome {
+        Outcome::Victory => GameOutcome::Win,
+        Outcome::Defeat => GameOutcome::Loss,
+        Outcome::Draw => GameOutcome::Draw,
+    }
+}
+
+async fn query_ranking(client: &mut TestClient) -> (Option<i32>, Vec<RankItem>) {
+    client
+        .send(&Packet::RankingQuery {
+            view: RankingView::Page(0),
+        })
+        .await;
+    client
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                my_rating,
+                items: top_list,
+                ..
+            } => Some((my_rating, top_list)),
+            _ => None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn ratings_are_updated_after_games() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    let (_, b_data) = b.handshake().await;
+    let a_uuid = a_data.uuid.unwrap().to_string();
+    let b_uuid = b_data.uuid.unwrap().to_string();
+    let initial = get_config().initial_rating;
+
+    // --- A game against a bot is rated against the initial rating ---
+    a.send(&Packet::EnterGame).await;
+    a.recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    a.send(&Packet::GameLoadSuccess).await;
+    let outcome = game_outcome_of(a.recv_until(game_outcome).await);
+
+    let a_rating = updated_rating(initial, initial, outcome.score(), get_config().bot_k_factor);
+    let (my_rating, top_list) = query_ranking(&mut a).await;
+    assert_eq!(my_rating, Some(a_rating));
+    assert_eq!(top_list.len(), 1);
+    assert_eq!(top_list[0].rating, a_rating);
+
+    // --- Nobody throws, so the game between the players ends in a draw ---
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let (left, right) = a
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    assert!(left.uuid.is_some() && right.uuid.is_some());
+    b.recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+    assert_eq!(a.recv_until(game_outcome).await, Outcome::Draw);
+    assert_eq!(b.recv_until(game_outcome).await, Outcome::Draw);
+
+    // --- Both ratings are moved by the same game ---
+    let k_factor = get_config().k_factor;
+    let expected_a = updated_rating(a_rating, initial, 0.5, k_factor);
+    let expected_b = updated_rating(initial, a_rating, 0.5, k_factor);
+    assert_eq!(expected_a - a_rating, initial - expected_b);
+
+    let (my_rating, top_list) = query_ranking(&mut b).await;
+    assert_eq!(my_rating, Some(expected_b));
+    let rating_of = |uuid: &str| {
+        top_list
+            .iter()
+            .find(|item| item.uuid == uuid)
+            .map(|item| item.rating)
+    };
+    assert_eq!(rating_of(&a_uuid), Some(expected_a));
+    assert_eq!(rating_of(&b_uuid), Some(expected_b));
+    let b_item = top_list.iter().find(|item| item.uuid == b_uuid).unwrap();
+    assert_eq!((b_item.wins, b_item.losses, b_item.draws), (0, 0, 1));
+    assert!(top_list.windows(2).all(|w| w[0].rating >= w[1].rating));
+
+    a.close().await;
+    b.close().await;
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use protocol::{Packet, RankingView, Ruleset};
+use server::config::Config;
+
+use self::common::*;
+
+fn rejoin_config() -> Config {
+    Config {
+        ruleset: Ruleset {
+            max_play_time: 10_000,
+            ..test_config().ruleset
+        },
+        reconnect_grace_time: 5_000,
+        ..test_config()
+    }
+}
+
+#[tokio::test]
+async fn reconnected_player_takes_back_their_seat() {
+    let addr = start_server(rejoin_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (a_token, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+        _ => None,
+    };
+    let (left, right) = a.recv_until(matched).await;
+    b.recv_until(matched).await;
+
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(|p| matches!(p, Packet::InGameTurnSetup { .. }).then_some(()))
+        .await;
+
+    // The player drops out in the middle of the game...
+    a.close().await;
+
+    // ...and comes back with the same identity.
+    let mut a = TestClient::connect_with_token(addr, &a_token).await;
+    let (_, data) = a.handshake().await;
+    assert_eq!(data.uuid, a_data.uuid);
+
+    let (rejoin_left, rejoin_right) = a
+        .recv_until(|p| match p {
+            Packet::RejoinGame { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(rejoin_left.uuid, left.uuid);
+    assert_eq!(rejoin_right.uuid, right.uuid);
+
+    a.send(&Packet::GameLoadSuccess).await;
+    let (total_remaining_millis, left_health_cnt, right_health_cnt) = a
+        .recv_until(|p| match p {
+            Packet::InGameResync {
+                total_remaining_millis,
+                left_health_cnt,
+                right_health_cnt,
+                ..
+            } => Some((total_remaining_millis, left_health_cnt, right_health_cnt)),
+            _ => None,
+        })
+        .await;
+    assert!(total_remaining_millis > 0);
+    assert!(left_health_cnt > 0 && right_health_cnt > 0);
+
+    // The returned player gets the result of the game.
+    let a_outcome = a.recv_until(game_outcome).await;
+    let b_outcome = b.recv_until(game_outcome).await;
+    println!("Game ended: {a_outcome:?} / {b_outcome:?}");
+
+    // The game is recorded for the returned player.
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    let top_list = a
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                items: top_list, ..
+            } => Some(top_list),
+            _ => None,
+        })
+        .await;
+    let a_uuid = a_data.uuid.unwrap().to_string();
+    assert!(top_list.iter().any(|item| item.uuid == a_uuid));
+    assert_eq!(top_list.len(), 2);
+
+    a.close().await;
+    b.close().await;
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use protocol::{Packet, PlayData, uuid::Uuid};
+
+use self::common::*;
+
+/// Plays a game between two players in a private room until the result.
+async fn play_game(host: &mut TestClient, guest: &mut TestClient) -> (PlayData, PlayData) {
+    host.send(&Packet::CreateRoom).await;
+    let code = host
+        .recv_until(|p| match p {
+            Packet::RoomCreated { code } => Some(code),
+            _ => None,
+        })
+        .await;
+    guest.send(&Packet::JoinRoom { code }).await;
+    let sides = wait_for_game(host, guest).await;
+    finish_game(host, guest).await;
+    sides
+}
+
+async fn wait_for_game(host: &mut TestClient, guest: &mut TestClient) -> (PlayData, PlayData) {
+    let sides = host
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    guest
+        .recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    sides
+}
+
+async fn finish_game(host: &mut TestClient, guest: &mut TestClient) {
+    host.send(&Packet::GameLoadSuccess).await;
+    guest.send(&Packet::GameLoadSuccess).await;
+    assert_eq!(host.recv_until(game_outcome).await, Outcome::Draw);
+    assert_eq!(guest.recv_until(game_outcome).await, Outcome::Draw);
+}
+
+#[tokio::test]
+async fn accepted_rematch_starts_a_new_game() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    host.handshake().await;
+    guest.handshake().await;
+
+    let (left, right) = play_game(&mut host, &mut guest).await;
+
+    // --- The opponent is told about the offer ---
+    guest.send(&Packet::RematchOffer).await;
+    host.recv_until(|p| matches!(p, Packet::RematchOffered).then_some(()))
+        .await;
+    host.send(&Packet::RematchOffer).await;
+
+    // --- The players swap sides and carry their records over ---
+    let (new_left, new_right) = wait_for_game(&mut host, &mut guest).await;
+    assert_eq!(new_left.uuid, right.uuid);
+    assert_eq!(new_right.uuid, left.uuid);
+    assert_eq!(new_left.draw, right.draw + 1);
+    assert_eq!(new_right.draw, left.draw + 1);
+
+    finish_game(&mut host, &mut guest).await;
+
+    host.close().await;
+    guest.close().await;
+}
+
+#[tokio::test]
+async fn declined_rematch_returns_both_players_to_title() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    host.handshake().await;
+    guest.handshake().await;
+
+    play_game(&mut host, &mut guest).await;
+
+    host.send(&Packet::RematchOffer).await;
+    guest
+        .recv_until(|p| matches!(p, Packet::RematchOffered).then_some(()))
+        .await;
+    guest.send(&Packet::RematchDecline).await;
+    host.recv_until(|p| matches!(p, Packet::RematchDeclined).then_some(()))
+        .await;
+
+    // --- Both players are back on the title screen ---
+    for client in [&mut host, &mut guest] {
+        client.send(&Packet::ProfileQuery).await;
+        client
+            .recv_until(|p| matches!(p, Packet::ProfileResult { .. }).then_some(()))
+            .await;
+    }
+
+    // --- A late offer is declined right away ---
+    guest.send(&Packet::RematchOffer).await;
+    assert!(matches!(guest.recv().await, Packet::RematchDeclined));
+
+    host.close().await;
+    guest.close().await;
+}
+
+#[tokio::test]
+async fn replay_query_leaves_the_result_screen() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    host.handshake().await;
+    guest.handshake().await;
+
+    play_game(&mut host, &mut guest).await;
+
+    // --- A query of the title is answered before the rematch is ---
+    host.send(&Packet::ReplayQuery { id: Uuid::new_v4() }).await;
+    let replay = host
+        .recv_until(|p| match p {
+            Packet::ReplayResult { replay } => Some(replay),
+            _ => None,
+        })
+        .await;
+    assert!(replay.is_none());
+
+    host.close().await;
+    guest.close().await;
+}
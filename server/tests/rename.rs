@@ -0,0 +1,133 @@
+mod common;
+
+use protocol::{Hero, NameError, Packet, RankingView, uuid::Uuid};
+use server::{
+    config::Config,
+    store::{MemoryStore, PlayerRecord, PlayerStore},
+};
+
+use self::common::*;
+
+fn rename_config() -> Config {
+    Config {
+        denied_names: vec!["운영자".into(), "Admin".into()],
+        ..test_config()
+    }
+}
+
+fn rename_result(packet: Packet) -> Option<Result<String, NameError>> {
+    match packet {
+        Packet::NameChanged { name } => Some(Ok(name)),
+        Packet::NameRejected { reason } => Some(Err(reason)),
+        _ => None,
+    }
+}
+
+async fn rename(client: &mut TestClient, name: &str) -> Result<String, NameError> {
+    let name = name.to_string();
+    client.send(&Packet::ChangeName { name }).await;
+    client.recv_until(rename_result).await
+}
+
+#[tokio::test]
+async fn player_can_change_name() {
+    let addr = start_server(rename_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (a_token, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    // --- Invalid names are rejected ---
+    assert_eq!(rename(&mut a, "코").await, Err(NameError::InvalidLength));
+    let long_name = "코".repeat(13);
+    assert_eq!(
+        rename(&mut a, &long_name).await,
+        Err(NameError::InvalidLength)
+    );
+    assert_eq!(
+        rename(&mut a, "코미!").await,
+        Err(NameError::InvalidCharacter)
+    );
+    assert_eq!(
+        rename(&mut a, "코미  팬").await,
+        Err(NameError::InvalidCharacter)
+    );
+    assert_eq!(rename(&mut a, "진짜 운영자").await, Err(NameError::Denied));
+    assert_eq!(rename(&mut a, "ad min").await, Err(NameError::Denied));
+    assert_eq!(
+        rename(&mut a, "말랑한 코미").await,
+        Err(NameError::Duplicated)
+    );
+
+    // --- A valid name is trimmed and taken ---
+    assert_eq!(
+        rename(&mut a, " 코미 Fan ").await,
+        Ok("코미 Fan".to_string())
+    );
+    assert_eq!(rename(&mut b, "코미fan").await, Err(NameError::Duplicated));
+    assert_eq!(rename(&mut a, "코미 FAN").await, Ok("코미 FAN".to_string()));
+
+    // --- The leaderboard shows the new name ---
+    a.send(&Packet::EnterGame).await;
+    a.recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    a.send(&Packet::GameLoadSuccess).await;
+    a.recv_until(game_outcome).await;
+
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    let top_list = a
+        .recv_until(|p| match p {
+            Packet::RankingResult {
+                items: top_list, ..
+            } => Some(top_list),
+            _ => None,
+        })
+        .await;
+    let a_uuid = a_data.uuid.unwrap().to_string();
+    let item = top_list.iter().find(|item| item.uuid == a_uuid).unwrap();
+    assert_eq!(item.name, "코미 FAN");
+
+    // --- The new name is kept in the user record ---
+    a.close().await;
+    let mut a = TestClient::connect_with_token(addr, &a_token).await;
+    let (_, resumed) = a.handshake().await;
+    assert_eq!(resumed.name, "코미 FAN");
+
+    // --- The previous name is free again ---
+    assert_eq!(rename(&mut a, "코미 팬").await, Ok("코미 팬".to_string()));
+    assert_eq!(rename(&mut b, "코미 Fan").await, Ok("코미 Fan".to_string()));
+
+    a.close().await;
+    b.close().await;
+}
+
+#[tokio::test]
+async fn missing_user_is_not_renamed() {
+    setup_config(rename_config());
+    let store = MemoryStore::new();
+    let missing = Uuid::new_v4();
+    assert!(!store.rename_player(&missing, "", "코미").await.unwrap());
+
+    // The record was not recreated, and the name was not taken.
+    let addr = "127.0.0.1:0".parse().unwrap();
+    let uuid = Uuid::new_v4();
+    let record = PlayerRecord::new("Alpha".into(), Hero::Erpin);
+    assert!(
+        store
+            .create_player(&uuid, &addr, &record, "")
+            .await
+            .unwrap()
+    );
+    assert!(store.rename_player(&uuid, "Alpha", "코미").await.unwrap());
+    assert!(
+        store
+            .create_player(&missing, &addr, &record, "")
+            .await
+            .unwrap()
+    );
+    let loaded = store.load_player(&missing, "").await.unwrap().unwrap();
+    assert_eq!(loaded.name, "Alpha");
+}
@@ -0,0 +1,186 @@
+mod common;
+
+use protocol::{COLLIDER_DATA, Packet, Replay, ReplaySim, Ruleset, Side, uuid::Uuid};
+use server::config::Config;
+
+use self::common::*;
+
+fn replay_config() -> Config {
+    Config {
+        ruleset: Ruleset {
+            max_play_time: 10_000,
+            max_turn_time: 3_000,
+            ..test_config().ruleset
+        },
+        ..test_config()
+    }
+}
+
+/// Asks for the replay of the last game in the match history of the client.
+async fn last_replay(client: &mut TestClient) -> Replay {
+    client.send(&Packet::ProfileQuery).await;
+    let history = client
+        .recv_until(|p| match p {
+            Packet::ProfileResult { history, .. } => Some(history),
+            _ => None,
+        })
+        .await;
+    let id = history[0].replay_id.expect("the game has a replay");
+    let seed = history[0].seed;
+
+    client.send(&Packet::ReplayQuery { id }).await;
+    let replay = client
+        .recv_until(|p| match p {
+            Packet::ReplayResult { replay } => Some(replay),
+            _ => None,
+        })
+        .await
+        .expect("the replay has not expired");
+    assert_eq!(replay.seed, seed);
+    *replay
+}
+
+#[tokio::test]
+async fn replay_plays_the_match_again() {
+    let addr = start_server(replay_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess {
+            left,
+            right,
+            ruleset,
+        } => Some((left, right, ruleset)),
+        _ => None,
+    };
+    let (left, right, ruleset) = a.recv_until(matched).await;
+    b.recv_until(matched).await;
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+
+    let (mut left_client, mut right_client) = match left.uuid == a_data.uuid {
+        true => (a, b),
+        false => (b, a),
+    };
+
+    // --- The left player hits the right hero, and the right player drops it at their feet ---
+    let first_wind = left_client
+        .recv_until(|p| match p {
+            Packet::InGameTurnSetup {
+                wind_angle,
+                wind_power,
+            } => Some((wind_angle, wind_power)),
+            _ => None,
+        })
+        .await;
+    left_client
+        .recv_until(|p| matches!(p, Packet::InGameLeftTurn { .. }).then_some(()))
+        .await;
+    let collider = COLLIDER_DATA.get(&right.hero).unwrap();
+    let (angle, power) = find_hitting_throw(
+        collider,
+        ruleset.wind.velocity(first_wind.0, first_wind.1),
+        &ruleset,
+    );
+    left_client
+        .send(&Packet::UpdateThrowParams { angle, power })
+        .await;
+    left_client.send(&Packet::ThrowProjectile).await;
+
+    right_client
+        .recv_until(|p| matches!(p, Packet::InGameRightTurn { .. }).then_some(()))
+        .await;
+    right_client
+        .send(&Packet::UpdateThrowParams { angle: 0, power: 0 })
+        .await;
+    right_client.send(&Packet::ThrowProjectile).await;
+
+    // --- Everything the left player saw until the end of the game ---
+    let mut winds = vec![first_wind];
+    let mut hits = Vec::new();
+    let outcome = loop {
+        let packet = left_client.recv().await;
+        match packet {
+            Packet::InGameTurnSetup {
+                wind_angle,
+                wind_power,
+            } => winds.push((wind_angle, wind_power)),
+            Packet::InGameHit { target, pos } => hits.push((target, pos)),
+            packet => {
+                if let Some(outcome) = game_outcome(packet) {
+                    break outcome;
+                }
+            }
+        }
+    };
+    assert_eq!(outcome, Outcome::Victory);
+    assert_eq!(hits.len(), 1);
+    right_client.recv_until(game_outcome).await;
+
+    // --- Both players get the same replay ---
+    let replay = last_replay(&mut left_client).await;
+    assert_eq!(last_replay(&mut right_client).await, replay);
+    assert_eq!(
+        (&replay.left.name, replay.left.hero),
+        (&left.name, left.hero)
+    );
+    assert_eq!(
+        (&replay.right.name, replay.right.hero),
+        (&right.name, right.hero)
+    );
+
+    // --- The simulation has the same wind, hits and result as the game ---
+    let health = replay.ruleset.health_count as usize;
+    let mut sim = ReplaySim::new(replay);
+    let mut sim_winds = match sim.resync() {
+        Packet::InGameResync {
+            wind_angle,
+            wind_power,
+            ..
+        } => vec![(wind_angle, wind_power)],
+        packet => panic!("expected InGameResync, got {packet:?}"),
+    };
+    let mut sim_hits = Vec::new();
+    while !sim.is_finished() {
+        for packet in sim.update() {
+            match packet {
+                Packet::InGameTurnSetup {
+                    wind_angle,
+                    wind_power,
+                } => sim_winds.push((wind_angle, wind_power)),
+                Packet::InGameHit { target, pos } => sim_hits.push((target, pos)),
+                _ => { /* empty */ }
+            }
+        }
+    }
+    assert_eq!(sim_winds, winds);
+    assert_eq!(sim_hits, hits);
+    assert_eq!(sim_hits[0].0, Side::Right);
+    assert_eq!(sim.health(), (health, health - 1));
+
+    left_client.close().await;
+    right_client.close().await;
+}
+
+#[tokio::test]
+async fn unknown_replay_is_reported_as_expired() {
+    let addr = start_server(replay_config());
+    let mut a = TestClient::connect(addr).await;
+    a.handshake().await;
+
+    a.send(&Packet::ReplayQuery { id: Uuid::new_v4() }).await;
+    let replay = a
+        .recv_until(|p| match p {
+            Packet::ReplayResult { replay } => Some(replay),
+            _ => None,
+        })
+        .await;
+    assert!(replay.is_none());
+
+    a.close().await;
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use protocol::{Packet, ROOM_CODE_LENGTH};
+use tokio::time::{self, Duration};
+
+use self::common::*;
+
+async fn create_room(client: &mut TestClient) -> String {
+    client.send(&Packet::CreateRoom).await;
+    client
+        .recv_until(|p| match p {
+            Packet::RoomCreated { code } => Some(code),
+            _ => None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn guests_join_rooms_by_code() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    let (_, host_data) = host.handshake().await;
+    let (_, guest_data) = guest.handshake().await;
+
+    let code = create_room(&mut host).await;
+    assert_eq!(code.chars().count(), ROOM_CODE_LENGTH);
+
+    // --- Rooms do not fall back to a bot ---
+    let max_matching_time = test_config().max_matching_time as u64;
+    time::sleep(Duration::from_millis(max_matching_time * 2)).await;
+
+    // --- Codes are not case-sensitive ---
+    guest
+        .send(&Packet::JoinRoom {
+            code: format!(" {} ", code.to_lowercase()),
+        })
+        .await;
+    let (left, right) = host
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    guest
+        .recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+        .await;
+    let mut uuids = [left.uuid.unwrap(), right.uuid.unwrap()];
+    uuids.sort();
+    let mut expected = [host_data.uuid.unwrap(), guest_data.uuid.unwrap()];
+    expected.sort();
+    assert_eq!(uuids, expected);
+
+    // --- The room is closed once the match starts ---
+    let mut other = TestClient::connect(addr).await;
+    other.handshake().await;
+    other.send(&Packet::JoinRoom { code }).await;
+    assert!(matches!(other.recv().await, Packet::RoomNotFound));
+
+    // --- The game is played like a public match ---
+    host.send(&Packet::GameLoadSuccess).await;
+    guest.send(&Packet::GameLoadSuccess).await;
+    assert_eq!(host.recv_until(game_outcome).await, Outcome::Draw);
+    assert_eq!(guest.recv_until(game_outcome).await, Outcome::Draw);
+
+    host.close().await;
+    guest.close().await;
+    other.close().await;
+}
+
+#[tokio::test]
+async fn closed_rooms_cannot_be_joined() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    host.handshake().await;
+    guest.handshake().await;
+
+    let code = create_room(&mut host).await;
+    host.send(&Packet::TryCancelGame).await;
+    host.recv_until(|p| matches!(p, Packet::CancelSuccess).then_some(()))
+        .await;
+
+    guest.send(&Packet::JoinRoom { code }).await;
+    assert!(matches!(guest.recv().await, Packet::RoomNotFound));
+
+    // --- Both players are back on the title screen ---
+    for client in [&mut host, &mut guest] {
+        client.send(&Packet::ProfileQuery).await;
+        client
+            .recv_until(|p| matches!(p, Packet::ProfileResult { .. }).then_some(()))
+            .await;
+    }
+
+    host.close().await;
+    guest.close().await;
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use std::path::PathBuf;
+
+use protocol::{COLLIDER_DATA, MAX_HEALTH_COUNT, Packet, Ruleset, RulesetError};
+use server::config::{Config, load_ruleset};
+
+use self::common::*;
+
+fn ruleset_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("rulesets")
+        .join(name)
+}
+
+fn ruleset_config() -> Config {
+    Config {
+        ruleset: Ruleset {
+            max_play_time: 10_000,
+            max_turn_time: 5_000,
+            gravity: -600.0,
+            ..load_ruleset(&ruleset_path("sudden_death.toml")).unwrap()
+        },
+        ..test_config()
+    }
+}
+
+#[test]
+fn shipped_rulesets_are_valid() {
+    assert_eq!(
+        load_ruleset(&ruleset_path("standard.toml")),
+        Ok(Ruleset::default())
+    );
+
+    let sudden_death = load_ruleset(&ruleset_path("sudden_death.toml")).unwrap();
+    assert_eq!(sudden_death.health_count, 1);
+    assert_eq!(sudden_death.throw_power, Ruleset::default().throw_power);
+}
+
+#[test]
+fn invalid_ruleset_is_rejected() {
+    for health_count in [0, MAX_HEALTH_COUNT as u8 + 1] {
+        let ruleset = Ruleset {
+            health_count,
+            ..Default::default()
+        };
+        let error = ruleset.validate().unwrap_err();
+        assert_eq!(error, RulesetError::HealthCount);
+        assert!(error.to_string().ends_with(&MAX_HEALTH_COUNT.to_string()));
+    }
+
+    let ruleset = Ruleset {
+        world_min_x: 100.0,
+        world_max_x: -100.0,
+        ..Default::default()
+    };
+    assert_eq!(ruleset.validate(), Err(RulesetError::WorldBounds));
+}
+
+#[tokio::test]
+async fn match_is_played_with_the_ruleset() {
+    let addr = start_server(ruleset_config());
+    let mut a = TestClient::connect(addr).await;
+    let mut b = TestClient::connect(addr).await;
+    let (_, a_data) = a.handshake().await;
+    b.handshake().await;
+
+    // --- Both players are sent the ruleset with the match ---
+    a.send(&Packet::EnterGame).await;
+    b.send(&Packet::EnterGame).await;
+    let matched = |packet| match packet {
+        Packet::MatchingSuccess {
+            left,
+            right,
+            ruleset,
+        } => Some((left, right, ruleset)),
+        _ => None,
+    };
+    let (left, right, ruleset) = a.recv_until(matched).await;
+    assert_eq!(*ruleset, ruleset_config().ruleset);
+    assert_eq!(b.recv_until(matched).await.2, ruleset);
+    a.send(&Packet::GameLoadSuccess).await;
+    b.send(&Packet::GameLoadSuccess).await;
+
+    let (mut left_client, mut right_client) = match left.uuid == a_data.uuid {
+        true => (a, b),
+        false => (b, a),
+    };
+
+    // --- The players start with the health of the ruleset ---
+    let (wind_angle, wind_power) = left_client
+        .recv_until(|p| match p {
+            Packet::InGameTurnSetup {
+                wind_angle,
+                wind_power,
+            } => Some((wind_angle, wind_power)),
+            _ => None,
+        })
+        .await;
+    let health = left_client
+        .recv_until(|p| match p {
+            Packet::InGameLeftTurn {
+                left_health_cnt,
+                right_health_cnt,
+                ..
+            } => Some((left_health_cnt, right_health_cnt)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(health, (1, 1));
+
+    // --- A throw aimed with the gravity of the ruleset hits, and a single hit wins ---
+    let collider = COLLIDER_DATA.get(&right.hero).unwrap();
+    let (angle, power) = find_hitting_throw(
+        collider,
+        ruleset.wind.velocity(wind_angle, wind_power),
+        &ruleset,
+    );
+    left_client
+        .send(&Packet::UpdateThrowParams { angle, power })
+        .await;
+    left_client.send(&Packet::ThrowProjectile).await;
+
+    assert_eq!(left_client.recv_until(game_outcome).await, Outcome::Victory);
+    assert_eq!(right_client.recv_until(game_outcome).await, Outcome::Defeat);
+
+    left_client.close().await;
+    right_client.close().await;
+}
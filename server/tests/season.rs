@@ -0,0 +1,73 @@
+use protocol::{Hero, uuid::Uuid};
+use server::{
+    config::{Config, init_config},
+    season::{season_at, season_end},
+    store::{GameOutcome, GameSide, LeaderboardStore, MemoryStore, PlayerRecord, PlayerStore},
+};
+
+const DAY: i64 = 86_400;
+
+#[test]
+fn seasons_follow_the_schedule() {
+    let config = Config {
+        season_start: 1_000 * DAY,
+        season_length_days: 7,
+        ..Default::default()
+    };
+    assert_eq!(season_at(&config, 0), 1);
+    assert_eq!(season_at(&config, config.season_start), 1);
+    assert_eq!(season_at(&config, season_end(&config, 1) - 1), 1);
+    assert_eq!(season_at(&config, season_end(&config, 1)), 2);
+    assert_eq!(season_end(&config, 2), config.season_start + 14 * DAY);
+}
+
+#[tokio::test]
+async fn archived_seasons_keep_their_final_standings() {
+    init_config(Config::default());
+    let store = MemoryStore::new();
+    let addr = "127.0.0.1:0".parse().unwrap();
+    let a = Uuid::new_v4();
+    let b = Uuid::new_v4();
+    for (uuid, name) in [(&a, "Alpha"), (&b, "Beta")] {
+        let record = PlayerRecord::new(name.into(), Hero::Alice);
+        assert!(store.create_player(uuid, &addr, &record, "").await.unwrap());
+    }
+
+    let side = |uuid: Uuid| GameSide {
+        uuid,
+        hero: Hero::Alice,
+        hits: 0,
+    };
+
+    // --- Season 1: A wins against B ---
+    let (a_rating, b_rating) = store
+        .record_match_result(1, &side(a), &side(b), GameOutcome::Win)
+        .await
+        .unwrap();
+    assert!(store.archive_season(1).await.unwrap());
+    assert!(!store.archive_season(1).await.unwrap());
+
+    // --- Season 2: only B plays, and renames ---
+    store
+        .record_bot_game_result(2, &side(b), GameOutcome::Win)
+        .await
+        .unwrap();
+    assert!(store.rename_player(&b, "Beta", "Gamma").await.unwrap());
+
+    let season_1 = store.ranking(1, &b, 0, 10).await.unwrap();
+    assert_eq!(season_1.total, 2);
+    assert_eq!(season_1.my_rank, Some(2));
+    assert_eq!(season_1.my_rating, Some(b_rating));
+    assert_eq!(season_1.items[0].uuid, a.to_string());
+    assert_eq!(season_1.items[0].rating, a_rating);
+    assert_eq!(season_1.items[1].name, "Beta");
+    assert_eq!(season_1.items[1].losses, 1);
+    assert_eq!(season_1.items[1].wins, 0);
+
+    let season_2 = store.ranking(2, &a, 0, 10).await.unwrap();
+    assert_eq!(season_2.total, 1);
+    assert_eq!(season_2.my_rank, None);
+    assert_eq!(season_2.items[0].name, "Gamma");
+    assert_eq!((season_2.items[0].wins, season_2.items[0].losses), (1, 0));
+    assert_eq!(store.rank(2, &b).await.unwrap(), Some(1));
+}
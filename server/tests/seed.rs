@@ -0,0 +1,114 @@
+mod common;
+
+use protocol::{
+    BotAim, BotLevel, COLLIDER_DATA, Hero, MatchRng, Packet, Ruleset, Side, WindRule, rand::Rng,
+};
+use server::config::Config;
+
+use self::common::*;
+
+fn seed_config() -> Config {
+    Config {
+        ruleset: Ruleset {
+            wind: WindRule {
+                min_angle: 10,
+                max_angle: 11,
+                min_power: 200,
+                max_power: 201,
+                max_speed: 100.0,
+            },
+            ..test_config().ruleset
+        },
+        ..test_config()
+    }
+}
+
+#[tokio::test]
+async fn match_follows_the_wind_of_the_ruleset() {
+    let addr = start_server(seed_config());
+    let mut a = TestClient::connect(addr).await;
+    a.handshake().await;
+
+    // --- The ruleset is sent with the match, here against a bot ---
+    a.send(&Packet::EnterGame).await;
+    let ruleset = a
+        .recv_until(|p| match p {
+            Packet::MatchingSuccess { ruleset, .. } => Some(ruleset),
+            _ => None,
+        })
+        .await;
+    assert_eq!(*ruleset, seed_config().ruleset);
+    a.send(&Packet::GameLoadSuccess).await;
+
+    // --- Every turn has the only wind the ruleset allows ---
+    let mut turns = 0;
+    loop {
+        let packet = a.recv().await;
+        match packet {
+            Packet::InGameTurnSetup {
+                wind_angle,
+                wind_power,
+            } => {
+                assert_eq!((wind_angle, wind_power), (10, 200));
+                turns += 1;
+            }
+            packet => {
+                if game_outcome(packet).is_some() {
+                    break;
+                }
+            }
+        }
+    }
+    assert!(turns > 0);
+
+    // --- The match history keeps the seed of the game ---
+    a.send(&Packet::ProfileQuery).await;
+    let history = a
+        .recv_until(|p| match p {
+            Packet::ProfileResult { history, .. } => Some(history),
+            _ => None,
+        })
+        .await;
+    let id = history[0].replay_id.expect("the game has a replay");
+    a.send(&Packet::ReplayQuery { id }).await;
+    let replay = a
+        .recv_until(|p| match p {
+            Packet::ReplayResult { replay } => Some(replay),
+            _ => None,
+        })
+        .await
+        .expect("the replay has not expired");
+    assert_eq!(replay.seed, history[0].seed);
+
+    a.close().await;
+}
+
+#[test]
+fn same_seed_draws_the_same_match() {
+    let rule = WindRule::default();
+    let target = COLLIDER_DATA.get(&Hero::Erpin).unwrap();
+    let draw = |seed: u64, bot_draws: usize| {
+        let mut rng = MatchRng::new(seed);
+        let level: BotLevel = rng.bot.random();
+        let mut aim = BotAim::new(
+            Side::Right,
+            level,
+            target,
+            rule.velocity(0, 200),
+            &Ruleset::default(),
+            &mut rng.bot,
+        );
+        let aims: Vec<_> = (0..bot_draws)
+            .map(|_| aim.update(0.02, &mut rng.bot))
+            .collect();
+        let winds: Vec<_> = (0..8).map(|_| rule.pick(&mut rng.wind)).collect();
+        (level, aims, winds)
+    };
+
+    let (level, aims, winds) = draw(42, 30);
+    assert_eq!(draw(42, 30), (level, aims, winds.clone()));
+
+    // The bots draw from their own stream, so the wind does not depend on them.
+    assert_eq!(draw(42, 0).2, winds);
+    assert_ne!(draw(43, 0).2, winds);
+}
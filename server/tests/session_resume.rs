@@ -0,0 +1,65 @@
+mod common;
+
+use protocol::{Packet, RankingView};
+
+use self::common::*;
+
+#[tokio::test]
+async fn reconnect_with_token_restores_user() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let (token, data) = a.handshake().await;
+    a.close().await;
+
+    // --- A valid token restores the same user ---
+    let mut a = TestClient::connect_with_token(addr, &token).await;
+    let (resumed_token, resumed) = a.handshake().await;
+    assert_eq!(resumed_token, token);
+    assert_eq!(resumed.uuid, data.uuid);
+    assert_eq!(resumed.name, data.name);
+    assert_eq!(resumed.hero, data.hero);
+    assert_eq!((resumed.win, resumed.lose), (data.win, data.lose));
+
+    a.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    assert!(matches!(a.recv().await, Packet::RankingResult { .. }));
+    a.close().await;
+
+    // --- A token with a wrong secret creates a new user ---
+    let (uuid, _) = token.split_once('.').unwrap();
+    let forged = format!("{uuid}.{}", "0".repeat(64));
+    let mut b = TestClient::connect_with_token(addr, &forged).await;
+    let (new_token, new_data) = b.handshake().await;
+    assert_ne!(new_token, token);
+    assert_ne!(new_data.uuid, data.uuid);
+    b.close().await;
+
+    // --- A malformed token creates a new user ---
+    let mut c = TestClient::connect_with_token(addr, "garbage").await;
+    let (_, other) = c.handshake().await;
+    assert_ne!(other.uuid, data.uuid);
+    c.close().await;
+}
+
+#[tokio::test]
+async fn resumed_session_closes_the_previous_connection() {
+    let addr = start_server(test_config());
+    let mut a = TestClient::connect(addr).await;
+    let (token, data) = a.handshake().await;
+
+    // --- The same token opens a new connection, and the old one is closed ---
+    let mut b = TestClient::connect_with_token(addr, &token).await;
+    let (_, resumed) = b.handshake().await;
+    assert_eq!(resumed.uuid, data.uuid);
+    a.wait_closed().await;
+
+    // --- The new connection keeps working ---
+    b.send(&Packet::RankingQuery {
+        view: RankingView::Page(0),
+    })
+    .await;
+    assert!(matches!(b.recv().await, Packet::RankingResult { .. }));
+    b.close().await;
+}
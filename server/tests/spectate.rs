@@ -0,0 +1,111 @@
+mod common;
+
+use protocol::{LiveMatch, Packet, PlayData};
+use tokio::time::{self, Duration};
+
+use self::common::*;
+
+/// Asks for the live matches until the match of the player shows up.
+async fn find_live_match(client: &mut TestClient, player: &PlayData) -> LiveMatch {
+    loop {
+        client.send(&Packet::LiveMatchQuery).await;
+        let matches = client
+            .recv_until(|p| match p {
+                Packet::LiveMatchList { matches } => Some(matches),
+                _ => None,
+            })
+            .await;
+        let found = matches
+            .into_iter()
+            .find(|m| m.left.uuid == player.uuid || m.right.uuid == player.uuid);
+        if let Some(live_match) = found {
+            return live_match;
+        }
+        time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
+#[tokio::test]
+async fn spectator_follows_a_live_match_until_it_ends() {
+    let addr = start_server(test_config());
+    let mut host = TestClient::connect(addr).await;
+    let mut guest = TestClient::connect(addr).await;
+    let mut spectator = TestClient::connect(addr).await;
+    let (_, host_data) = host.handshake().await;
+    guest.handshake().await;
+    spectator.handshake().await;
+
+    host.send(&Packet::CreateRoom).await;
+    let code = host
+        .recv_until(|p| match p {
+            Packet::RoomCreated { code } => Some(code),
+            _ => None,
+        })
+        .await;
+    guest.send(&Packet::JoinRoom { code }).await;
+    for client in [&mut host, &mut guest] {
+        client
+            .recv_until(|p| matches!(p, Packet::MatchingSuccess { .. }).then_some(()))
+            .await;
+        client.send(&Packet::GameLoadSuccess).await;
+    }
+
+    // --- The spectator gets the players, then the current state of the match once loaded ---
+    let live_match = find_live_match(&mut spectator, &host_data).await;
+    spectator
+        .send(&Packet::WatchMatch { id: live_match.id })
+        .await;
+    let (left, right) = spectator
+        .recv_until(|p| match p {
+            Packet::WatchStarted { left, right, .. } => Some((left, right)),
+            _ => None,
+        })
+        .await;
+    assert_eq!(left.uuid, live_match.left.uuid);
+    assert_eq!(right.uuid, live_match.right.uuid);
+    spectator.send(&Packet::GameLoadSuccess).await;
+    assert!(matches!(
+        spectator.recv().await,
+        Packet::InGameResync { .. }
+    ));
+
+    // --- The match is streamed until it ends ---
+    let mut turns = 0;
+    loop {
+        match spectator.recv().await {
+            Packet::InGameLeftTurn { .. } | Packet::InGameRightTurn { .. } => turns += 1,
+            Packet::WatchEnded => break,
+            Packet::GameResult { .. } | Packet::GameResultDraw { .. } => {
+                panic!("Spectators must not receive a game result")
+            }
+            _ => { /* empty */ }
+        }
+    }
+    assert!(turns > 0);
+
+    // --- The spectator is back on the title screen ---
+    spectator.send(&Packet::ProfileQuery).await;
+    spectator
+        .recv_until(|p| matches!(p, Packet::ProfileResult { .. }).then_some(()))
+        .await;
+
+    host.close().await;
+    guest.close().await;
+    spectator.close().await;
+}
+
+#[tokio::test]
+async fn watching_a_finished_match_is_refused() {
+    let addr = start_server(test_config());
+    let mut client = TestClient::connect(addr).await;
+    client.handshake().await;
+
+    client.send(&Packet::WatchMatch { id: u64::MAX }).await;
+    assert!(matches!(client.recv().await, Packet::WatchNotFound));
+
+    // --- The player stays on the title screen ---
+    client.send(&Packet::LiveMatchQuery).await;
+    assert!(matches!(client.recv().await, Packet::LiveMatchList { .. }));
+
+    client.close().await;
+}